@@ -0,0 +1,397 @@
+//! Asynchronous backend interface
+//!
+//! Gantree: L6_Backend → AsyncBackend
+//!
+//! `Backend` blocks the caller until results arrive. `AsyncBackend` exposes
+//! the job lifecycle instead (`submit` → `JobHandle`, `poll`) so callers can
+//! keep several jobs queued at once and await them from an async context.
+//! Futures are boxed (`BoxFuture`) so the trait stays object safe and
+//! runtime agnostic.
+
+use crate::execution::{Backend, ExecutionResult};
+use niso_core::{Circuit, NisoError, NisoResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+/// Boxed, sendable future returned by `AsyncBackend`
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Handle to a submitted job
+/// Gantree: JobHandle // 제출된 작업 핸들
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobHandle {
+    /// Backend-assigned job ID
+    pub id: String,
+
+    /// Backend the job runs on
+    pub backend: String,
+
+    /// Number of circuits in the job
+    pub num_circuits: usize,
+
+    /// Shots per circuit
+    pub shots: u64,
+}
+
+/// Outcome of polling a job
+/// Gantree: JobPoll // 작업 폴링 결과
+#[derive(Debug, Clone)]
+pub enum JobPoll {
+    /// Queued or running
+    Pending,
+
+    /// Finished; one result per circuit, in submission order
+    Ready(Vec<ExecutionResult>),
+}
+
+impl JobPoll {
+    /// Check if results are available
+    pub fn is_ready(&self) -> bool {
+        matches!(self, JobPoll::Ready(_))
+    }
+
+    /// Results, if finished
+    pub fn into_results(self) -> Option<Vec<ExecutionResult>> {
+        match self {
+            JobPoll::Ready(results) => Some(results),
+            JobPoll::Pending => None,
+        }
+    }
+}
+
+/// Async quantum backend trait
+/// Gantree: AsyncBackendTrait // 비동기 백엔드 인터페이스
+///
+/// A failed or cancelled job surfaces as `Err` from `poll` or
+/// `execute_batch`.
+pub trait AsyncBackend: Send + Sync {
+    /// Get backend name
+    fn name(&self) -> &str;
+
+    /// Get number of qubits
+    fn num_qubits(&self) -> usize;
+
+    /// Submit circuits as one job without waiting for it
+    /// Gantree: submit(circuits, shots) -> JobHandle // 작업 제출
+    fn submit<'a>(
+        &'a self,
+        circuits: &'a [Circuit],
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<JobHandle>>;
+
+    /// Check a submitted job once
+    /// Gantree: poll(job) -> JobPoll // 작업 상태 확인
+    fn poll<'a>(&'a self, job: &'a JobHandle) -> BoxFuture<'a, NisoResult<JobPoll>>;
+
+    /// Run circuits as one job and wait for the results
+    /// Gantree: execute_batch(circuits, shots) -> Vec<ExecutionResult> // 배치 실행
+    fn execute_batch<'a>(
+        &'a self,
+        circuits: &'a [Circuit],
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<Vec<ExecutionResult>>>;
+
+    /// Run a single circuit and wait for the result
+    /// Gantree: execute(circuit, shots) -> ExecutionResult // 단일 실행
+    fn execute<'a>(
+        &'a self,
+        circuit: &'a Circuit,
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<ExecutionResult>> {
+        Box::pin(async move {
+            self.execute_batch(std::slice::from_ref(circuit), shots)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    NisoError::BackendError(format!("backend '{}' returned no result", self.name()))
+                })
+        })
+    }
+}
+
+// ============================================================================
+// Pointer Impls
+// ============================================================================
+
+impl<A: AsyncBackend + ?Sized> AsyncBackend for Box<A> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn num_qubits(&self) -> usize {
+        (**self).num_qubits()
+    }
+
+    fn submit<'a>(
+        &'a self,
+        circuits: &'a [Circuit],
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<JobHandle>> {
+        (**self).submit(circuits, shots)
+    }
+
+    fn poll<'a>(&'a self, job: &'a JobHandle) -> BoxFuture<'a, NisoResult<JobPoll>> {
+        (**self).poll(job)
+    }
+
+    fn execute_batch<'a>(
+        &'a self,
+        circuits: &'a [Circuit],
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<Vec<ExecutionResult>>> {
+        (**self).execute_batch(circuits, shots)
+    }
+
+    fn execute<'a>(
+        &'a self,
+        circuit: &'a Circuit,
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<ExecutionResult>> {
+        (**self).execute(circuit, shots)
+    }
+}
+
+impl<A: AsyncBackend + ?Sized> AsyncBackend for Arc<A> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn num_qubits(&self) -> usize {
+        (**self).num_qubits()
+    }
+
+    fn submit<'a>(
+        &'a self,
+        circuits: &'a [Circuit],
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<JobHandle>> {
+        (**self).submit(circuits, shots)
+    }
+
+    fn poll<'a>(&'a self, job: &'a JobHandle) -> BoxFuture<'a, NisoResult<JobPoll>> {
+        (**self).poll(job)
+    }
+
+    fn execute_batch<'a>(
+        &'a self,
+        circuits: &'a [Circuit],
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<Vec<ExecutionResult>>> {
+        (**self).execute_batch(circuits, shots)
+    }
+
+    fn execute<'a>(
+        &'a self,
+        circuit: &'a Circuit,
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<ExecutionResult>> {
+        (**self).execute(circuit, shots)
+    }
+}
+
+// ============================================================================
+// Blocking Adapter
+// ============================================================================
+
+/// `AsyncBackend` over a blocking `Backend`
+/// Gantree: BlockingAdapter // 동기 백엔드 어댑터
+///
+/// Jobs run to completion inside `submit` (on the polling thread), so
+/// `poll` is always ready. Meant for local simulators, e.g.
+/// `BlockingAdapter::new(SimulatorBackend::ideal(5))`.
+pub struct BlockingAdapter<B> {
+    backend: B,
+    completed: Mutex<HashMap<String, Vec<ExecutionResult>>>,
+    next_id: AtomicU64,
+}
+
+impl<B: Backend> BlockingAdapter<B> {
+    /// Wrap a blocking backend
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            completed: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Get the wrapped backend
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Unwrap the backend
+    pub fn into_inner(self) -> B {
+        self.backend
+    }
+}
+
+impl<B: Backend> AsyncBackend for BlockingAdapter<B> {
+    fn name(&self) -> &str {
+        self.backend.name()
+    }
+
+    fn num_qubits(&self) -> usize {
+        self.backend.num_qubits()
+    }
+
+    fn submit<'a>(
+        &'a self,
+        circuits: &'a [Circuit],
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<JobHandle>> {
+        Box::pin(async move {
+            let results = self.backend.execute_batch(circuits, shots)?;
+            let id = format!(
+                "{}-{}",
+                self.backend.name(),
+                self.next_id.fetch_add(1, Ordering::Relaxed)
+            );
+
+            self.completed
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(id.clone(), results);
+
+            Ok(JobHandle {
+                id,
+                backend: self.backend.name().to_string(),
+                num_circuits: circuits.len(),
+                shots,
+            })
+        })
+    }
+
+    fn poll<'a>(&'a self, job: &'a JobHandle) -> BoxFuture<'a, NisoResult<JobPoll>> {
+        Box::pin(async move {
+            self.completed
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&job.id)
+                .map(JobPoll::Ready)
+                .ok_or_else(|| {
+                    NisoError::BackendError(format!("unknown or collected job '{}'", job.id))
+                })
+        })
+    }
+
+    fn execute_batch<'a>(
+        &'a self,
+        circuits: &'a [Circuit],
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<Vec<ExecutionResult>>> {
+        Box::pin(async move { self.backend.execute_batch(circuits, shots) })
+    }
+}
+
+// ============================================================================
+// Executor
+// ============================================================================
+
+/// Wakes a parked thread
+struct ThreadWaker(std::thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drive a future to completion on the current thread
+/// Gantree: block_on(future) -> Output // 현재 스레드에서 실행
+///
+/// Minimal executor for sync callers of runtime-free futures such as
+/// `BlockingAdapter`'s. Futures that need a specific runtime (e.g. tokio
+/// I/O) must be awaited on that runtime instead.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        std::thread::park();
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulator::SimulatorBackend;
+    use niso_core::CircuitBuilder;
+
+    fn adapter() -> BlockingAdapter<SimulatorBackend> {
+        BlockingAdapter::new(SimulatorBackend::ideal(2).with_seed(3))
+    }
+
+    #[test]
+    fn test_blocking_adapter_matches_backend() {
+        let adapter = adapter();
+        let circuit = CircuitBuilder::new(2).h(0).cnot(0, 1).measure_all().build();
+
+        let sync = adapter.backend().execute(&circuit, 500).unwrap();
+        let result = block_on(adapter.execute(&circuit, 500)).unwrap();
+
+        assert_eq!(result.counts, sync.counts);
+        assert_eq!(AsyncBackend::num_qubits(&adapter), 2);
+    }
+
+    #[test]
+    fn test_submit_then_poll() {
+        let adapter: Arc<dyn AsyncBackend> = Arc::new(adapter());
+        let circuits = vec![
+            CircuitBuilder::new(1).x(0).measure_all().build(),
+            CircuitBuilder::new(2).measure_all().build(),
+        ];
+
+        let first = block_on(adapter.submit(&circuits, 100)).unwrap();
+        let second = block_on(adapter.submit(&circuits[..1], 100)).unwrap();
+        assert_ne!(first.id, second.id);
+        assert_eq!(first.num_circuits, 2);
+
+        let results = block_on(adapter.poll(&first))
+            .unwrap()
+            .into_results()
+            .unwrap();
+        assert_eq!(results[0].counts.get("1"), Some(&100));
+        assert_eq!(results[1].counts.get("00"), Some(&100));
+
+        // Results are handed out once
+        assert!(block_on(adapter.poll(&first)).is_err());
+        assert!(block_on(adapter.poll(&second)).unwrap().is_ready());
+    }
+
+    #[test]
+    fn test_block_on_waits_for_wake() {
+        struct YieldOnce(bool);
+
+        impl Future for YieldOnce {
+            type Output = u32;
+
+            fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+                if self.0 {
+                    Poll::Ready(7)
+                } else {
+                    self.0 = true;
+                    let waker = cx.waker().clone();
+                    std::thread::spawn(move || waker.wake());
+                    Poll::Pending
+                }
+            }
+        }
+
+        assert_eq!(block_on(YieldOnce(false)), 7);
+    }
+}
@@ -0,0 +1,247 @@
+//! Expectation value estimation for Pauli observables
+//!
+//! Gantree: L6_Backend → Estimator
+//!
+//! Measures <ψ|H|ψ> for a state-preparation circuit and a `SparsePauliOp`.
+//! Terms are grouped into qubit-wise-commuting sets so each group needs a
+//! single basis-rotated circuit; all groups run in one `execute_batch`.
+
+use crate::execution::Backend;
+use niso_core::{Basis, Circuit, Counts, Gate, NisoError, NisoResult, SparsePauliOp};
+use serde::{Deserialize, Serialize};
+
+/// Result of an expectation estimate
+/// Gantree: EstimatorResult // 기대값 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimatorResult {
+    /// Estimated <H>
+    pub value: f64,
+
+    /// Standard error of `value` (shot noise)
+    pub std_error: f64,
+
+    /// Estimated <P_i> for every term (identity terms = 1)
+    pub term_values: Vec<f64>,
+
+    /// Number of measurement circuits executed
+    pub num_circuits: usize,
+
+    /// Shots per circuit
+    pub shots: u64,
+}
+
+impl EstimatorResult {
+    /// Total shots spent
+    pub fn total_shots(&self) -> u64 {
+        self.num_circuits as u64 * self.shots
+    }
+}
+
+/// Pauli expectation estimator
+/// Gantree: Estimator // 기대값 추정기
+#[derive(Debug, Clone)]
+pub struct Estimator {
+    /// Shots per measurement circuit
+    shots: u64,
+}
+
+impl Estimator {
+    /// Create a new estimator
+    pub fn new(shots: u64) -> Self {
+        Self { shots }
+    }
+
+    /// Shots per measurement circuit
+    pub fn shots(&self) -> u64 {
+        self.shots
+    }
+
+    /// Build one measurement circuit per qubit-wise-commuting group
+    /// Gantree: measurement_circuits(&self,circuit,op) -> (groups, Vec<Circuit>) // 측정 회로 생성
+    ///
+    /// Existing measurements in `circuit` are dropped; each group's circuit
+    /// appends the basis change for its union basis and a full measurement.
+    pub fn measurement_circuits(
+        &self,
+        circuit: &Circuit,
+        observable: &SparsePauliOp,
+    ) -> NisoResult<(Vec<Vec<usize>>, Vec<Circuit>)> {
+        let num_qubits = circuit.num_qubits();
+        if observable.min_qubits() > num_qubits {
+            return Err(NisoError::GateQubitMismatch {
+                qubit: observable.min_qubits() - 1,
+                num_qubits,
+            });
+        }
+
+        let prep: Vec<Gate> = circuit
+            .gates()
+            .iter()
+            .filter(|g| !g.is_measurement())
+            .cloned()
+            .collect();
+
+        let groups = observable.group_qubit_wise_commuting();
+        let terms = observable.terms();
+        let mut circuits = Vec::with_capacity(groups.len());
+
+        for group in &groups {
+            let mut bases: Vec<Option<Basis>> = vec![None; num_qubits];
+            for &i in group {
+                for &(q, b) in terms[i].0.ops() {
+                    bases[q] = Some(b);
+                }
+            }
+
+            let mut measured = Circuit::from_gates(num_qubits, prep.clone())?;
+            for (q, basis) in bases.iter().enumerate() {
+                if let Some(b) = basis {
+                    measured.add_gates(Gate::basis_transform(q, *b))?;
+                }
+            }
+            measured.add_gate(Gate::MeasureAll)?;
+            circuits.push(measured);
+        }
+
+        Ok((groups, circuits))
+    }
+
+    /// Estimate <H> on the state prepared by `circuit`
+    /// Gantree: estimate(&self,backend,circuit,op) -> EstimatorResult // 기대값 추정
+    pub fn estimate<B: Backend + ?Sized>(
+        &self,
+        backend: &B,
+        circuit: &Circuit,
+        observable: &SparsePauliOp,
+    ) -> NisoResult<EstimatorResult> {
+        let (groups, circuits) = self.measurement_circuits(circuit, observable)?;
+        let results = if circuits.is_empty() {
+            Vec::new()
+        } else {
+            backend.execute_batch(&circuits, self.shots)?
+        };
+
+        let terms = observable.terms();
+        let mut term_values: Vec<f64> = terms
+            .iter()
+            .map(|(p, _)| if p.is_identity() { 1.0 } else { 0.0 })
+            .collect();
+        let mut variance = 0.0;
+
+        for (group, result) in groups.iter().zip(&results) {
+            for &i in group {
+                term_values[i] = terms[i].0.expectation_from_counts(&result.counts);
+            }
+            variance += Self::group_variance(observable, group, &result.counts);
+        }
+
+        let value = terms
+            .iter()
+            .zip(&term_values)
+            .map(|((_, c), v)| c * v)
+            .sum();
+
+        Ok(EstimatorResult {
+            value,
+            std_error: variance.sqrt(),
+            term_values,
+            num_circuits: circuits.len(),
+            shots: self.shots,
+        })
+    }
+
+    /// Variance of the group's sample mean Σ c_i P_i
+    ///
+    /// Terms in a group share the same shots, so their covariances are
+    /// included by computing the per-shot value directly.
+    fn group_variance(observable: &SparsePauliOp, group: &[usize], counts: &Counts) -> f64 {
+        let total: u64 = counts.values().sum();
+        if total < 2 {
+            return 0.0;
+        }
+
+        let terms = observable.terms();
+        let (mut sum, mut sum_sq) = (0.0, 0.0);
+        for (bits, &n) in counts {
+            let f: f64 = group
+                .iter()
+                .map(|&i| terms[i].1 * terms[i].0.eigenvalue(bits))
+                .sum();
+            sum += f * n as f64;
+            sum_sq += f * f * n as f64;
+        }
+
+        let n = total as f64;
+        let mean = sum / n;
+        let sample_var = (sum_sq / n - mean * mean).max(0.0) * n / (n - 1.0);
+        sample_var / n
+    }
+}
+
+impl Default for Estimator {
+    fn default() -> Self {
+        Self::new(niso_core::stats::DEFAULT_SHOTS)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulator::SimulatorBackend;
+    use niso_core::CircuitBuilder;
+
+    fn bell() -> Circuit {
+        CircuitBuilder::new(2).h(0).cnot(0, 1).measure_all().build()
+    }
+
+    #[test]
+    fn test_bell_correlations() {
+        let backend = SimulatorBackend::ideal(2).with_seed(42);
+        let op = SparsePauliOp::from_list(&[("ZZ", 1.0), ("XX", 1.0), ("YY", 1.0)]).unwrap();
+
+        let result = Estimator::new(2000)
+            .estimate(&backend, &bell(), &op)
+            .unwrap();
+
+        // Φ+: <ZZ> = <XX> = 1, <YY> = -1
+        assert_eq!(result.num_circuits, 3);
+        assert!((result.term_values[0] - 1.0).abs() < 1e-12);
+        assert!((result.term_values[1] - 1.0).abs() < 1e-12);
+        assert!((result.term_values[2] + 1.0).abs() < 1e-12);
+        assert!((result.value - 1.0).abs() < 1e-12);
+        assert!(result.std_error < 1e-9);
+    }
+
+    #[test]
+    fn test_grouped_circuits_and_identity() {
+        let backend = SimulatorBackend::ideal(2).with_seed(7);
+        let op = SparsePauliOp::from_list(&[("II", 0.5), ("ZI", 1.0), ("IZ", 1.0), ("XX", 0.25)])
+            .unwrap();
+
+        let result = Estimator::new(4000)
+            .estimate(&backend, &bell(), &op)
+            .unwrap();
+
+        // ZI and IZ share a circuit; each is ~0 on a Bell state
+        assert_eq!(result.num_circuits, 2);
+        assert_eq!(result.total_shots(), 8000);
+        assert_eq!(result.term_values[0], 1.0);
+        // 0.5 + (Z0 + Z1 ~ 0, perfectly correlated) + 0.25
+        assert!((result.value - 0.75).abs() < 6.0 * result.std_error + 1e-9);
+        assert!(result.std_error > 0.01 && result.std_error < 0.05);
+    }
+
+    #[test]
+    fn test_observable_too_wide() {
+        let backend = SimulatorBackend::ideal(2);
+        let op = SparsePauliOp::from_list(&[("ZII", 1.0)]).unwrap();
+
+        assert!(Estimator::new(100)
+            .estimate(&backend, &bell(), &op)
+            .is_err());
+    }
+}
@@ -9,6 +9,7 @@ use niso_core::{Circuit, Counts, NisoResult};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::Arc;
 
 /// Result of circuit execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -142,6 +143,22 @@ pub trait Backend: Send + Sync {
         circuits.iter().map(|c| self.execute(c, shots)).collect()
     }
 
+    /// Execute several batches as independent jobs
+    /// Gantree: execute_concurrent(batches, shots) -> Vec<Vec<ExecutionResult>> // 동시 작업 실행
+    ///
+    /// Hardware backends keep all jobs in flight at once so their queue
+    /// waits overlap; the default runs the batches one after another.
+    fn execute_concurrent(
+        &self,
+        batches: &[Vec<Circuit>],
+        shots: u64,
+    ) -> NisoResult<Vec<Vec<ExecutionResult>>> {
+        batches
+            .iter()
+            .map(|batch| self.execute_batch(batch, shots))
+            .collect()
+    }
+
     /// Get calibration info (if available)
     fn calibration(&self) -> Option<&CalibrationInfo> {
         None
@@ -158,6 +175,126 @@ pub trait Backend: Send + Sync {
     }
 }
 
+// ============================================================================
+// Pointer Impls
+// ============================================================================
+
+// Boxed, shared and borrowed backends (e.g. `Box<dyn Backend>`) are backends too
+
+impl<B: Backend + ?Sized> Backend for Box<B> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn num_qubits(&self) -> usize {
+        (**self).num_qubits()
+    }
+
+    fn execute(&self, circuit: &Circuit, shots: u64) -> NisoResult<ExecutionResult> {
+        (**self).execute(circuit, shots)
+    }
+
+    fn execute_batch(&self, circuits: &[Circuit], shots: u64) -> NisoResult<Vec<ExecutionResult>> {
+        (**self).execute_batch(circuits, shots)
+    }
+
+    fn execute_concurrent(
+        &self,
+        batches: &[Vec<Circuit>],
+        shots: u64,
+    ) -> NisoResult<Vec<Vec<ExecutionResult>>> {
+        (**self).execute_concurrent(batches, shots)
+    }
+
+    fn calibration(&self) -> Option<&CalibrationInfo> {
+        (**self).calibration()
+    }
+
+    fn is_simulator(&self) -> bool {
+        (**self).is_simulator()
+    }
+
+    fn max_shots(&self) -> u64 {
+        (**self).max_shots()
+    }
+}
+
+impl<B: Backend + ?Sized> Backend for Arc<B> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn num_qubits(&self) -> usize {
+        (**self).num_qubits()
+    }
+
+    fn execute(&self, circuit: &Circuit, shots: u64) -> NisoResult<ExecutionResult> {
+        (**self).execute(circuit, shots)
+    }
+
+    fn execute_batch(&self, circuits: &[Circuit], shots: u64) -> NisoResult<Vec<ExecutionResult>> {
+        (**self).execute_batch(circuits, shots)
+    }
+
+    fn execute_concurrent(
+        &self,
+        batches: &[Vec<Circuit>],
+        shots: u64,
+    ) -> NisoResult<Vec<Vec<ExecutionResult>>> {
+        (**self).execute_concurrent(batches, shots)
+    }
+
+    fn calibration(&self) -> Option<&CalibrationInfo> {
+        (**self).calibration()
+    }
+
+    fn is_simulator(&self) -> bool {
+        (**self).is_simulator()
+    }
+
+    fn max_shots(&self) -> u64 {
+        (**self).max_shots()
+    }
+}
+
+impl<B: Backend + ?Sized> Backend for &B {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn num_qubits(&self) -> usize {
+        (**self).num_qubits()
+    }
+
+    fn execute(&self, circuit: &Circuit, shots: u64) -> NisoResult<ExecutionResult> {
+        (**self).execute(circuit, shots)
+    }
+
+    fn execute_batch(&self, circuits: &[Circuit], shots: u64) -> NisoResult<Vec<ExecutionResult>> {
+        (**self).execute_batch(circuits, shots)
+    }
+
+    fn execute_concurrent(
+        &self,
+        batches: &[Vec<Circuit>],
+        shots: u64,
+    ) -> NisoResult<Vec<Vec<ExecutionResult>>> {
+        (**self).execute_concurrent(batches, shots)
+    }
+
+    fn calibration(&self) -> Option<&CalibrationInfo> {
+        (**self).calibration()
+    }
+
+    fn is_simulator(&self) -> bool {
+        (**self).is_simulator()
+    }
+
+    fn max_shots(&self) -> u64 {
+        (**self).max_shots()
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -179,6 +316,19 @@ mod tests {
         counts
     }
 
+    #[test]
+    fn test_boxed_and_shared_backend() {
+        use crate::simulator::SimulatorBackend;
+
+        let boxed: Box<dyn Backend> = Box::new(SimulatorBackend::ideal(2).with_seed(1));
+        let shared: Arc<dyn Backend> = Arc::from(boxed);
+        let circuit = niso_core::CircuitBuilder::new(2).x(0).measure_all().build();
+
+        assert_eq!(shared.num_qubits(), 2);
+        let result = shared.execute(&circuit, 100).unwrap();
+        assert_eq!(result.counts.get("01"), Some(&100));
+    }
+
     #[test]
     fn test_execution_result_new() {
         let counts = make_test_counts();
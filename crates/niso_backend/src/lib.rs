@@ -58,10 +58,18 @@ pub mod execution;
 /// Simulator backend (Gantree: L6_Backend ??SimulatorBackend)
 pub mod simulator;
 
+/// Pauli expectation estimator (Gantree: L6_Backend ??Estimator)
+pub mod estimator;
+
+/// Async backend interface (Gantree: L6_Backend ??AsyncBackend)
+pub mod async_backend;
+
 // ============================================================================
 // Re-exports
 // ============================================================================
 
+pub use async_backend::{block_on, AsyncBackend, BlockingAdapter, BoxFuture, JobHandle, JobPoll};
+pub use estimator::{Estimator, EstimatorResult};
 pub use execution::{Backend, ExecutionMetadata, ExecutionResult};
 pub use simulator::SimulatorBackend;
 
@@ -77,6 +85,8 @@ pub mod prelude {
     //! use niso_backend::prelude::*;
     //! ```
 
+    pub use crate::async_backend::{AsyncBackend, BlockingAdapter, JobHandle, JobPoll};
+    pub use crate::estimator::{Estimator, EstimatorResult};
     pub use crate::execution::{Backend, ExecutionMetadata, ExecutionResult};
     pub use crate::simulator::SimulatorBackend;
 }
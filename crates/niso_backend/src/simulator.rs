@@ -6,8 +6,9 @@
 
 use crate::execution::{Backend, ExecutionMetadata, ExecutionResult};
 use niso_calibration::CalibrationInfo;
-use niso_core::{Circuit, Counts, Gate, NisoError, NisoResult};
-use niso_noise::NoiseModel;
+use niso_core::{Circuit, Counts, Gate, NisoError, NisoResult, QubitId, Topology};
+use niso_noise::{CrosstalkMap, GateTimes, NoiseModel};
+use niso_schedule::Scheduler;
 use rand::prelude::*;
 use rand::rngs::StdRng;
 use std::collections::HashMap;
@@ -30,6 +31,25 @@ pub struct SimulatorBackend {
 
     /// Random seed
     seed: Option<u64>,
+
+    /// Crosstalk map overriding the noise model's (optional)
+    crosstalk: Option<CrosstalkMap>,
+
+    /// Gate timing used to detect overlapping gates
+    gate_times: GateTimes,
+}
+
+/// Correlated error triggered by two conflicting gates overlapping in time
+#[derive(Debug, Clone)]
+struct CrosstalkEvent {
+    /// Index of the later gate of the overlapping pair
+    gate_idx: usize,
+
+    /// Every qubit of both gates
+    qubits: [QubitId; 4],
+
+    /// Probability of a correlated error
+    error: f64,
 }
 
 impl SimulatorBackend {
@@ -45,6 +65,8 @@ impl SimulatorBackend {
             noise_model,
             calibration: None,
             seed: None,
+            crosstalk: None,
+            gate_times: GateTimes::default(),
         }
     }
 
@@ -76,6 +98,23 @@ impl SimulatorBackend {
         self
     }
 
+    /// Set crosstalk map
+    ///
+    /// Two-qubit gates on conflicting edges that overlap in the ASAP
+    /// schedule suffer a correlated error. Without an explicit map the
+    /// noise model's `crosstalk_map` is used, over the calibration
+    /// topology (or a line).
+    pub fn with_crosstalk(mut self, crosstalk: CrosstalkMap) -> Self {
+        self.crosstalk = Some(crosstalk);
+        self
+    }
+
+    /// Set gate timing used for scheduling
+    pub fn with_gate_times(mut self, gate_times: GateTimes) -> Self {
+        self.gate_times = gate_times;
+        self
+    }
+
     /// Set backend name
     pub fn with_name(mut self, name: &str) -> Self {
         self.name = name.to_string();
@@ -89,25 +128,74 @@ impl SimulatorBackend {
     /// Simulate circuit and return counts
     fn simulate(&self, circuit: &Circuit, shots: u64, rng: &mut StdRng) -> Counts {
         let mut counts: Counts = HashMap::new();
+        let crosstalk_events = self.crosstalk_events(circuit);
 
         for _ in 0..shots {
-            let bitstring = self.simulate_single_shot(circuit, rng);
+            let bitstring = self.simulate_single_shot(circuit, &crosstalk_events, rng);
             *counts.entry(bitstring).or_insert(0) += 1;
         }
 
         counts
     }
 
+    /// Find conflicting gate pairs that overlap in the ASAP schedule
+    fn crosstalk_events(&self, circuit: &Circuit) -> Vec<CrosstalkEvent> {
+        let crosstalk = match self.crosstalk_map() {
+            Some(map) if !map.is_empty() => map,
+            _ => return Vec::new(),
+        };
+
+        let schedule = Scheduler::compute_asap(circuit, &self.gate_times);
+        let gates = circuit.gates();
+
+        schedule
+            .crosstalk_overlaps(&crosstalk)
+            .into_iter()
+            .filter_map(|(a, b, error)| {
+                let (qa, qb) = (gates[a].qubits(), gates[b].qubits());
+                Some(CrosstalkEvent {
+                    gate_idx: a.max(b),
+                    qubits: [*qa.first()?, *qa.get(1)?, *qb.first()?, *qb.get(1)?],
+                    error,
+                })
+            })
+            .collect()
+    }
+
+    /// Explicit crosstalk map, or the noise model's over the device topology
+    fn crosstalk_map(&self) -> Option<CrosstalkMap> {
+        self.crosstalk.clone().or_else(|| {
+            let topology = self
+                .calibration
+                .as_ref()
+                .map(|cal| cal.to_topology())
+                .unwrap_or_else(|| Topology::linear(self.num_qubits));
+            self.noise_model.crosstalk_map(&topology)
+        })
+    }
+
     /// Simulate a single shot
-    fn simulate_single_shot(&self, circuit: &Circuit, rng: &mut StdRng) -> String {
+    fn simulate_single_shot(
+        &self,
+        circuit: &Circuit,
+        crosstalk_events: &[CrosstalkEvent],
+        rng: &mut StdRng,
+    ) -> String {
         // Initialize state vector (simplified: track amplitudes)
         let n = circuit.num_qubits();
         let mut state = vec![Complex::zero(); 1 << n];
         state[0] = Complex::one(); // |00...0⟩
 
         // Apply gates
-        for gate in circuit.gates() {
+        for (gate_idx, gate) in circuit.gates().iter().enumerate() {
             self.apply_gate(&mut state, gate, n, rng);
+
+            // Correlated errors once both conflicting gates have run
+            for event in crosstalk_events.iter().filter(|e| e.gate_idx == gate_idx) {
+                if rng.gen::<f64>() < event.error {
+                    self.apply_correlated_error(&mut state, &event.qubits, n, rng);
+                }
+            }
         }
 
         // Measure with noise
@@ -167,6 +255,23 @@ impl SimulatorBackend {
         }
     }
 
+    /// Apply a random Pauli to every qubit of the conflicting gates
+    fn apply_correlated_error(
+        &self,
+        state: &mut [Complex],
+        qubits: &[QubitId],
+        n: usize,
+        rng: &mut StdRng,
+    ) {
+        for &q in qubits {
+            match rng.gen_range(0..3) {
+                0 => self.apply_x(state, q, n),
+                1 => self.apply_y(state, q, n),
+                _ => self.apply_z(state, q, n),
+            }
+        }
+    }
+
     /// Measure state and return bitstring
     fn measure_state(&self, state: &[Complex], n: usize, rng: &mut StdRng) -> String {
         // Calculate probabilities
@@ -526,6 +631,112 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_crosstalk_correlated_errors() {
+        // Two Bell pairs prepared in parallel on conflicting edges
+        let circuit = CircuitBuilder::new(4)
+            .h(0)
+            .h(2)
+            .cnot(0, 1)
+            .cnot(2, 3)
+            .build();
+        let crosstalk = CrosstalkMap::new().with_pair((0, 1), (2, 3), 0.5);
+
+        let clean = SimulatorBackend::ideal(4).with_seed(42);
+        let noisy = SimulatorBackend::ideal(4)
+            .with_crosstalk(crosstalk)
+            .with_seed(42);
+
+        // Fraction of shots where each pair is still correlated
+        let correlated = |result: &ExecutionResult| -> f64 {
+            result
+                .counts
+                .iter()
+                .filter(|(bs, _)| {
+                    let b: Vec<char> = bs.chars().collect();
+                    b[0] == b[1] && b[2] == b[3]
+                })
+                .map(|(_, &c)| c as f64)
+                .sum::<f64>()
+                / result.shots as f64
+        };
+
+        let clean_result = clean.execute(&circuit, 2000).unwrap();
+        let noisy_result = noisy.execute(&circuit, 2000).unwrap();
+
+        assert!((correlated(&clean_result) - 1.0).abs() < 1e-12);
+        assert!(
+            correlated(&noisy_result) < 0.9,
+            "Crosstalk should break correlations: {}",
+            correlated(&noisy_result)
+        );
+    }
+
+    #[test]
+    fn test_crosstalk_no_overlap() {
+        // A barrier serializes the two CNOTs, so no crosstalk occurs
+        let circuit = CircuitBuilder::new(4)
+            .h(0)
+            .h(2)
+            .cnot(0, 1)
+            .barrier()
+            .cnot(2, 3)
+            .build();
+        let crosstalk = CrosstalkMap::new().with_pair((0, 1), (2, 3), 0.5);
+
+        let clean = SimulatorBackend::ideal(4).with_seed(7);
+        let noisy = SimulatorBackend::ideal(4)
+            .with_crosstalk(crosstalk)
+            .with_seed(7);
+
+        let clean_result = clean.execute(&circuit, 500).unwrap();
+        let noisy_result = noisy.execute(&circuit, 500).unwrap();
+
+        assert_eq!(clean_result.counts, noisy_result.counts);
+    }
+
+    #[test]
+    fn test_crosstalk_from_noise_model() {
+        // No explicit map: the noise model's rate applies to neighbouring
+        // edges of the default line topology
+        let circuit = CircuitBuilder::new(4)
+            .h(0)
+            .h(2)
+            .cnot(0, 1)
+            .cnot(2, 3)
+            .build();
+        let model = NoiseModel::ideal().with_crosstalk(0.5);
+
+        let result = SimulatorBackend::new(4, model)
+            .with_seed(42)
+            .execute(&circuit, 2000)
+            .unwrap();
+
+        let broken: u64 = result
+            .counts
+            .iter()
+            .filter(|(bs, _)| {
+                let b: Vec<char> = bs.chars().collect();
+                b[0] != b[1] || b[2] != b[3]
+            })
+            .map(|(_, &c)| c)
+            .sum();
+        assert!(broken > 200, "broken correlations: {}", broken);
+
+        // Both gates are hit: each Bell pair loses correlation
+        let pair_broken = |i: usize| {
+            result
+                .counts
+                .iter()
+                .filter(|(bs, _)| {
+                    let b: Vec<char> = bs.chars().collect();
+                    b[i] != b[i + 1]
+                })
+                .count()
+        };
+        assert!(pair_broken(0) > 0 && pair_broken(2) > 0);
+    }
+
     #[test]
     fn test_seed_reproducibility() {
         let backend1 = SimulatorBackend::from_depol(3, 0.02).unwrap().with_seed(42);
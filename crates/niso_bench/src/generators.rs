@@ -251,7 +251,7 @@ mod tests {
         let circuit = gen.qft(4);
 
         assert_eq!(circuit.num_qubits(), 4);
-        assert!(circuit.gates().len() > 0);
+        assert!(!circuit.gates().is_empty());
     }
 
     #[test]
@@ -278,7 +278,7 @@ mod tests {
         let circuit = gen.random(5, 3);
 
         assert_eq!(circuit.num_qubits(), 5);
-        assert!(circuit.gates().len() > 0);
+        assert!(!circuit.gates().is_empty());
     }
 
     #[test]
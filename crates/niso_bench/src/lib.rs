@@ -235,7 +235,7 @@ mod tests {
 
         // Depths should generally increase
         for (i, c) in circuits.iter().enumerate() {
-            assert!(c.depth() >= i + 1, "Circuit {} has depth {}", i, c.depth());
+            assert!(c.depth() > i, "Circuit {} has depth {}", i, c.depth());
         }
     }
 
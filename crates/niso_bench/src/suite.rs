@@ -473,6 +473,7 @@ mod tests {
     fn test_benchmark_result_from_tqqc() {
         let result = TqqcResult {
             delta_opt: 0.1,
+            deltas_opt: vec![0.1],
             parity_baseline: 0.5,
             parity_final: 0.6,
             improvement: 0.1,
@@ -482,6 +483,8 @@ mod tests {
             significant_moves: 5,
             total_inner_iterations: 15,
             history: vec![],
+            total_shots: 0,
+            aborted: false,
         };
 
         let bench = BenchmarkResult::from_tqqc("test", 5, 0.02, &result, 100, 8192);
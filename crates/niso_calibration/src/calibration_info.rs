@@ -46,6 +46,16 @@ pub struct CalibrationInfo {
     /// Gantree: readout_errors: HashMap<QubitId,f64> // 측정 에러 맵
     pub readout_errors: HashMap<QubitId, f64>,
 
+    /// Probability of reading 1 after preparing 0, per qubit
+    /// Gantree: prob_meas1_prep0: HashMap<QubitId,f64> // 0→1 측정 에러
+    #[serde(default)]
+    pub prob_meas1_prep0: HashMap<QubitId, f64>,
+
+    /// Probability of reading 0 after preparing 1, per qubit
+    /// Gantree: prob_meas0_prep1: HashMap<QubitId,f64> // 1→0 측정 에러
+    #[serde(default)]
+    pub prob_meas0_prep1: HashMap<QubitId, f64>,
+
     /// Coupling map (connectivity)
     /// Gantree: coupling_map: Vec<(QubitId,QubitId)> // 연결 맵
     pub coupling_map: Vec<(QubitId, QubitId)>,
@@ -54,6 +64,21 @@ pub struct CalibrationInfo {
     pub gate_times_1q_ns: Option<f64>,
     /// Two-qubit gate times (optional)
     pub gate_times_2q_ns: Option<f64>,
+
+    /// Single-qubit gate durations per gate name and qubit (nanoseconds)
+    /// Gantree: gate_lengths_1q: HashMap<String,HashMap<QubitId,f64>> // 1Q 시간 맵
+    #[serde(default)]
+    pub gate_lengths_1q: HashMap<String, HashMap<QubitId, f64>>,
+
+    /// Two-qubit gate durations per gate name and qubit pair (nanoseconds)
+    /// Gantree: gate_lengths_2q: HashMap<String,HashMap<(Q,Q),f64>> // 2Q 시간 맵
+    #[serde(default, with = "niso_noise::gate_times::edge_overrides_serde")]
+    pub gate_lengths_2q: HashMap<String, HashMap<(QubitId, QubitId), f64>>,
+
+    /// Readout durations per qubit (nanoseconds)
+    /// Gantree: readout_lengths: HashMap<QubitId,f64> // 측정 시간 맵
+    #[serde(default)]
+    pub readout_lengths: HashMap<QubitId, f64>,
 }
 
 impl CalibrationInfo {
@@ -71,9 +96,14 @@ impl CalibrationInfo {
             gate_errors_1q: HashMap::new(),
             gate_errors_2q: HashMap::new(),
             readout_errors: HashMap::new(),
+            prob_meas1_prep0: HashMap::new(),
+            prob_meas0_prep1: HashMap::new(),
             coupling_map: Vec::new(),
             gate_times_1q_ns: None,
             gate_times_2q_ns: None,
+            gate_lengths_1q: HashMap::new(),
+            gate_lengths_2q: HashMap::new(),
+            readout_lengths: HashMap::new(),
         }
     }
 
@@ -176,6 +206,29 @@ impl CalibrationInfo {
         self.readout_errors.values().sum::<f64>() / self.readout_errors.len() as f64
     }
 
+    /// Readout asymmetry P(0|1) - P(1|0) of a qubit
+    ///
+    /// Positive when relaxation during readout dominates; `None` unless
+    /// both flip probabilities are known.
+    pub fn readout_asymmetry(&self, qubit: QubitId) -> Option<f64> {
+        Some(self.prob_meas0_prep1.get(&qubit)? - self.prob_meas1_prep0.get(&qubit)?)
+    }
+
+    /// Check if per-qubit or per-edge gate durations are available
+    pub fn has_gate_lengths(&self) -> bool {
+        self.gate_lengths_1q.values().any(|m| !m.is_empty())
+            || self.gate_lengths_2q.values().any(|m| !m.is_empty())
+            || !self.readout_lengths.is_empty()
+    }
+
+    /// Get average readout duration (nanoseconds)
+    pub fn avg_readout_length(&self) -> Option<f64> {
+        if self.readout_lengths.is_empty() {
+            return None;
+        }
+        Some(self.readout_lengths.values().sum::<f64>() / self.readout_lengths.len() as f64)
+    }
+
     /// Check if calibration is fresh (within TTL)
     pub fn is_fresh(&self, ttl: Duration) -> bool {
         match self.timestamp.elapsed() {
@@ -241,12 +294,44 @@ impl CalibrationInfo {
 
     /// Convert to gate times
     /// Gantree: to_gate_times(&self) -> GateTimes // 시간 변환
+    ///
+    /// Per-qubit and per-edge durations become location overrides. The
+    /// `sx` (or `x`) length of a qubit also serves as its default
+    /// single-qubit duration, and any native two-qubit gate length as the
+    /// default for its edge.
     pub fn to_gate_times(&self) -> GateTimes {
-        GateTimes::new(
+        let mut times = GateTimes::new(
             self.gate_times_1q_ns.unwrap_or(35.0),
             self.gate_times_2q_ns.unwrap_or(300.0),
-            5000.0, // Measurement
-        )
+            self.avg_readout_length().unwrap_or(5000.0), // Measurement
+        );
+
+        for (name, lengths) in &self.gate_lengths_1q {
+            for (&q, &ns) in lengths {
+                times.set_qubit_gate_time(name, q, ns);
+            }
+        }
+
+        for name in ["x", "sx"] {
+            if let Some(lengths) = self.gate_lengths_1q.get(name) {
+                for (&q, &ns) in lengths {
+                    times.set_qubit_gate_time(GateTimes::CLASS_1Q, q, ns);
+                }
+            }
+        }
+
+        for (name, lengths) in &self.gate_lengths_2q {
+            for (&edge, &ns) in lengths {
+                times.set_edge_gate_time(name, edge, ns);
+                times.set_edge_gate_time(GateTimes::CLASS_2Q, edge, ns);
+            }
+        }
+
+        for (&q, &ns) in &self.readout_lengths {
+            times.set_qubit_gate_time(GateTimes::MEASURE, q, ns);
+        }
+
+        times
     }
 
     // ========================================================================
@@ -330,6 +415,7 @@ mod system_time_serde {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use niso_core::Gate;
 
     #[test]
     fn test_calibration_info_new() {
@@ -374,6 +460,34 @@ mod tests {
         assert!(topology.is_connected(0, 1));
     }
 
+    #[test]
+    fn test_to_gate_times_per_location() {
+        let mut info = CalibrationInfo::ibm_typical(3);
+        info.gate_lengths_1q
+            .entry("sx".to_string())
+            .or_default()
+            .insert(1, 60.0);
+        info.gate_lengths_1q
+            .entry("rz".to_string())
+            .or_default()
+            .insert(1, 0.0);
+        let ecr = info.gate_lengths_2q.entry("ecr".to_string()).or_default();
+        ecr.insert((0, 1), 500.0);
+        ecr.insert((1, 2), 1000.0);
+        info.readout_lengths.insert(2, 1400.0);
+
+        assert!(info.has_gate_lengths());
+
+        let times = info.to_gate_times();
+        assert_eq!(times.gate_duration(&Gate::H(1)), 60.0);
+        assert_eq!(times.gate_duration(&Gate::Rz(1, 0.2)), 0.0);
+        assert_eq!(times.gate_duration(&Gate::H(0)), 35.0);
+        assert_eq!(times.gate_duration(&Gate::Ecr(0, 1)), 500.0);
+        assert_eq!(times.gate_duration(&Gate::Cnot(2, 1)), 1000.0);
+        assert_eq!(times.gate_duration(&Gate::Measure(2)), 1400.0);
+        assert_eq!(times.measurement_ns, 1400.0);
+    }
+
     #[test]
     fn test_best_qubits() {
         let mut info = CalibrationInfo::new("test");
@@ -391,6 +505,17 @@ mod tests {
         assert_eq!(best.len(), 3);
     }
 
+    #[test]
+    fn test_readout_asymmetry() {
+        let mut info = CalibrationInfo::ibm_typical(2);
+        assert_eq!(info.readout_asymmetry(0), None);
+
+        info.prob_meas1_prep0.insert(0, 0.01);
+        info.prob_meas0_prep1.insert(0, 0.03);
+        assert!((info.readout_asymmetry(0).unwrap() - 0.02).abs() < 1e-12);
+        assert_eq!(info.readout_asymmetry(1), None);
+    }
+
     #[test]
     fn test_freshness() {
         let info = CalibrationInfo::new("test");
@@ -408,4 +533,23 @@ mod tests {
         assert_eq!(info.backend_name, restored.backend_name);
         assert_eq!(info.num_qubits(), restored.num_qubits());
     }
+
+    #[test]
+    fn test_serialization_keeps_gate_lengths_2q() {
+        let mut info = CalibrationInfo::new("test");
+        info.gate_lengths_2q
+            .entry("ecr".to_string())
+            .or_default()
+            .insert((0, 1), 660.0);
+        info.gate_lengths_2q
+            .entry("cz".to_string())
+            .or_default()
+            .insert((2, 3), 68.0);
+
+        let json = serde_json::to_string(&info).unwrap();
+        let restored: CalibrationInfo = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.gate_lengths_2q, info.gate_lengths_2q);
+        assert_eq!(restored.gate_lengths_2q["ecr"][&(0, 1)], 660.0);
+    }
 }
@@ -351,6 +351,29 @@ impl CircuitBuilder {
             .measure_all()
     }
 
+    /// Build a TQQC parity circuit with per-qubit delta corrections
+    ///
+    /// Same structure as [`tqqc_parity`](Self::tqqc_parity), but step 3
+    /// applies Rz(theta + deltas[0]) on qubit 0 and Rz(deltas[q]) on every
+    /// other qubit q that has an entry in `deltas`.
+    pub fn tqqc_parity_multi(
+        self,
+        theta: Angle,
+        deltas: &[Angle],
+        entangler: EntanglerType,
+        basis: &BasisString,
+    ) -> Self {
+        let mut builder = self.h(0).entangler_chain(entangler);
+        let n = builder.circuit.num_qubits();
+
+        for (q, &delta) in deltas.iter().enumerate().take(n) {
+            let angle = if q == 0 { theta + delta } else { delta };
+            builder = builder.rz(q, angle);
+        }
+
+        builder.apply_basis(basis).measure_all()
+    }
+
     // ========================================================================
     // VQE/QAOA Helpers
     // ========================================================================
@@ -465,6 +488,19 @@ mod tests {
         assert_eq!(circuit.count_2q(), 4);
     }
 
+    #[test]
+    fn test_builder_tqqc_parity_multi() {
+        let basis = BasisString::all_x(3);
+        let circuit = CircuitBuilder::new(3)
+            .tqqc_parity_multi(0.5, &[0.1, 0.2, 0.3], EntanglerType::Cx, &basis)
+            .build();
+
+        // One Rz per qubit, theta folded into qubit 0
+        assert_eq!(circuit.count_parameterized(), 3);
+        assert!(circuit.gates().contains(&Gate::Rz(0, 0.6)));
+        assert!(circuit.gates().contains(&Gate::Rz(2, 0.3)));
+    }
+
     #[test]
     fn test_builder_basis_transform() {
         let basis = BasisString::parse("XYZ").unwrap();
@@ -8,7 +8,7 @@
 use crate::error::{NisoError, NisoResult};
 use crate::gate::Gate;
 use crate::topology::Topology;
-use crate::types::QubitId;
+use crate::types::{Angle, QubitId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fmt;
@@ -198,6 +198,51 @@ impl Circuit {
         self.gates.iter().filter(|g| g.is_parameterized()).count()
     }
 
+    // ========================================================================
+    // Parameters
+    // ========================================================================
+
+    /// Number of free parameters (rotation angles, `U` counts three)
+    pub fn num_parameters(&self) -> usize {
+        self.gates.iter().map(|g| g.angles().len()).sum()
+    }
+
+    /// Current parameter values in gate order
+    pub fn parameters(&self) -> Vec<Angle> {
+        self.gates.iter().flat_map(|g| g.angles()).collect()
+    }
+
+    /// Return a copy with all rotation angles replaced in gate order
+    /// Gantree: bind_parameters(&self,params) -> Result<Circuit> // 파라미터 바인딩
+    pub fn bind_parameters(&self, params: &[Angle]) -> NisoResult<Circuit> {
+        let expected = self.num_parameters();
+        if params.len() != expected {
+            return Err(NisoError::InvalidGateParameter(format!(
+                "expected {} parameters, got {}",
+                expected,
+                params.len()
+            )));
+        }
+
+        let mut offset = 0;
+        let gates = self
+            .gates
+            .iter()
+            .map(|g| {
+                let n = g.angles().len();
+                let bound = g.bind_angles(&params[offset..offset + n]);
+                offset += n;
+                bound
+            })
+            .collect();
+
+        Ok(Circuit {
+            num_qubits: self.num_qubits,
+            gates,
+            name: self.name.clone(),
+        })
+    }
+
     /// Get qubits used in the circuit
     pub fn used_qubits(&self) -> HashSet<QubitId> {
         let mut used = HashSet::new();
@@ -552,6 +597,24 @@ mod tests {
         assert_eq!(circuit.gate_count(), 2);
     }
 
+    #[test]
+    fn test_bind_parameters() {
+        let mut circuit = Circuit::new(2);
+        circuit.add_gate(Gate::Ry(0, 0.1)).unwrap();
+        circuit.add_gate(Gate::Cnot(0, 1)).unwrap();
+        circuit.add_gate(Gate::U(1, 0.2, 0.3, 0.4)).unwrap();
+
+        assert_eq!(circuit.num_parameters(), 4);
+        assert_eq!(circuit.parameters(), vec![0.1, 0.2, 0.3, 0.4]);
+
+        let bound = circuit.bind_parameters(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+        assert_eq!(bound.gates()[0], Gate::Ry(0, 1.0));
+        assert_eq!(bound.gates()[1], Gate::Cnot(0, 1));
+        assert_eq!(bound.gates()[2], Gate::U(1, 2.0, 3.0, 4.0));
+
+        assert!(circuit.bind_parameters(&[1.0]).is_err());
+    }
+
     #[test]
     fn test_two_qubit_pairs() {
         let mut circuit = Circuit::new(3);
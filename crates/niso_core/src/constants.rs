@@ -246,18 +246,96 @@ pub mod stats {
     /// Epsilon for tie detection
     pub const TIE_EPSILON: f64 = 1e-9;
 
-    /// Get z-critical value for a given confidence level
+    /// Get two-sided z-critical value for a given confidence level
+    /// Gantree: z_critical(confidence) -> f64 // 양측 임계값
+    ///
+    /// Exact quantile Φ⁻¹(1 - (1 - confidence) / 2); the tabulated
+    /// `Z_CRIT_*` constants are rounded values of this function.
     pub fn z_critical(confidence: f64) -> f64 {
-        if confidence >= 0.99 {
-            Z_CRIT_99
-        } else if confidence >= 0.975 {
-            Z_CRIT_975
-        } else if confidence >= 0.95 {
-            Z_CRIT_95
-        } else if confidence >= 0.90 {
-            Z_CRIT_90
+        probit(1.0 - (1.0 - confidence) / 2.0)
+    }
+
+    /// Standard normal CDF Φ(z)
+    /// Gantree: normal_cdf(z) -> f64 // 정규 누적분포
+    ///
+    /// Abramowitz–Stegun 7.1.26, |error| < 1.5e-7.
+    pub fn normal_cdf(z: f64) -> f64 {
+        let x = z.abs() / std::f64::consts::SQRT_2;
+        let t = 1.0 / (1.0 + 0.327_591_1 * x);
+        let poly = t
+            * (0.254_829_592
+                + t * (-0.284_496_736
+                    + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+        let erf = 1.0 - poly * (-x * x).exp();
+
+        if z >= 0.0 {
+            0.5 * (1.0 + erf)
         } else {
-            Z_CRIT_95 // fallback
+            0.5 * (1.0 - erf)
+        }
+    }
+
+    /// Inverse standard normal CDF Φ⁻¹(p) (probit)
+    /// Gantree: probit(p) -> f64 // 정규 분위수
+    ///
+    /// Acklam's rational approximation, relative error < 1.2e-9.
+    /// Returns ∓∞ at p = 0 / 1 and NaN outside [0, 1].
+    pub fn probit(p: f64) -> f64 {
+        const A: [f64; 6] = [
+            -3.969_683_028_665_376e1,
+            2.209_460_984_245_205e2,
+            -2.759_285_104_469_687e2,
+            1.383_577_518_672_69e2,
+            -3.066_479_806_614_716e1,
+            2.506_628_277_459_239,
+        ];
+        const B: [f64; 5] = [
+            -5.447_609_879_822_406e1,
+            1.615_858_368_580_409e2,
+            -1.556_989_798_598_866e2,
+            6.680_131_188_771_972e1,
+            -1.328_068_155_288_572e1,
+        ];
+        const C: [f64; 6] = [
+            -7.784_894_002_430_293e-3,
+            -3.223_964_580_411_365e-1,
+            -2.400_758_277_161_838,
+            -2.549_732_539_343_734,
+            4.374_664_141_464_968,
+            2.938_163_982_698_783,
+        ];
+        const D: [f64; 4] = [
+            7.784_695_709_041_462e-3,
+            3.224_671_290_700_398e-1,
+            2.445_134_137_142_996,
+            3.754_408_661_907_416,
+        ];
+        const P_LOW: f64 = 0.024_25;
+
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        if p == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if p == 1.0 {
+            return f64::INFINITY;
+        }
+
+        let tail = |q: f64| {
+            (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+                / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+        };
+
+        if p < P_LOW {
+            tail((-2.0 * p.ln()).sqrt())
+        } else if p > 1.0 - P_LOW {
+            -tail((-2.0 * (1.0 - p).ln()).sqrt())
+        } else {
+            let q = p - 0.5;
+            let r = q * q;
+            (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+                / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
         }
     }
 }
@@ -294,14 +372,34 @@ mod tests {
 
     #[test]
     fn test_z_critical() {
-        assert!((stats::z_critical(0.90) - stats::Z_CRIT_90).abs() < 1e-10);
-        assert!((stats::z_critical(0.95) - stats::Z_CRIT_95).abs() < 1e-10);
-        assert!((stats::z_critical(0.99) - stats::Z_CRIT_99).abs() < 1e-10);
+        // Tabulated constants are rounded exact quantiles
+        assert!((stats::z_critical(0.90) - stats::Z_CRIT_90).abs() < 1e-3);
+        assert!((stats::z_critical(0.95) - stats::Z_CRIT_95).abs() < 1e-3);
+        assert!((stats::z_critical(0.975) - stats::Z_CRIT_975).abs() < 2e-3);
+        assert!((stats::z_critical(0.99) - stats::Z_CRIT_99).abs() < 1e-3);
+
+        // Levels between the table entries no longer snap
+        let z97 = stats::z_critical(0.97);
+        assert!((z97 - 2.1701).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_probit() {
+        assert!(stats::probit(0.5).abs() < 1e-12);
+        assert!((stats::probit(0.975) - 1.959_963_985).abs() < 1e-8);
+        assert!((stats::probit(0.001) + 3.090_232_306).abs() < 1e-8);
+        assert!(stats::probit(1.0).is_infinite());
+        assert!(stats::probit(1.5).is_nan());
+
+        for &p in &[0.01, 0.2, 0.7, 0.99] {
+            assert!((stats::normal_cdf(stats::probit(p)) - p).abs() < 1e-6);
+        }
     }
 
     #[test]
     fn test_t2_constraint() {
         // T2 should be <= 2*T1 (physical constraint)
-        assert!(physics::DEFAULT_T2_US <= 2.0 * physics::DEFAULT_T1_US);
+        let (t1, t2) = (physics::DEFAULT_T1_US, physics::DEFAULT_T2_US);
+        assert!(t2 <= 2.0 * t1);
     }
 }
@@ -250,6 +250,40 @@ impl Gate {
         )
     }
 
+    /// Get the rotation angles of a parameterized gate (empty otherwise)
+    pub fn angles(&self) -> Vec<Angle> {
+        match self {
+            Gate::Rx(_, a)
+            | Gate::Ry(_, a)
+            | Gate::Rz(_, a)
+            | Gate::P(_, a)
+            | Gate::Crz(_, _, a)
+            | Gate::Crx(_, _, a)
+            | Gate::Cry(_, _, a) => vec![*a],
+            Gate::U(_, theta, phi, lambda) => vec![*theta, *phi, *lambda],
+            _ => vec![],
+        }
+    }
+
+    /// Return a copy with the rotation angles replaced
+    /// Gantree: bind_angles(&self,angles) -> Gate // 파라미터 바인딩
+    ///
+    /// `angles` must have length `self.angles().len()`; non-parameterized
+    /// gates are returned unchanged.
+    pub fn bind_angles(&self, angles: &[Angle]) -> Gate {
+        match (self, angles) {
+            (Gate::Rx(q, _), [a]) => Gate::Rx(*q, *a),
+            (Gate::Ry(q, _), [a]) => Gate::Ry(*q, *a),
+            (Gate::Rz(q, _), [a]) => Gate::Rz(*q, *a),
+            (Gate::P(q, _), [a]) => Gate::P(*q, *a),
+            (Gate::Crz(c, t, _), [a]) => Gate::Crz(*c, *t, *a),
+            (Gate::Crx(c, t, _), [a]) => Gate::Crx(*c, *t, *a),
+            (Gate::Cry(c, t, _), [a]) => Gate::Cry(*c, *t, *a),
+            (Gate::U(q, _, _, _), [theta, phi, lambda]) => Gate::U(*q, *theta, *phi, *lambda),
+            _ => self.clone(),
+        }
+    }
+
     /// Check if gate is measurement
     pub fn is_measurement(&self) -> bool {
         matches!(self, Gate::Measure(_) | Gate::MeasureAll)
@@ -489,7 +523,7 @@ mod tests {
         assert_eq!(Gate::H(0).to_qasm(), "h q[0];");
         assert_eq!(Gate::Cnot(0, 1).to_qasm(), "cx q[0],q[1];");
         assert_eq!(
-            Gate::Rx(0, 1.5707963267948966).to_qasm(),
+            Gate::Rx(0, std::f64::consts::FRAC_PI_2).to_qasm(),
             "rx(1.5707963267948966) q[0];"
         );
     }
@@ -15,6 +15,7 @@
 //!         Circuit // 회로 구조체 (완료)
 //!         CircuitBuilder // 빌더 패턴 (완료)
 //!         Topology // 큐비트 토폴로지 (완료)
+//!         Pauli // 파울리 관측량 (완료)
 //! ```
 //!
 //! ## Quick Start
@@ -91,6 +92,9 @@ pub mod builder;
 /// Qubit topology (Gantree: L1_Circuit → Topology)
 pub mod topology;
 
+/// Pauli observables (Gantree: L1_Circuit → Pauli)
+pub mod pauli;
+
 // ============================================================================
 // Re-exports
 // ============================================================================
@@ -100,6 +104,7 @@ pub use circuit::Circuit;
 pub use constants::{physics, stats, tqqc};
 pub use error::{NisoError, NisoResult};
 pub use gate::{EntanglerType, Gate};
+pub use pauli::{PauliString, SparsePauliOp};
 pub use topology::Topology;
 pub use types::{Angle, Basis, BasisString, Bitstring, Counts, ParamVec, Probability, QubitId};
 
@@ -119,6 +124,7 @@ pub mod prelude {
     pub use crate::constants::{physics, stats, tqqc};
     pub use crate::error::{NisoError, NisoResult};
     pub use crate::gate::{EntanglerType, Gate};
+    pub use crate::pauli::{PauliString, SparsePauliOp};
     pub use crate::topology::Topology;
     pub use crate::types::{
         Angle, Basis, BasisString, Bitstring, Counts, ParamVec, Probability, QubitId,
@@ -242,7 +248,7 @@ mod tests {
         let original = CircuitBuilder::new(3)
             .h(0)
             .cnot(0, 1)
-            .rz(0, 1.5707963267948966)
+            .rz(0, std::f64::consts::FRAC_PI_2)
             .measure_all()
             .build();
 
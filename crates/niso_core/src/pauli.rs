@@ -0,0 +1,336 @@
+//! Pauli observables for NISO
+//!
+//! Gantree: L1_Circuit → Pauli
+//!
+//! Sparse Pauli strings and weighted sums of Pauli strings (Hamiltonians),
+//! with qubit-wise-commuting grouping for measurement.
+//!
+//! Labels follow the bitstring convention used throughout NISO: the
+//! rightmost character acts on qubit 0 ("XIZ" = X on qubit 2, Z on qubit 0).
+
+use crate::error::{NisoError, NisoResult};
+use crate::types::{Basis, Counts, QubitId};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Sparse Pauli string (identity on unlisted qubits)
+/// Gantree: PauliString // 파울리 문자열
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PauliString {
+    /// Non-identity factors sorted by qubit
+    ops: Vec<(QubitId, Basis)>,
+}
+
+impl PauliString {
+    // ========================================================================
+    // Constructors
+    // ========================================================================
+
+    /// Identity string
+    pub fn identity() -> Self {
+        Self { ops: Vec::new() }
+    }
+
+    /// Create from (qubit, Pauli) factors
+    pub fn from_sparse(ops: &[(QubitId, Basis)]) -> NisoResult<Self> {
+        let mut ops = ops.to_vec();
+        ops.sort_by_key(|&(q, _)| q);
+
+        if let Some(w) = ops.windows(2).find(|w| w[0].0 == w[1].0) {
+            return Err(NisoError::InvalidGateParameter(format!(
+                "duplicate Pauli factor on qubit {}",
+                w[0].0
+            )));
+        }
+
+        Ok(Self { ops })
+    }
+
+    /// Parse a dense label such as "XIZY" (rightmost = qubit 0)
+    /// Gantree: from_label(s) -> Result<Self> // 레이블 파싱
+    pub fn from_label(label: &str) -> NisoResult<Self> {
+        let mut ops = Vec::new();
+        for (q, c) in label.chars().rev().enumerate() {
+            match c.to_ascii_uppercase() {
+                'I' => {}
+                other => ops.push((q, Basis::from_char(other)?)),
+            }
+        }
+        Ok(Self { ops })
+    }
+
+    /// Single-qubit Z on `qubit`
+    pub fn z(qubit: QubitId) -> Self {
+        Self {
+            ops: vec![(qubit, Basis::Z)],
+        }
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Non-identity factors sorted by qubit
+    pub fn ops(&self) -> &[(QubitId, Basis)] {
+        &self.ops
+    }
+
+    /// Check if this is the identity
+    pub fn is_identity(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Number of non-identity factors
+    pub fn weight(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Pauli acting on `qubit` (`None` = identity)
+    pub fn basis_on(&self, qubit: QubitId) -> Option<Basis> {
+        self.ops.iter().find(|&&(q, _)| q == qubit).map(|&(_, b)| b)
+    }
+
+    /// Minimum number of qubits this string acts on
+    pub fn min_qubits(&self) -> usize {
+        self.ops.last().map(|&(q, _)| q + 1).unwrap_or(0)
+    }
+
+    /// Check qubit-wise commutation (same Pauli or identity on every qubit)
+    /// Gantree: qubit_wise_commutes(&self,other) -> bool // QWC 판정
+    pub fn qubit_wise_commutes(&self, other: &PauliString) -> bool {
+        self.ops
+            .iter()
+            .all(|&(q, b)| other.basis_on(q).is_none_or(|ob| ob == b))
+    }
+
+    /// Dense label over `num_qubits` qubits (rightmost = qubit 0)
+    pub fn to_label(&self, num_qubits: usize) -> String {
+        (0..num_qubits.max(self.min_qubits()))
+            .rev()
+            .map(|q| self.basis_on(q).map(|b| b.to_char()).unwrap_or('I'))
+            .collect()
+    }
+
+    /// ±1 eigenvalue for a bitstring measured in this string's basis
+    ///
+    /// Assumes the basis change for every factor was applied before
+    /// measurement, so each factor reads as Z.
+    pub fn eigenvalue(&self, bitstring: &str) -> f64 {
+        let bytes = bitstring.as_bytes();
+        let ones = self
+            .ops
+            .iter()
+            .filter(|&&(q, _)| q < bytes.len() && bytes[bytes.len() - 1 - q] == b'1')
+            .count();
+        if ones % 2 == 0 {
+            1.0
+        } else {
+            -1.0
+        }
+    }
+
+    /// Expectation value from counts measured in this string's basis
+    pub fn expectation_from_counts(&self, counts: &Counts) -> f64 {
+        let total: u64 = counts.values().sum();
+        if total == 0 {
+            return 0.0;
+        }
+
+        counts
+            .iter()
+            .map(|(bits, &n)| self.eigenvalue(bits) * n as f64)
+            .sum::<f64>()
+            / total as f64
+    }
+}
+
+impl fmt::Display for PauliString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_label(1))
+    }
+}
+
+/// Weighted sum of Pauli strings
+/// Gantree: SparsePauliOp // 파울리 합 (해밀토니안)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SparsePauliOp {
+    terms: Vec<(PauliString, f64)>,
+}
+
+impl SparsePauliOp {
+    // ========================================================================
+    // Constructors
+    // ========================================================================
+
+    /// Create an empty operator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create from (label, coefficient) pairs
+    /// Gantree: from_list(&[(label,coeff)]) -> Result<Self> // 목록 생성
+    pub fn from_list(terms: &[(&str, f64)]) -> NisoResult<Self> {
+        let mut op = Self::new();
+        for &(label, coeff) in terms {
+            op.add_term(PauliString::from_label(label)?, coeff);
+        }
+        Ok(op)
+    }
+
+    /// Add a term (coefficients of repeated strings are summed)
+    pub fn add_term(&mut self, pauli: PauliString, coeff: f64) {
+        if let Some(term) = self.terms.iter_mut().find(|(p, _)| *p == pauli) {
+            term.1 += coeff;
+        } else {
+            self.terms.push((pauli, coeff));
+        }
+    }
+
+    /// Builder-style `add_term`
+    pub fn with_term(mut self, pauli: PauliString, coeff: f64) -> Self {
+        self.add_term(pauli, coeff);
+        self
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Get terms
+    pub fn terms(&self) -> &[(PauliString, f64)] {
+        &self.terms
+    }
+
+    /// Number of terms
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Check if empty
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Minimum number of qubits the operator acts on
+    pub fn min_qubits(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|(p, _)| p.min_qubits())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Sum of absolute coefficients (bound on |<H>|)
+    pub fn coefficient_norm(&self) -> f64 {
+        self.terms.iter().map(|(_, c)| c.abs()).sum()
+    }
+
+    /// Group non-identity terms into qubit-wise-commuting sets
+    /// Gantree: group_qubit_wise_commuting(&self) -> Vec<Vec<usize>> // QWC 그룹
+    ///
+    /// Greedy first-fit over terms in order; returns term indices.
+    /// Identity terms need no measurement and are not included.
+    pub fn group_qubit_wise_commuting(&self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+
+        for (i, (pauli, _)) in self.terms.iter().enumerate() {
+            if pauli.is_identity() {
+                continue;
+            }
+
+            let slot = groups.iter_mut().find(|group| {
+                group
+                    .iter()
+                    .all(|&j| self.terms[j].0.qubit_wise_commutes(pauli))
+            });
+
+            match slot {
+                Some(group) => group.push(i),
+                None => groups.push(vec![i]),
+            }
+        }
+
+        groups
+    }
+}
+
+impl fmt::Display for SparsePauliOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.min_qubits();
+        let parts: Vec<String> = self
+            .terms
+            .iter()
+            .map(|(p, c)| format!("{:+.4}*{}", c, p.to_label(n)))
+            .collect();
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_roundtrip() {
+        let p = PauliString::from_label("XIZ").unwrap();
+
+        assert_eq!(p.basis_on(0), Some(Basis::Z));
+        assert_eq!(p.basis_on(1), None);
+        assert_eq!(p.basis_on(2), Some(Basis::X));
+        assert_eq!(p.to_label(4), "IXIZ");
+        assert!(PauliString::from_label("XQ").is_err());
+    }
+
+    #[test]
+    fn test_qubit_wise_commutes() {
+        let xx = PauliString::from_label("XX").unwrap();
+        let xi = PauliString::from_label("XI").unwrap();
+        let zx = PauliString::from_label("ZX").unwrap();
+
+        assert!(xx.qubit_wise_commutes(&xi));
+        assert!(!xx.qubit_wise_commutes(&zx));
+        assert!(PauliString::identity().qubit_wise_commutes(&zx));
+    }
+
+    #[test]
+    fn test_expectation_from_counts() {
+        let mut counts = Counts::new();
+        counts.insert("01".to_string(), 75);
+        counts.insert("11".to_string(), 25);
+
+        // Z0: always -1
+        assert!((PauliString::z(0).expectation_from_counts(&counts) + 1.0).abs() < 1e-12);
+        // Z1: +1 * 0.75 - 1 * 0.25
+        assert!((PauliString::z(1).expectation_from_counts(&counts) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_grouping() {
+        // H2-like: II, ZI, IZ, ZZ, XX -> {ZI, IZ, ZZ}, {XX}
+        let op = SparsePauliOp::from_list(&[
+            ("II", -1.05),
+            ("ZI", 0.39),
+            ("IZ", -0.39),
+            ("ZZ", -0.01),
+            ("XX", 0.18),
+        ])
+        .unwrap();
+
+        let groups = op.group_qubit_wise_commuting();
+        assert_eq!(groups, vec![vec![1, 2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn test_add_term_merges() {
+        let op = SparsePauliOp::new()
+            .with_term(PauliString::z(0), 0.5)
+            .with_term(PauliString::z(0), 0.25);
+
+        assert_eq!(op.len(), 1);
+        assert!((op.terms()[0].1 - 0.75).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,143 @@
+//! Backend resolution for the integrated optimizer
+//!
+//! Gantree: L7_Integration → BackendFactory
+//!
+//! `NisoOptimizer` and `Pipeline` run TQQC on whatever backend a factory
+//! produces. By default every `HardwareTarget`, including
+//! `IbmSuperconducting`, is emulated by a `SimulatorFactory` running the
+//! target's noise preset (applied by `NisoConfig::with_hardware`); a
+//! hardware backend (e.g. `IbmBackend`) is plugged in with `with_backend` or
+//! a custom factory.
+
+use crate::config::NisoConfig;
+use niso_backend::{Backend, SimulatorBackend};
+use niso_calibration::CalibrationInfo;
+use niso_core::{NisoError, NisoResult};
+use std::sync::Arc;
+
+/// Creates the backend a run executes on
+/// Gantree: BackendFactory // 백엔드 팩토리 trait
+///
+/// Closures `Fn(&NisoConfig, Option<&CalibrationInfo>) -> NisoResult<Box<dyn Backend>>`
+/// are factories too.
+pub trait BackendFactory: Send + Sync {
+    /// Create a backend for `config`
+    /// Gantree: create_backend(&self,config,cal) -> Box<dyn Backend> // 백엔드 생성
+    ///
+    /// `calibration` is the device calibration currently held by the
+    /// optimizer, if any.
+    fn create_backend(
+        &self,
+        config: &NisoConfig,
+        calibration: Option<&CalibrationInfo>,
+    ) -> NisoResult<Box<dyn Backend>>;
+}
+
+impl<F> BackendFactory for F
+where
+    F: Fn(&NisoConfig, Option<&CalibrationInfo>) -> NisoResult<Box<dyn Backend>> + Send + Sync,
+{
+    fn create_backend(
+        &self,
+        config: &NisoConfig,
+        calibration: Option<&CalibrationInfo>,
+    ) -> NisoResult<Box<dyn Backend>> {
+        self(config, calibration)
+    }
+}
+
+/// Noisy simulator built from the configuration's noise model
+/// Gantree: SimulatorFactory // 시뮬레이터 팩토리
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimulatorFactory;
+
+impl BackendFactory for SimulatorFactory {
+    fn create_backend(
+        &self,
+        config: &NisoConfig,
+        calibration: Option<&CalibrationInfo>,
+    ) -> NisoResult<Box<dyn Backend>> {
+        let mut backend = SimulatorBackend::new(config.qubits, config.to_noise_model());
+
+        if let Some(seed) = config.seed {
+            backend = backend.with_seed(seed);
+        }
+
+        if let Some(cal) = calibration {
+            backend = backend.with_calibration(cal.clone());
+        }
+
+        Ok(Box::new(backend))
+    }
+}
+
+/// Where a run gets its backend from
+pub(crate) enum BackendSource {
+    /// Emulate `config.hardware` on every run with a `SimulatorFactory`
+    Target,
+
+    /// User-supplied factory
+    Factory(Box<dyn BackendFactory>),
+
+    /// Fixed backend shared by all runs
+    Fixed(Arc<dyn Backend>),
+}
+
+impl BackendSource {
+    /// Resolve the backend for a run and check it is wide enough
+    pub(crate) fn resolve(
+        &self,
+        config: &NisoConfig,
+        calibration: Option<&CalibrationInfo>,
+    ) -> NisoResult<Arc<dyn Backend>> {
+        let backend: Arc<dyn Backend> = match self {
+            BackendSource::Target => {
+                Arc::from(SimulatorFactory.create_backend(config, calibration)?)
+            }
+            BackendSource::Factory(factory) => {
+                Arc::from(factory.create_backend(config, calibration)?)
+            }
+            BackendSource::Fixed(backend) => Arc::clone(backend),
+        };
+
+        if backend.num_qubits() < config.qubits {
+            return Err(NisoError::BackendError(format!(
+                "backend '{}' has {} qubits, configuration needs {}",
+                backend.name(),
+                backend.num_qubits(),
+                config.qubits
+            )));
+        }
+
+        Ok(backend)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_resolves_to_simulator() {
+        let config = NisoConfig::default_5q().with_seed(1);
+        let backend = BackendSource::Target.resolve(&config, None).unwrap();
+
+        assert_eq!(backend.num_qubits(), 5);
+        assert!(backend.is_simulator());
+    }
+
+    #[test]
+    fn test_closure_factory_and_width_check() {
+        let factory = |_: &NisoConfig, _: Option<&CalibrationInfo>| {
+            Ok(Box::new(SimulatorBackend::ideal(3)) as Box<dyn Backend>)
+        };
+        let source = BackendSource::Factory(Box::new(factory));
+
+        assert!(source.resolve(&NisoConfig::ideal(3), None).is_ok());
+        assert!(source.resolve(&NisoConfig::ideal(5), None).is_err());
+    }
+}
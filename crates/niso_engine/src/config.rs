@@ -6,6 +6,7 @@
 
 use niso_core::{BasisString, EntanglerType};
 use niso_noise::{GateTimes, NoiseModel};
+use niso_schedule::TimingConstraints;
 use niso_tqqc::{DeltaMode, SigMode, TqqcConfig};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -119,6 +120,11 @@ pub struct NisoConfig {
     /// Two-qubit gate time (nanoseconds)
     pub gate_time_2q_ns: f64,
 
+    /// Device timing constraints; when set, the schedule stage aligns
+    /// gate start/end times to the `dt` grid
+    #[serde(default)]
+    pub timing_constraints: Option<TimingConstraints>,
+
     // ========================================================================
     // Execution Parameters
     // ========================================================================
@@ -162,6 +168,7 @@ impl NisoConfig {
             readout_error: 0.01,
             gate_time_1q_ns: 35.0,
             gate_time_2q_ns: 300.0,
+            timing_constraints: None,
             seed: None,
             verbose: false,
             cache_results: true,
@@ -314,6 +321,12 @@ impl NisoConfig {
         self
     }
 
+    /// Schedule on the device `dt` grid with the given timing constraints
+    pub fn with_timing_constraints(mut self, constraints: TimingConstraints) -> Self {
+        self.timing_constraints = Some(constraints);
+        self
+    }
+
     // ========================================================================
     // Conversions
     // ========================================================================
@@ -386,6 +399,10 @@ impl NisoConfig {
             ));
         }
 
+        if let Some(tc) = &self.timing_constraints {
+            tc.validate().map_err(|e| e.to_string())?;
+        }
+
         Ok(())
     }
 
@@ -482,6 +499,17 @@ mod tests {
         assert!(NisoConfig::default_7q().with_qubits(1).validate().is_err());
     }
 
+    #[test]
+    fn test_validation_rejects_zero_granularity() {
+        let tc = TimingConstraints {
+            granularity: 0,
+            ..TimingConstraints::ibm()
+        };
+        let config = NisoConfig::default_7q().with_timing_constraints(tc);
+
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_is_recommended() {
         let recommended = NisoConfig::default_7q().with_noise(0.015);
@@ -64,6 +64,9 @@
 /// Configuration (Gantree: L7_Integration ??NisoConfig)
 pub mod config;
 
+/// Backend factories (Gantree: L7_Integration ??BackendFactory)
+pub mod backend;
+
 /// Optimizer (Gantree: L7_Integration ??NisoOptimizer)
 pub mod optimizer;
 
@@ -74,6 +77,7 @@ pub mod pipeline;
 // Re-exports
 // ============================================================================
 
+pub use backend::{BackendFactory, SimulatorFactory};
 pub use config::{HardwareTarget, NisoConfig, OptimizationMode};
 pub use optimizer::{
     CalibrationSummary, ExecutionMetrics, NisoOptimizer, OptimizationResult, ScheduleMetrics,
@@ -92,6 +96,7 @@ pub mod prelude {
     //! use niso_engine::prelude::*;
     //! ```
 
+    pub use crate::backend::BackendFactory;
     pub use crate::config::{HardwareTarget, NisoConfig, OptimizationMode};
     pub use crate::optimizer::{ExecutionMetrics, NisoOptimizer, OptimizationResult};
     pub use crate::pipeline::{Pipeline, PipelineStage};
@@ -4,13 +4,15 @@
 //!
 //! Provides unified optimization interface combining all NISO subsystems.
 
+use crate::backend::{BackendFactory, BackendSource};
 use crate::config::NisoConfig;
-use niso_backend::{Backend, ExecutionResult, SimulatorBackend};
+use niso_backend::{Backend, ExecutionResult};
 use niso_calibration::{CalibrationCache, CalibrationInfo};
 use niso_core::{Circuit, NisoError, NisoResult};
 use niso_schedule::Scheduler;
-use niso_tqqc::{Parity, TqqcEngine, TqqcResult};
+use niso_tqqc::{OptimizationObserver, Parity, TqqcEngine, TqqcResult};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Instant;
 
 /// Optimization result with comprehensive metrics
@@ -116,6 +118,12 @@ pub struct NisoOptimizer {
 
     /// Verbose output
     verbose: bool,
+
+    /// Progress observers, handed to the TQQC engine for each run
+    observers: Vec<Box<dyn OptimizationObserver>>,
+
+    /// Backend source (hardware target by default)
+    backend: BackendSource,
 }
 
 impl NisoOptimizer {
@@ -130,6 +138,8 @@ impl NisoOptimizer {
             config,
             calibration_cache: CalibrationCache::default_ttl(),
             calibration: None,
+            observers: Vec::new(),
+            backend: BackendSource::Target,
         }
     }
 
@@ -165,6 +175,28 @@ impl NisoOptimizer {
         self
     }
 
+    /// Run on a fixed backend (e.g. `IbmBackend`) instead of the
+    /// hardware target's simulator
+    ///
+    /// Without explicit calibration, the backend's own calibration is
+    /// used for schedule metrics and the calibration summary.
+    pub fn with_backend(mut self, backend: Box<dyn Backend>) -> Self {
+        self.backend = BackendSource::Fixed(Arc::from(backend));
+        self
+    }
+
+    /// Create the backend of every run with `factory`
+    pub fn with_backend_factory(mut self, factory: impl BackendFactory + 'static) -> Self {
+        self.backend = BackendSource::Factory(Box::new(factory));
+        self
+    }
+
+    /// Attach a progress observer
+    pub fn with_observer(mut self, observer: impl OptimizationObserver + 'static) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
     /// Get current configuration
     pub fn config(&self) -> &NisoConfig {
         &self.config
@@ -189,21 +221,30 @@ impl NisoOptimizer {
             println!("Starting NISO optimization: {}", self.config);
         }
 
-        // Create backend
+        // Create backend; use its calibration for this run if none was set
         let backend = self.create_backend()?;
+        let calibration = self
+            .calibration
+            .clone()
+            .or_else(|| backend.calibration().cloned());
 
         // Create TQQC engine
         let tqqc_config = self.config.to_tqqc_config();
         let mut engine = TqqcEngine::new(tqqc_config, backend);
+        for observer in self.observers.drain(..) {
+            engine.add_observer(observer);
+        }
 
         // Run optimization
-        let tqqc_result = engine.optimize()?;
+        let tqqc_result = engine.optimize();
+        self.observers = engine.take_observers();
+        let tqqc_result = tqqc_result?;
 
         // Compute schedule metrics if calibration available
-        let schedule = self.compute_schedule_metrics();
+        let schedule = self.compute_schedule_metrics(calibration.as_ref());
 
         // Build calibration summary
-        let calibration_summary = self.calibration.as_ref().map(|c| CalibrationSummary {
+        let calibration_summary = calibration.as_ref().map(|c| CalibrationSummary {
             backend: c.backend_name.clone(),
             avg_t1: c.avg_t1(),
             avg_t2: c.avg_t2(),
@@ -258,21 +299,10 @@ impl NisoOptimizer {
     // Backend Creation
     // ========================================================================
 
-    /// Create backend based on configuration
-    fn create_backend(&self) -> NisoResult<SimulatorBackend> {
-        let noise_model = self.config.to_noise_model();
-
-        let mut backend = SimulatorBackend::new(self.config.qubits, noise_model);
-
-        if let Some(seed) = self.config.seed {
-            backend = backend.with_seed(seed);
-        }
-
-        if let Some(ref cal) = self.calibration {
-            backend = backend.with_calibration(cal.clone());
-        }
-
-        Ok(backend)
+    /// Resolve the backend for a run
+    fn create_backend(&self) -> NisoResult<Arc<dyn Backend>> {
+        self.backend
+            .resolve(&self.config, self.calibration.as_ref())
     }
 
     // ========================================================================
@@ -280,10 +310,18 @@ impl NisoOptimizer {
     // ========================================================================
 
     /// Compute schedule metrics for TQQC circuit
-    fn compute_schedule_metrics(&self) -> Option<ScheduleMetrics> {
+    fn compute_schedule_metrics(
+        &self,
+        calibration: Option<&CalibrationInfo>,
+    ) -> Option<ScheduleMetrics> {
         let tqqc_config = self.config.to_tqqc_config();
         let circuit = Parity::build_circuit(&tqqc_config, 0.0, 0.0);
-        let gate_times = self.config.to_gate_times();
+
+        // Prefer device-calibrated per-qubit/per-edge durations
+        let gate_times = match calibration {
+            Some(cal) if cal.has_gate_lengths() => cal.to_gate_times(),
+            _ => self.config.to_gate_times(),
+        };
 
         let schedule = Scheduler::compute_asap(&circuit, &gate_times);
 
@@ -363,13 +401,72 @@ mod tests {
         assert!(result.metrics.total_time_ms > 0);
     }
 
+    #[test]
+    fn test_optimize_with_observer_abort() {
+        use std::ops::ControlFlow;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct StopAfterFirst(Arc<AtomicUsize>);
+
+        impl OptimizationObserver for StopAfterFirst {
+            fn on_iteration(&mut self, _record: &niso_tqqc::IterationRecord) -> ControlFlow<()> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                ControlFlow::Break(())
+            }
+        }
+
+        let seen = Arc::new(AtomicUsize::new(0));
+        let config = NisoConfig::default_5q().with_points(5).with_seed(42);
+        let mut optimizer = NisoOptimizer::new(config).with_observer(StopAfterFirst(seen.clone()));
+
+        let result = optimizer.optimize().unwrap();
+        assert!(result.tqqc_result.aborted);
+        assert_eq!(result.tqqc_result.iterations, 1);
+
+        // Observers stay attached for the next run
+        optimizer.optimize().unwrap();
+        assert_eq!(seen.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_optimize_with_backend() {
+        use niso_backend::SimulatorBackend;
+
+        let calibration = CalibrationInfo::ibm_typical(5);
+        let backend = SimulatorBackend::ibm_typical(5)
+            .with_seed(42)
+            .with_calibration(calibration.clone());
+        let config = NisoConfig::default_5q().with_points(2).with_seed(42);
+
+        let mut optimizer = NisoOptimizer::new(config).with_backend(Box::new(backend));
+        let result = optimizer.optimize().unwrap();
+
+        // Calibration comes from the backend, for this run only
+        let summary = result.calibration_summary.unwrap();
+        assert_eq!(summary.backend, calibration.backend_name);
+        assert!(result.tqqc_result.iterations > 0);
+        assert!(optimizer.calibration().is_none());
+    }
+
+    #[test]
+    fn test_backend_too_small() {
+        use niso_backend::SimulatorBackend;
+
+        let config = NisoConfig::default_7q().with_points(2);
+        let mut optimizer =
+            NisoOptimizer::new(config).with_backend(Box::new(SimulatorBackend::ideal(5)));
+
+        assert!(optimizer.optimize().is_err());
+    }
+
     #[test]
     fn test_measure_parity() {
         let config = NisoConfig::ideal(5).with_seed(42);
         let optimizer = NisoOptimizer::new(config);
 
         let parity = optimizer.measure_parity(0.0, 0.0).unwrap();
-        assert!(parity >= -1.0 && parity <= 1.0);
+        assert!((-1.0..=1.0).contains(&parity));
     }
 
     #[test]
@@ -4,15 +4,17 @@
 //!
 //! Provides staged execution with intermediate results.
 
+use crate::backend::{BackendFactory, BackendSource};
 use crate::config::NisoConfig;
 use crate::optimizer::{CalibrationSummary, OptimizationResult, ScheduleMetrics};
-use niso_backend::SimulatorBackend;
+use niso_backend::Backend;
 use niso_calibration::CalibrationInfo;
 use niso_core::{Circuit, NisoError, NisoResult};
 use niso_noise::NoiseVectorSet;
 use niso_schedule::{CircuitSchedule, Scheduler};
-use niso_tqqc::{Parity, TqqcEngine, TqqcResult};
+use niso_tqqc::{OptimizationObserver, Parity, TqqcEngine, TqqcResult};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Instant;
 
 /// Pipeline stage
@@ -98,6 +100,12 @@ pub struct Pipeline {
 
     /// Verbose output
     verbose: bool,
+
+    /// Progress observers, handed to the TQQC engine in the optimize stage
+    observers: Vec<Box<dyn OptimizationObserver>>,
+
+    /// Backend source (hardware target by default)
+    backend: BackendSource,
 }
 
 impl Pipeline {
@@ -111,6 +119,8 @@ impl Pipeline {
         Self {
             state: PipelineState::new(config),
             verbose,
+            observers: Vec::new(),
+            backend: BackendSource::Target,
         }
     }
 
@@ -124,6 +134,26 @@ impl Pipeline {
         Self::new(NisoConfig::default_5q())
     }
 
+    /// Run the optimize stage on a fixed backend (e.g. `IbmBackend`)
+    ///
+    /// `calibrate` then uses the backend's own calibration when it has one.
+    pub fn with_backend(mut self, backend: Box<dyn Backend>) -> Self {
+        self.backend = BackendSource::Fixed(Arc::from(backend));
+        self
+    }
+
+    /// Create the optimize-stage backend with `factory`
+    pub fn with_backend_factory(mut self, factory: impl BackendFactory + 'static) -> Self {
+        self.backend = BackendSource::Factory(Box::new(factory));
+        self
+    }
+
+    /// Attach a progress observer
+    pub fn with_observer(mut self, observer: impl OptimizationObserver + 'static) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
     // ========================================================================
     // Stage Accessors
     // ========================================================================
@@ -155,6 +185,13 @@ impl Pipeline {
             println!("Pipeline: Calibrating...");
         }
 
+        // Prefer the device calibration of a fixed backend
+        if let BackendSource::Fixed(ref backend) = self.backend {
+            if let Some(calibration) = backend.calibration().cloned() {
+                return self.calibrate_from(calibration);
+            }
+        }
+
         // Create calibration from config
         let calibration = CalibrationInfo::uniform(
             "niso_simulator",
@@ -166,6 +203,14 @@ impl Pipeline {
             self.state.config.readout_error,
         );
 
+        self.calibrate_from(calibration)
+    }
+
+    /// Stage 1 (alternative): Use device calibration
+    ///
+    /// Per-qubit and per-edge gate durations in the calibration are used
+    /// when scheduling.
+    pub fn calibrate_from(&mut self, calibration: CalibrationInfo) -> NisoResult<&CalibrationInfo> {
         // Create noise vectors
         let noise_vectors = calibration.to_noise_vectors();
 
@@ -198,7 +243,8 @@ impl Pipeline {
 
     /// Stage 3: Schedule circuit
     ///
-    /// Computes ASAP schedule for the circuit.
+    /// Computes ASAP schedule for the circuit. When the config carries
+    /// timing constraints, the schedule is aligned to the device `dt` grid.
     pub fn schedule(&mut self) -> NisoResult<&CircuitSchedule> {
         // Ensure circuit is built
         if self.state.circuit.is_none() {
@@ -210,9 +256,20 @@ impl Pipeline {
         }
 
         let circuit = self.state.circuit.as_ref().unwrap();
-        let gate_times = self.state.config.to_gate_times();
 
-        let schedule = Scheduler::compute_asap(circuit, &gate_times);
+        // Prefer device-calibrated per-qubit/per-edge durations
+        let gate_times = match self.state.calibration {
+            Some(ref cal) if cal.has_gate_lengths() => cal.to_gate_times(),
+            _ => self.state.config.to_gate_times(),
+        };
+
+        let schedule = match self.state.config.timing_constraints {
+            Some(ref constraints) => {
+                constraints.validate()?;
+                Scheduler::compute_aligned(circuit, &gate_times, constraints)
+            }
+            None => Scheduler::compute_asap(circuit, &gate_times),
+        };
 
         self.state.schedule = Some(schedule);
         self.state.stage = PipelineStage::Scheduled;
@@ -235,17 +292,19 @@ impl Pipeline {
             .map_err(NisoError::InvalidGateParameter)?;
 
         // Create backend
-        let noise_model = self.state.config.to_noise_model();
-        let mut backend = SimulatorBackend::new(self.state.config.qubits, noise_model);
-
-        if let Some(seed) = self.state.config.seed {
-            backend = backend.with_seed(seed);
-        }
+        let backend = self
+            .backend
+            .resolve(&self.state.config, self.state.calibration.as_ref())?;
 
         // Create TQQC engine and run
         let tqqc_config = self.state.config.to_tqqc_config();
         let mut engine = TqqcEngine::new(tqqc_config, backend);
-        let result = engine.optimize()?;
+        for observer in self.observers.drain(..) {
+            engine.add_observer(observer);
+        }
+        let result = engine.optimize();
+        self.observers = engine.take_observers();
+        let result = result?;
 
         self.state.tqqc_result = Some(result);
         self.state.stage = PipelineStage::Optimized;
@@ -365,6 +424,54 @@ mod tests {
         assert!(pipeline.state().is_scheduled());
     }
 
+    #[test]
+    fn test_schedule_uses_calibrated_gate_lengths() {
+        let mut pipeline = Pipeline::default_5q();
+        let default_duration = pipeline.schedule().unwrap().total_duration_ns();
+
+        // Slow ECR-style edges on the device
+        let mut calibration = CalibrationInfo::ibm_typical(5);
+        let lengths = calibration
+            .gate_lengths_2q
+            .entry("ecr".to_string())
+            .or_default();
+        for q in 0..4 {
+            lengths.insert((q, q + 1), 1000.0);
+        }
+
+        pipeline.calibrate_from(calibration).unwrap();
+        let calibrated_duration = pipeline.schedule().unwrap().total_duration_ns();
+
+        assert!(calibrated_duration > default_duration);
+    }
+
+    #[test]
+    fn test_schedule_with_timing_constraints() {
+        let tc = niso_schedule::TimingConstraints::ibm();
+        let config = NisoConfig::default_5q().with_timing_constraints(tc);
+        let mut pipeline = Pipeline::new(config);
+
+        let schedule = pipeline.schedule().unwrap();
+
+        for sg in schedule.gates() {
+            let start = sg.start_time_ns / tc.dt_ns;
+            assert!((start - start.round()).abs() < 1e-6);
+            assert_eq!(start.round() as u64 % tc.pulse_alignment, 0);
+        }
+    }
+
+    #[test]
+    fn test_schedule_rejects_zero_granularity() {
+        let tc = niso_schedule::TimingConstraints {
+            granularity: 0,
+            ..niso_schedule::TimingConstraints::ibm()
+        };
+        let config = NisoConfig::default_5q().with_timing_constraints(tc);
+        let mut pipeline = Pipeline::new(config);
+
+        assert!(pipeline.schedule().is_err());
+    }
+
     #[test]
     fn test_optimize() {
         let config = NisoConfig::default_5q().with_points(3).with_seed(42);
@@ -438,4 +545,68 @@ mod tests {
         let result = pipeline.optimize().unwrap();
         assert!(result.iterations > 0);
     }
+
+    #[test]
+    fn test_pipeline_with_backend() {
+        use niso_backend::SimulatorBackend;
+
+        let calibration = CalibrationInfo::ibm_typical(5);
+        let backend = SimulatorBackend::ibm_typical(5)
+            .with_seed(42)
+            .with_calibration(calibration.clone());
+        let config = NisoConfig::default_5q().with_points(2).with_seed(42);
+
+        let mut pipeline = Pipeline::new(config).with_backend(Box::new(backend));
+        let result = pipeline.run().unwrap();
+
+        assert_eq!(
+            result.calibration_summary.unwrap().backend,
+            calibration.backend_name
+        );
+        assert!(result.tqqc_result.iterations > 0);
+    }
+
+    #[test]
+    fn test_optimize_passes_calibration_to_factory() {
+        use niso_backend::SimulatorBackend;
+        use std::sync::{Arc, Mutex};
+
+        let seen = Arc::new(Mutex::new(None));
+        let seen_in_factory = Arc::clone(&seen);
+        let factory = move |config: &NisoConfig, cal: Option<&CalibrationInfo>| {
+            *seen_in_factory.lock().unwrap() = cal.map(|c| c.backend_name.clone());
+            Ok(Box::new(SimulatorBackend::ideal(config.qubits)) as Box<dyn Backend>)
+        };
+
+        let config = NisoConfig::default_5q().with_points(1).with_seed(42);
+        let mut pipeline = Pipeline::new(config).with_backend_factory(factory);
+        pipeline
+            .calibrate_from(CalibrationInfo::ibm_typical(5))
+            .unwrap();
+        pipeline.optimize().unwrap();
+
+        let expected = CalibrationInfo::ibm_typical(5).backend_name;
+        assert_eq!(seen.lock().unwrap().as_deref(), Some(expected.as_str()));
+    }
+
+    #[test]
+    fn test_pipeline_observer() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct FinishCounter(Arc<AtomicUsize>);
+
+        impl OptimizationObserver for FinishCounter {
+            fn on_finished(&mut self, _result: &TqqcResult) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let finished = Arc::new(AtomicUsize::new(0));
+        let config = NisoConfig::default_5q().with_points(2).with_seed(42);
+        let mut pipeline = Pipeline::new(config).with_observer(FinishCounter(finished.clone()));
+
+        pipeline.run().unwrap();
+        assert_eq!(finished.load(Ordering::SeqCst), 1);
+    }
 }
@@ -0,0 +1,210 @@
+//! Crosstalk map for NISO
+//!
+//! Gantree: L2_Noise → CrosstalkMap
+//!
+//! Describes pairs of neighbouring coupler edges whose two-qubit gates
+//! interfere when driven at the same time, together with the error
+//! rate incurred by such a simultaneous execution.
+
+use niso_core::{QubitId, Topology};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Undirected coupler edge (stored with the smaller qubit first)
+pub type Edge = (QubitId, QubitId);
+
+/// Normalize an edge so that `(a, b)` and `(b, a)` compare equal
+fn normalize(edge: Edge) -> Edge {
+    if edge.0 <= edge.1 {
+        edge
+    } else {
+        (edge.1, edge.0)
+    }
+}
+
+/// A pair of conflicting edges
+/// Gantree: CrosstalkPair // 충돌 엣지 쌍
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CrosstalkPair {
+    /// First edge
+    pub edge_a: Edge,
+
+    /// Second edge
+    pub edge_b: Edge,
+
+    /// Error probability when both edges run two-qubit gates simultaneously
+    pub error: f64,
+}
+
+impl CrosstalkPair {
+    /// Check whether this pair matches two edges (in either order)
+    pub fn matches(&self, a: Edge, b: Edge) -> bool {
+        let (a, b) = (normalize(a), normalize(b));
+        (self.edge_a == a && self.edge_b == b) || (self.edge_a == b && self.edge_b == a)
+    }
+}
+
+/// Crosstalk map of neighbouring edges
+/// Gantree: CrosstalkMap // 크로스톡 맵
+///
+/// Two-qubit gates on conflicting edges should not overlap in time.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CrosstalkMap {
+    pairs: Vec<CrosstalkPair>,
+}
+
+impl CrosstalkMap {
+    // ========================================================================
+    // Constructors
+    // ========================================================================
+
+    /// Create an empty crosstalk map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a map from a topology with a uniform crosstalk error
+    /// Gantree: from_topology(topo,err) -> Self // 토폴로지에서 생성
+    ///
+    /// Two disjoint edges conflict when a coupler connects one of their
+    /// qubits (i.e. they are nearest neighbours on the device).
+    pub fn from_topology(topology: &Topology, error: f64) -> Self {
+        let mut edges: Vec<Edge> = topology
+            .coupling_map()
+            .iter()
+            .map(|&e| normalize(e))
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+
+        let mut map = Self::new();
+        for (i, &a) in edges.iter().enumerate() {
+            for &b in &edges[i + 1..] {
+                let disjoint = a.0 != b.0 && a.0 != b.1 && a.1 != b.0 && a.1 != b.1;
+                let adjacent = [a.0, a.1]
+                    .iter()
+                    .any(|&qa| [b.0, b.1].iter().any(|&qb| topology.is_connected(qa, qb)));
+
+                if disjoint && adjacent {
+                    map.pairs.push(CrosstalkPair {
+                        edge_a: a,
+                        edge_b: b,
+                        error,
+                    });
+                }
+            }
+        }
+
+        map
+    }
+
+    /// Add a conflicting edge pair (replacing any existing entry)
+    pub fn with_pair(mut self, edge_a: Edge, edge_b: Edge, error: f64) -> Self {
+        self.insert(edge_a, edge_b, error);
+        self
+    }
+
+    /// Insert a conflicting edge pair (replacing any existing entry)
+    pub fn insert(&mut self, edge_a: Edge, edge_b: Edge, error: f64) {
+        if let Some(pair) = self.pairs.iter_mut().find(|p| p.matches(edge_a, edge_b)) {
+            pair.error = error;
+        } else {
+            self.pairs.push(CrosstalkPair {
+                edge_a: normalize(edge_a),
+                edge_b: normalize(edge_b),
+                error,
+            });
+        }
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Get all conflicting pairs
+    pub fn pairs(&self) -> &[CrosstalkPair] {
+        &self.pairs
+    }
+
+    /// Number of conflicting pairs
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Check if the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Get crosstalk error between two edges, if they conflict
+    /// Gantree: conflict(a,b) -> Option<f64> // 충돌 여부
+    pub fn conflict(&self, edge_a: Edge, edge_b: Edge) -> Option<f64> {
+        self.pairs
+            .iter()
+            .find(|p| p.matches(edge_a, edge_b))
+            .map(|p| p.error)
+    }
+
+    /// Get crosstalk error between the qubits of two gates, if they conflict
+    ///
+    /// Only two-qubit operand lists are considered; anything else never
+    /// conflicts.
+    pub fn conflict_between(&self, qubits_a: &[QubitId], qubits_b: &[QubitId]) -> Option<f64> {
+        match (qubits_a, qubits_b) {
+            ([a0, a1], [b0, b1]) => self.conflict((*a0, *a1), (*b0, *b1)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CrosstalkMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CrosstalkMap({} pairs)", self.pairs.len())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_topology_linear() {
+        // 0-1-2-3: edges (0,1),(1,2),(2,3); only (0,1)/(2,3) are disjoint neighbours
+        let map = CrosstalkMap::from_topology(&Topology::linear(4), 0.01);
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.conflict((0, 1), (2, 3)), Some(0.01));
+        assert_eq!(map.conflict((3, 2), (1, 0)), Some(0.01));
+        assert_eq!(map.conflict((0, 1), (1, 2)), None);
+    }
+
+    #[test]
+    fn test_from_topology_far_edges() {
+        // (0,1) and (4,5) are not neighbours on a 6-qubit chain
+        let map = CrosstalkMap::from_topology(&Topology::linear(6), 0.01);
+
+        assert_eq!(map.conflict((0, 1), (4, 5)), None);
+        assert!(map.conflict((1, 2), (3, 4)).is_some());
+    }
+
+    #[test]
+    fn test_insert_replaces() {
+        let mut map = CrosstalkMap::new().with_pair((0, 1), (2, 3), 0.01);
+        map.insert((3, 2), (0, 1), 0.05);
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.conflict((0, 1), (2, 3)), Some(0.05));
+    }
+
+    #[test]
+    fn test_conflict_between_gate_qubits() {
+        let map = CrosstalkMap::new().with_pair((0, 1), (2, 3), 0.02);
+
+        assert_eq!(map.conflict_between(&[1, 0], &[2, 3]), Some(0.02));
+        assert_eq!(map.conflict_between(&[0], &[2, 3]), None);
+    }
+}
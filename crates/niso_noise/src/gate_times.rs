@@ -6,7 +6,7 @@
 //! platforms, enabling accurate circuit scheduling and decoherence
 //! estimation.
 
-use niso_core::{Circuit, Gate};
+use niso_core::{Circuit, Gate, QubitId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -29,9 +29,28 @@ pub struct GateTimes {
 
     /// Per-gate overrides (optional)
     gate_overrides: HashMap<String, f64>,
+
+    /// Per-qubit overrides: gate name -> qubit -> time
+    /// Gantree: qubit_overrides: HashMap<String,HashMap<QubitId,f64>> // 큐비트별 시간
+    #[serde(default)]
+    qubit_overrides: HashMap<String, HashMap<QubitId, f64>>,
+
+    /// Per-edge overrides: gate name -> (q0, q1) -> time
+    /// Gantree: edge_overrides: HashMap<String,HashMap<(Q,Q),f64>> // 엣지별 시간
+    #[serde(default, with = "edge_overrides_serde")]
+    edge_overrides: HashMap<String, HashMap<(QubitId, QubitId), f64>>,
 }
 
 impl GateTimes {
+    /// Override key applying to every single-qubit gate on a qubit
+    pub const CLASS_1Q: &'static str = "1q";
+
+    /// Override key applying to every two-qubit gate on an edge
+    pub const CLASS_2Q: &'static str = "2q";
+
+    /// Override key for measurement
+    pub const MEASURE: &'static str = "measure";
+
     // ========================================================================
     // Constructors
     // ========================================================================
@@ -43,6 +62,8 @@ impl GateTimes {
             two_qubit_ns,
             measurement_ns,
             gate_overrides: HashMap::new(),
+            qubit_overrides: HashMap::new(),
+            edge_overrides: HashMap::new(),
         }
     }
 
@@ -54,6 +75,8 @@ impl GateTimes {
             two_qubit_ns: 300.0,    // ~300 ns for CX gates
             measurement_ns: 5000.0, // ~5 μs
             gate_overrides: HashMap::new(),
+            qubit_overrides: HashMap::new(),
+            edge_overrides: HashMap::new(),
         }
     }
 
@@ -71,6 +94,8 @@ impl GateTimes {
             two_qubit_ns: 200_000.0,   // ~200 μs
             measurement_ns: 100_000.0, // ~100 μs
             gate_overrides: HashMap::new(),
+            qubit_overrides: HashMap::new(),
+            edge_overrides: HashMap::new(),
         }
     }
 
@@ -81,6 +106,8 @@ impl GateTimes {
             two_qubit_ns: 1_000.0,    // ~1 μs (Rydberg interaction)
             measurement_ns: 50_000.0, // ~50 μs
             gate_overrides: HashMap::new(),
+            qubit_overrides: HashMap::new(),
+            edge_overrides: HashMap::new(),
         }
     }
 
@@ -91,6 +118,8 @@ impl GateTimes {
             two_qubit_ns: 100.0,     // ~100 ns (fusion gates)
             measurement_ns: 1_000.0, // ~1 μs (SPD detection)
             gate_overrides: HashMap::new(),
+            qubit_overrides: HashMap::new(),
+            edge_overrides: HashMap::new(),
         }
     }
 
@@ -105,6 +134,69 @@ impl GateTimes {
         self
     }
 
+    /// Set custom time for a gate on a specific qubit
+    /// Gantree: with_qubit_gate_time(name,q,ns) -> Self // 큐비트별 설정
+    ///
+    /// `gate_name` may also be [`GateTimes::CLASS_1Q`] (any single-qubit
+    /// gate) or [`GateTimes::MEASURE`] (readout length).
+    pub fn with_qubit_gate_time(mut self, gate_name: &str, qubit: QubitId, time_ns: f64) -> Self {
+        self.set_qubit_gate_time(gate_name, qubit, time_ns);
+        self
+    }
+
+    /// Set custom time for a gate on a specific edge
+    /// Gantree: with_edge_gate_time(name,q0,q1,ns) -> Self // 엣지별 설정
+    ///
+    /// `gate_name` may also be [`GateTimes::CLASS_2Q`] (any two-qubit gate).
+    pub fn with_edge_gate_time(
+        mut self,
+        gate_name: &str,
+        edge: (QubitId, QubitId),
+        time_ns: f64,
+    ) -> Self {
+        self.set_edge_gate_time(gate_name, edge, time_ns);
+        self
+    }
+
+    /// Set custom time for a gate on a specific qubit (in place)
+    pub fn set_qubit_gate_time(&mut self, gate_name: &str, qubit: QubitId, time_ns: f64) {
+        self.qubit_overrides
+            .entry(gate_name.to_lowercase())
+            .or_default()
+            .insert(qubit, time_ns);
+    }
+
+    /// Set custom time for a gate on a specific edge (in place)
+    pub fn set_edge_gate_time(&mut self, gate_name: &str, edge: (QubitId, QubitId), time_ns: f64) {
+        self.edge_overrides
+            .entry(gate_name.to_lowercase())
+            .or_default()
+            .insert(edge, time_ns);
+    }
+
+    /// Get the per-qubit time for a gate, if overridden
+    pub fn qubit_gate_time(&self, gate_name: &str, qubit: QubitId) -> Option<f64> {
+        self.qubit_overrides
+            .get(gate_name)
+            .and_then(|m| m.get(&qubit))
+            .copied()
+    }
+
+    /// Get the per-edge time for a gate, if overridden
+    ///
+    /// Falls back to the reversed edge if only that direction is known.
+    pub fn edge_gate_time(&self, gate_name: &str, q0: QubitId, q1: QubitId) -> Option<f64> {
+        self.edge_overrides
+            .get(gate_name)
+            .and_then(|m| m.get(&(q0, q1)).or_else(|| m.get(&(q1, q0))).copied())
+    }
+
+    /// Check if any per-qubit or per-edge overrides are set
+    pub fn has_location_overrides(&self) -> bool {
+        self.qubit_overrides.values().any(|m| !m.is_empty())
+            || self.edge_overrides.values().any(|m| !m.is_empty())
+    }
+
     /// Set IBM-specific gate times
     pub fn with_ibm_defaults(mut self) -> Self {
         // Virtual gates (effectively 0)
@@ -143,14 +235,36 @@ impl GateTimes {
 
     /// Get duration for a specific gate
     /// Gantree: gate_duration(&self,Gate) -> f64 // 게이트별 시간
+    ///
+    /// Lookup order: per-location time for the gate name, global time for
+    /// the gate name, per-location time for the gate class, class default.
     pub fn gate_duration(&self, gate: &Gate) -> f64 {
         let gate_name = gate.name();
 
+        // Check per-location overrides for this gate name
+        if let Some(time) = self.location_time(gate, gate_name) {
+            return time;
+        }
+
         // Check overrides first
         if let Some(&time) = self.gate_overrides.get(gate_name) {
             return time;
         }
 
+        // Check per-location overrides for the gate class
+        let class = if gate.is_measurement() {
+            Some(Self::MEASURE)
+        } else if gate.is_two_qubit() {
+            Some(Self::CLASS_2Q)
+        } else if gate.is_single_qubit() {
+            Some(Self::CLASS_1Q)
+        } else {
+            None
+        };
+        if let Some(time) = class.and_then(|c| self.location_time(gate, c)) {
+            return time;
+        }
+
         // Default based on gate type
         if gate.is_measurement() {
             self.measurement_ns
@@ -168,6 +282,21 @@ impl GateTimes {
         }
     }
 
+    /// Look up a per-qubit or per-edge override for a gate
+    fn location_time(&self, gate: &Gate, key: &str) -> Option<f64> {
+        match (gate, gate.qubits().as_slice()) {
+            // All qubits are read out in parallel: slowest readout dominates
+            (Gate::MeasureAll, _) => self
+                .qubit_overrides
+                .get(key)
+                .filter(|m| !m.is_empty())
+                .map(|m| m.values().cloned().fold(0.0, f64::max)),
+            (_, [q]) => self.qubit_gate_time(key, *q),
+            (_, [q0, q1]) => self.edge_gate_time(key, *q0, *q1),
+            _ => None,
+        }
+    }
+
     /// Calculate total circuit duration (sequential, no parallelism)
     /// Gantree: circuit_duration(&self,Circuit) -> f64 // 회로 시간
     pub fn circuit_duration_sequential(&self, circuit: &Circuit) -> f64 {
@@ -266,6 +395,51 @@ impl GateTimes {
     }
 }
 
+// ============================================================================
+// Edge Override Serde Helper
+// ============================================================================
+
+/// Serialize edge maps as `name -> [[q0, q1, time], ...]` (JSON keys must be strings)
+///
+/// Shared with `CalibrationInfo::gate_lengths_2q`, which has the same shape.
+pub mod edge_overrides_serde {
+    use niso_core::QubitId;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+
+    type EdgeOverrides = HashMap<String, HashMap<(QubitId, QubitId), f64>>;
+
+    /// Serialize an edge map into its flattened form
+    pub fn serialize<S>(map: &EdgeOverrides, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let flat: HashMap<&String, Vec<(QubitId, QubitId, f64)>> = map
+            .iter()
+            .map(|(name, edges)| {
+                let entries = edges.iter().map(|(&(a, b), &t)| (a, b, t)).collect();
+                (name, entries)
+            })
+            .collect();
+        flat.serialize(serializer)
+    }
+
+    /// Deserialize an edge map from its flattened form
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<EdgeOverrides, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let flat = HashMap::<String, Vec<(QubitId, QubitId, f64)>>::deserialize(deserializer)?;
+        Ok(flat
+            .into_iter()
+            .map(|(name, entries)| {
+                let edges = entries.into_iter().map(|(a, b, t)| ((a, b), t)).collect();
+                (name, edges)
+            })
+            .collect())
+    }
+}
+
 impl Default for GateTimes {
     fn default() -> Self {
         Self::default_ibm().with_ibm_defaults()
@@ -361,6 +535,56 @@ mod tests {
         assert!(times.two_qubit_ns > 100_000.0);
     }
 
+    #[test]
+    fn test_per_edge_override() {
+        let times = GateTimes::default()
+            .with_edge_gate_time("cx", (0, 1), 600.0)
+            .with_edge_gate_time(GateTimes::CLASS_2Q, (1, 2), 450.0);
+
+        assert_eq!(times.gate_duration(&Gate::Cnot(0, 1)), 600.0);
+        // Reversed direction falls back to the known one
+        assert_eq!(times.gate_duration(&Gate::Cnot(1, 0)), 600.0);
+        // Global "cx" override beats the class override
+        assert_eq!(times.gate_duration(&Gate::Cnot(1, 2)), 300.0);
+        // Class override applies where no name override exists
+        let plain = GateTimes::new(35.0, 300.0, 5000.0).with_edge_gate_time(
+            GateTimes::CLASS_2Q,
+            (1, 2),
+            450.0,
+        );
+        assert_eq!(plain.gate_duration(&Gate::Cnot(1, 2)), 450.0);
+        assert_eq!(plain.gate_duration(&Gate::Cnot(2, 3)), 300.0);
+    }
+
+    #[test]
+    fn test_per_qubit_override() {
+        let times = GateTimes::new(35.0, 300.0, 5000.0)
+            .with_qubit_gate_time(GateTimes::CLASS_1Q, 1, 50.0)
+            .with_qubit_gate_time("rz", 1, 0.0)
+            .with_qubit_gate_time(GateTimes::MEASURE, 0, 1200.0)
+            .with_qubit_gate_time(GateTimes::MEASURE, 1, 1500.0);
+
+        assert_eq!(times.gate_duration(&Gate::H(1)), 50.0);
+        assert_eq!(times.gate_duration(&Gate::Rz(1, 0.3)), 0.0);
+        assert_eq!(times.gate_duration(&Gate::H(0)), 35.0);
+        assert_eq!(times.gate_duration(&Gate::Measure(0)), 1200.0);
+        assert_eq!(times.gate_duration(&Gate::MeasureAll), 1500.0);
+        assert!(times.has_location_overrides());
+    }
+
+    #[test]
+    fn test_location_overrides_serde() {
+        let times = GateTimes::default()
+            .with_edge_gate_time("ecr", (3, 4), 660.0)
+            .with_qubit_gate_time("sx", 2, 36.0);
+
+        let json = serde_json::to_string(&times).unwrap();
+        let restored: GateTimes = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, times);
+        assert_eq!(restored.edge_gate_time("ecr", 3, 4), Some(660.0));
+    }
+
     #[test]
     fn test_with_gate_override() {
         let times = GateTimes::default().with_gate_time("h", 50.0);
@@ -17,10 +17,13 @@
 //!         quality_score(), is_tqqc_usable()
 //!     NoiseVectorSet // 노이즈 벡터 집합 (완료)
 //!         avg_t1(), avg_t2(), best_qubits()
+//!     CrosstalkMap // 크로스톡 맵 (완료)
+//!         from_topology(), with_pair(), conflict()
 //!     GateTimes // 게이트 시간 (완료)
 //!         single_qubit_ns, two_qubit_ns, measurement_ns
 //!         default_ibm(), trapped_ion(), neutral_atom()
 //!         gate_duration(), circuit_duration_asap()
+//!         with_qubit_gate_time(), with_edge_gate_time()
 //! ```
 //!
 //! ## Quick Start
@@ -72,10 +75,14 @@ pub mod noise_vector;
 /// Gate timing configuration (Gantree: L2_Noise → GateTimes)
 pub mod gate_times;
 
+/// Crosstalk between neighbouring edges (Gantree: L2_Noise → CrosstalkMap)
+pub mod crosstalk;
+
 // ============================================================================
 // Re-exports
 // ============================================================================
 
+pub use crosstalk::{CrosstalkMap, CrosstalkPair};
 pub use gate_times::GateTimes;
 pub use noise_model::NoiseModel;
 pub use noise_vector::{NoiseVector, NoiseVectorSet};
@@ -91,6 +98,7 @@ pub mod prelude {
     //! use niso_noise::prelude::*;
     //! ```
 
+    pub use crate::crosstalk::{CrosstalkMap, CrosstalkPair};
     pub use crate::gate_times::GateTimes;
     pub use crate::noise_model::NoiseModel;
     pub use crate::noise_vector::{NoiseVector, NoiseVectorSet};
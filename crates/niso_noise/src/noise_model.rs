@@ -5,7 +5,9 @@
 //! Provides unified noise model representation for NISQ devices,
 //! including T1/T2 decoherence, gate errors, and readout errors.
 
+use crate::crosstalk::CrosstalkMap;
 use niso_core::error::{NisoError, NisoResult};
+use niso_core::Topology;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -218,6 +220,17 @@ impl NoiseModel {
         self.crosstalk
     }
 
+    /// Build a crosstalk map for a device topology
+    /// Gantree: crosstalk_map(&self,Topology) -> Option<CrosstalkMap> // 크로스톡 맵
+    ///
+    /// Every pair of neighbouring edges is assigned the model's crosstalk
+    /// rate. Returns `None` if the model has no crosstalk.
+    pub fn crosstalk_map(&self, topology: &Topology) -> Option<CrosstalkMap> {
+        self.crosstalk
+            .filter(|&ct| ct > 0.0)
+            .map(|ct| CrosstalkMap::from_topology(topology, ct))
+    }
+
     // ========================================================================
     // Validation
     // ========================================================================
@@ -464,6 +477,16 @@ mod tests {
         assert_eq!(model.crosstalk(), Some(0.002));
     }
 
+    #[test]
+    fn test_crosstalk_map() {
+        let topo = Topology::linear(4);
+
+        let map = NoiseModel::ibm_typical().crosstalk_map(&topo).unwrap();
+        assert_eq!(map.conflict((0, 1), (2, 3)), Some(0.001));
+
+        assert!(NoiseModel::ideal().crosstalk_map(&topo).is_none());
+    }
+
     #[test]
     fn test_decay_probabilities() {
         let model = NoiseModel::new(100.0, 60.0, 0.001, 0.01, 0.01).unwrap();
@@ -196,6 +196,6 @@ mod tests {
 
         assert!(parity.is_ok());
         let p = parity.unwrap();
-        assert!(p >= -1.0 && p <= 1.0);
+        assert!((-1.0..=1.0).contains(&p));
     }
 }
@@ -28,6 +28,18 @@ impl PyIterationRecord {
         self.inner.delta
     }
 
+    /// Index of the probed parameter (None = all simultaneously)
+    #[getter]
+    pub fn param_index(&self) -> Option<usize> {
+        self.inner.param_index
+    }
+
+    /// Full delta vector after this iteration
+    #[getter]
+    pub fn deltas(&self) -> Vec<f64> {
+        self.inner.deltas.clone()
+    }
+
     /// Parity for +delta
     #[getter]
     pub fn parity_plus(&self) -> f64 {
@@ -73,6 +85,12 @@ impl PyIterationRecord {
         self.inner.is_significant
     }
 
+    /// Shots executed during this iteration
+    #[getter]
+    pub fn shots(&self) -> u64 {
+        self.inner.shots
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "IterationRecord(iter={}, delta={:.4}, parity={:.4}, improvement={:.4})",
@@ -106,6 +124,12 @@ impl PyTqqcResult {
         self.inner.delta_opt
     }
 
+    /// Optimized delta vector
+    #[getter]
+    pub fn deltas_opt(&self) -> Vec<f64> {
+        self.inner.deltas_opt.clone()
+    }
+
     /// Baseline parity (before optimization)
     #[getter]
     pub fn parity_baseline(&self) -> f64 {
@@ -160,6 +184,18 @@ impl PyTqqcResult {
         self.inner.total_inner_iterations
     }
 
+    /// Total shots executed
+    #[getter]
+    pub fn total_shots(&self) -> u64 {
+        self.inner.total_shots
+    }
+
+    /// Whether an observer aborted the run
+    #[getter]
+    pub fn aborted(&self) -> bool {
+        self.inner.aborted
+    }
+
     /// Whether improvement occurred
     pub fn improved(&self) -> bool {
         self.inner.improved()
@@ -372,6 +408,7 @@ mod tests {
     fn test_py_tqqc_result() {
         let result = TqqcResult {
             delta_opt: 0.15,
+            deltas_opt: vec![0.15],
             parity_baseline: 0.4,
             parity_final: 0.5,
             improvement: 0.1,
@@ -381,6 +418,8 @@ mod tests {
             significant_moves: 8,
             total_inner_iterations: 25,
             history: vec![],
+            total_shots: 0,
+            aborted: false,
         };
 
         let py_result = PyTqqcResult::from(result);
@@ -395,6 +434,8 @@ mod tests {
         let record = IterationRecord {
             iteration: 5,
             delta: 0.12,
+            param_index: Some(0),
+            deltas: vec![0.12],
             parity_plus: 0.45,
             parity_minus: 0.42,
             parity_selected: 0.45,
@@ -402,6 +443,7 @@ mod tests {
             inner_count: 3,
             direction: Some(Direction::Plus),
             is_significant: true,
+            shots: 0,
         };
 
         let py_record = PyIterationRecord::from(record);
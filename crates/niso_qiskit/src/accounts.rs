@@ -0,0 +1,562 @@
+//! Encrypted IBM Quantum account store
+//!
+//! Gantree: L10_Qiskit → AccountStore
+//!
+//! Named accounts in the `~/.qiskit/qiskit-ibm.json` layout
+//! (`{name: {channel, token, url, instance, ...}}`), kept in a file
+//! encrypted with a key derived from a passphrase:
+//!
+//! - key: PBKDF2-HMAC-SHA256 over the passphrase with a random salt
+//! - cipher: AES-256-GCM with a fresh random nonce on every save
+//! - envelope: JSON with the KDF parameters, salt, nonce and ciphertext
+//!
+//! Decrypted, the payload is exactly the qiskit JSON, so accounts can be
+//! imported from and exported to the plaintext file.
+//!
+//! ```rust,ignore
+//! let mut store = AccountStore::open(AccountStore::default_path()?, passphrase)?;
+//! store.save_account("work", &credentials, false)?;
+//! let creds = store.credentials(Some("work"))?;
+//! ```
+
+use crate::auth::{AuthError, IbmCredentials, QiskitConfigEntry};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::num::NonZeroU32;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+/// Envelope format version
+pub const ACCOUNT_STORE_VERSION: u32 = 1;
+
+/// PBKDF2 iterations for new stores
+pub const DEFAULT_KDF_ITERATIONS: u32 = 600_000;
+
+/// Accepted PBKDF2 iteration counts; files outside the range are rejected
+/// before any key derivation
+pub const KDF_ITERATIONS_RANGE: RangeInclusive<u32> = 1_000..=10_000_000;
+
+const KDF: &str = "pbkdf2-hmac-sha256";
+const CIPHER: &str = "aes-256-gcm";
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+/// Encrypted file contents
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    kdf: String,
+    iterations: u32,
+    salt: String,
+    cipher: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Passphrase-encrypted store of named accounts
+/// Gantree: AccountStore // 암호화 계정 저장소
+pub struct AccountStore {
+    path: PathBuf,
+    key: [u8; KEY_LEN],
+    salt: [u8; SALT_LEN],
+    iterations: u32,
+    accounts: BTreeMap<String, QiskitConfigEntry>,
+}
+
+impl AccountStore {
+    /// Default location: `~/.qiskit/qiskit-ibm.enc.json`
+    pub fn default_path() -> Result<PathBuf, AuthError> {
+        dirs::home_dir()
+            .map(|home| home.join(".qiskit").join("qiskit-ibm.enc.json"))
+            .ok_or_else(|| AuthError::ConfigFileError("Could not determine home directory".into()))
+    }
+
+    /// Open the store at `path`, or start an empty one if it does not exist
+    /// Gantree: open(path,passphrase) -> AccountStore // 저장소 열기
+    pub fn open(path: impl Into<PathBuf>, passphrase: &str) -> Result<Self, AuthError> {
+        Self::open_with_iterations(path, passphrase, DEFAULT_KDF_ITERATIONS)
+    }
+
+    /// Like `open`, with the PBKDF2 iteration count for a new store
+    ///
+    /// An existing store keeps the count recorded in its file.
+    pub fn open_with_iterations(
+        path: impl Into<PathBuf>,
+        passphrase: &str,
+        iterations: u32,
+    ) -> Result<Self, AuthError> {
+        let path = path.into();
+        if !path.exists() {
+            check_iterations(iterations)?;
+            let mut salt = [0u8; SALT_LEN];
+            fill_random(&mut salt)?;
+            return Ok(Self {
+                key: derive_key(passphrase, &salt, iterations),
+                path,
+                salt,
+                iterations,
+                accounts: BTreeMap::new(),
+            });
+        }
+
+        let text = fs::read_to_string(&path)
+            .map_err(|e| AuthError::CredentialStore(format!("{}: {}", path.display(), e)))?;
+        let envelope: Envelope =
+            serde_json::from_str(&text).map_err(|e| AuthError::CredentialStore(e.to_string()))?;
+        if envelope.version != ACCOUNT_STORE_VERSION
+            || envelope.kdf != KDF
+            || envelope.cipher != CIPHER
+        {
+            return Err(AuthError::CredentialStore(format!(
+                "unsupported format v{} ({}, {})",
+                envelope.version, envelope.kdf, envelope.cipher
+            )));
+        }
+
+        check_iterations(envelope.iterations)?;
+        let salt: [u8; SALT_LEN] = decode_exact(&envelope.salt, "salt")?;
+        let nonce: [u8; NONCE_LEN] = decode_exact(&envelope.nonce, "nonce")?;
+        let mut ciphertext = STANDARD
+            .decode(&envelope.ciphertext)
+            .map_err(|e| AuthError::CredentialStore(format!("ciphertext: {}", e)))?;
+
+        let key = derive_key(passphrase, &salt, envelope.iterations);
+        let plaintext = aead_key(&key)
+            .open_in_place(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(CIPHER),
+                &mut ciphertext,
+            )
+            .map_err(|_| AuthError::WrongPassphrase)?;
+        let accounts = serde_json::from_slice(plaintext)
+            .map_err(|e| AuthError::ConfigParseError(e.to_string()))?;
+
+        Ok(Self {
+            path,
+            key,
+            salt,
+            iterations: envelope.iterations,
+            accounts,
+        })
+    }
+
+    /// File backing the store
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Saved account names, sorted
+    pub fn list(&self) -> Vec<&str> {
+        self.accounts.keys().map(String::as_str).collect()
+    }
+
+    /// Saved entry for `name`
+    pub fn get(&self, name: &str) -> Option<&QiskitConfigEntry> {
+        self.accounts.get(name)
+    }
+
+    /// Save `credentials` as account `name` and write the file
+    /// Gantree: save_account(name,credentials,overwrite) // 계정 저장
+    pub fn save_account(
+        &mut self,
+        name: &str,
+        credentials: &IbmCredentials,
+        overwrite: bool,
+    ) -> Result<(), AuthError> {
+        self.save_entry(
+            name,
+            QiskitConfigEntry::from_credentials(credentials),
+            overwrite,
+        )
+    }
+
+    /// Save a raw qiskit entry as account `name` and write the file
+    pub fn save_entry(
+        &mut self,
+        name: &str,
+        entry: QiskitConfigEntry,
+        overwrite: bool,
+    ) -> Result<(), AuthError> {
+        if !overwrite && self.accounts.contains_key(name) {
+            return Err(AuthError::AccountExists(name.to_string()));
+        }
+        self.accounts.insert(name.to_string(), entry);
+        self.write()
+    }
+
+    /// Delete account `name` and write the file; false if it was not saved
+    /// Gantree: delete_account(name) -> bool // 계정 삭제
+    pub fn delete_account(&mut self, name: &str) -> Result<bool, AuthError> {
+        if self.accounts.remove(name).is_none() {
+            return Ok(false);
+        }
+        self.write()?;
+        Ok(true)
+    }
+
+    /// Credentials for account `name`, or the default account
+    ///
+    /// The default follows qiskit: `is_default_account`, then a name
+    /// starting with "default", then the first account.
+    pub fn credentials(&self, name: Option<&str>) -> Result<IbmCredentials, AuthError> {
+        let (_, entry) = IbmCredentials::select_entry(&self.accounts, name)?;
+        Ok(IbmCredentials::from_entry(entry))
+    }
+
+    /// Import every account of a plaintext qiskit file; returns the count
+    ///
+    /// Existing accounts of the same name are replaced.
+    pub fn import_qiskit_config(&mut self, path: impl AsRef<Path>) -> Result<usize, AuthError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| AuthError::ConfigFileError(format!("{}: {}", path.display(), e)))?;
+        let accounts: BTreeMap<String, QiskitConfigEntry> =
+            serde_json::from_str(&text).map_err(|e| AuthError::ConfigParseError(e.to_string()))?;
+
+        let count = accounts.len();
+        self.accounts.extend(accounts);
+        self.write()?;
+        Ok(count)
+    }
+
+    /// Accounts as plaintext qiskit JSON
+    ///
+    /// The output contains the tokens in clear.
+    pub fn to_qiskit_json(&self) -> String {
+        serde_json::to_string_pretty(&self.accounts).expect("accounts serialize")
+    }
+
+    /// Re-encrypt under a new passphrase and write the file
+    pub fn change_passphrase(&mut self, passphrase: &str) -> Result<(), AuthError> {
+        fill_random(&mut self.salt)?;
+        self.key = derive_key(passphrase, &self.salt, self.iterations);
+        self.write()
+    }
+
+    /// Encrypt the accounts and replace the file atomically
+    fn write(&self) -> Result<(), AuthError> {
+        let io_err = |e: std::io::Error| AuthError::CredentialStore(e.to_string());
+
+        let mut nonce = [0u8; NONCE_LEN];
+        fill_random(&mut nonce)?;
+        let mut ciphertext = serde_json::to_vec(&self.accounts)
+            .map_err(|e| AuthError::CredentialStore(e.to_string()))?;
+        aead_key(&self.key)
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(CIPHER),
+                &mut ciphertext,
+            )
+            .map_err(|_| AuthError::CredentialStore("encryption failed".into()))?;
+
+        let envelope = Envelope {
+            version: ACCOUNT_STORE_VERSION,
+            kdf: KDF.to_string(),
+            iterations: self.iterations,
+            salt: STANDARD.encode(self.salt),
+            cipher: CIPHER.to_string(),
+            nonce: STANDARD.encode(nonce),
+            ciphertext: STANDARD.encode(&ciphertext),
+        };
+        let json = serde_json::to_string_pretty(&envelope)
+            .map_err(|e| AuthError::CredentialStore(e.to_string()))?;
+
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(io_err)?;
+        }
+        let tmp = self.path.with_extension("tmp");
+        let mut file = create_private(&tmp).map_err(io_err)?;
+        file.write_all(json.as_bytes()).map_err(io_err)?;
+        file.sync_all().map_err(io_err)?;
+        fs::rename(&tmp, &self.path).map_err(io_err)
+    }
+}
+
+impl fmt::Debug for AccountStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccountStore")
+            .field("path", &self.path)
+            .field("iterations", &self.iterations)
+            .field("accounts", &self.list())
+            .finish_non_exhaustive()
+    }
+}
+
+/// PBKDF2-HMAC-SHA256 key from a passphrase
+fn derive_key(passphrase: &str, salt: &[u8], iterations: u32) -> [u8; KEY_LEN] {
+    let mut key = [0u8; KEY_LEN];
+    let iterations = NonZeroU32::new(iterations).unwrap_or(NonZeroU32::MIN);
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    key
+}
+
+fn aead_key(key: &[u8; KEY_LEN]) -> LessSafeKey {
+    LessSafeKey::new(UnboundKey::new(&AES_256_GCM, key).expect("32-byte AES key"))
+}
+
+fn fill_random(buf: &mut [u8]) -> Result<(), AuthError> {
+    SystemRandom::new()
+        .fill(buf)
+        .map_err(|_| AuthError::CredentialStore("system random source failed".into()))
+}
+
+fn decode_exact<const N: usize>(value: &str, what: &str) -> Result<[u8; N], AuthError> {
+    STANDARD
+        .decode(value)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| AuthError::CredentialStore(format!("invalid {}", what)))
+}
+
+/// Reject iteration counts outside `KDF_ITERATIONS_RANGE`
+fn check_iterations(iterations: u32) -> Result<(), AuthError> {
+    if KDF_ITERATIONS_RANGE.contains(&iterations) {
+        return Ok(());
+    }
+    Err(AuthError::CredentialStore(format!(
+        "PBKDF2 iterations {} outside {}..={}",
+        iterations,
+        KDF_ITERATIONS_RANGE.start(),
+        KDF_ITERATIONS_RANGE.end()
+    )))
+}
+
+/// Create a new file, owner-only from the start on Unix
+///
+/// A stale file from an interrupted save is removed first, so its
+/// permissions are never inherited.
+fn create_private(path: &Path) -> std::io::Result<fs::File> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::IbmChannel;
+
+    const TOKEN: &str = "iqp_token_0123456789abcdef0123456789abcdef";
+    const API_KEY: &str = "ApiKey-0123456789abcdef0123456789abcdef";
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("niso_accounts_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("accounts.enc.json")
+    }
+
+    fn open(path: &Path, passphrase: &str) -> Result<AccountStore, AuthError> {
+        AccountStore::open_with_iterations(path, passphrase, 1_000)
+    }
+
+    #[test]
+    fn test_save_list_delete_roundtrip() {
+        let path = temp_path("roundtrip");
+        let mut store = open(&path, "correct horse").unwrap();
+        assert!(store.list().is_empty());
+        assert!(!path.exists());
+
+        store
+            .save_account("default-ibm", &IbmCredentials::new(TOKEN), false)
+            .unwrap();
+        let cloud = IbmCredentials::new(API_KEY)
+            .with_channel(IbmChannel::IbmCloud)
+            .with_instance("crn:v1:bluemix:public:quantum-computing:us-east:a/1::")
+            .with_url("https://us-east.quantum-computing.cloud.ibm.com")
+            .with_iam_url("https://iam.test.cloud.ibm.com/identity/token");
+        store.save_account("work", &cloud, false).unwrap();
+        assert!(matches!(
+            store.save_account("work", &cloud, false),
+            Err(AuthError::AccountExists(_))
+        ));
+
+        // The file holds no secret in clear
+        let raw = fs::read_to_string(&path).unwrap();
+        assert!(!raw.contains(TOKEN) && !raw.contains("ibm_cloud"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let reopened = open(&path, "correct horse").unwrap();
+        assert_eq!(reopened.list(), ["default-ibm", "work"]);
+        let work = reopened.credentials(Some("work")).unwrap();
+        assert_eq!(work.token(), API_KEY);
+        assert_eq!(work.channel(), IbmChannel::IbmCloud);
+        assert!(work.requires_iam_exchange());
+        assert_eq!(
+            work.runtime_url(),
+            "https://us-east.quantum-computing.cloud.ibm.com"
+        );
+        assert_eq!(
+            work.iam_url(),
+            "https://iam.test.cloud.ibm.com/identity/token"
+        );
+        assert_eq!(reopened.credentials(None).unwrap().token(), TOKEN);
+
+        let mut store = reopened;
+        assert!(store.delete_account("work").unwrap());
+        assert!(!store.delete_account("work").unwrap());
+        assert_eq!(
+            open(&path, "correct horse").unwrap().list(),
+            ["default-ibm"]
+        );
+        assert!(matches!(
+            store.credentials(Some("work")),
+            Err(AuthError::AccountNotFound(_))
+        ));
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_wrong_passphrase_and_tampering() {
+        let path = temp_path("passphrase");
+        let mut store = open(&path, "first").unwrap();
+        store
+            .save_account("default", &IbmCredentials::new(TOKEN), false)
+            .unwrap();
+
+        assert!(matches!(
+            open(&path, "second"),
+            Err(AuthError::WrongPassphrase)
+        ));
+
+        store.change_passphrase("second").unwrap();
+        assert!(matches!(
+            open(&path, "first"),
+            Err(AuthError::WrongPassphrase)
+        ));
+        assert_eq!(open(&path, "second").unwrap().list(), ["default"]);
+
+        // Flip one ciphertext byte: authentication fails
+        let mut envelope: Envelope =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let mut bytes = STANDARD.decode(&envelope.ciphertext).unwrap();
+        bytes[0] ^= 1;
+        envelope.ciphertext = STANDARD.encode(bytes);
+        fs::write(&path, serde_json::to_string(&envelope).unwrap()).unwrap();
+        assert!(matches!(
+            open(&path, "second"),
+            Err(AuthError::WrongPassphrase)
+        ));
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_iteration_count_bounds() {
+        let path = temp_path("iterations");
+        assert!(matches!(
+            AccountStore::open_with_iterations(&path, "pass", 1),
+            Err(AuthError::CredentialStore(_))
+        ));
+
+        let mut store = open(&path, "pass").unwrap();
+        store
+            .save_account("default", &IbmCredentials::new(TOKEN), false)
+            .unwrap();
+
+        // A file demanding an absurd count is rejected, not derived
+        for iterations in [0, 999, u32::MAX] {
+            let mut envelope: Envelope =
+                serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+            envelope.iterations = iterations;
+            fs::write(&path, serde_json::to_string(&envelope).unwrap()).unwrap();
+            assert!(matches!(
+                open(&path, "pass"),
+                Err(AuthError::CredentialStore(_))
+            ));
+        }
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_qiskit_json_import_export() {
+        let path = temp_path("import");
+        let plain = path.with_file_name("qiskit-ibm.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(
+            &plain,
+            r#"{
+                "default-ibm-quantum-platform": {
+                    "channel": "ibm_quantum_platform",
+                    "token": "plain_token_0123456789abcdef0123456789",
+                    "url": "https://quantum.cloud.ibm.com",
+                    "verify": true,
+                    "private_endpoint": false
+                },
+                "premium": {
+                    "channel": "ibm_cloud",
+                    "token": "ApiKey-premium0123456789abcdef01234567",
+                    "instance": "crn:v1:bluemix:premium",
+                    "is_default_account": true
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let mut store = open(&path, "pass").unwrap();
+        assert_eq!(store.import_qiskit_config(&plain).unwrap(), 2);
+
+        // is_default_account wins over the "default" prefix
+        let creds = open(&path, "pass").unwrap().credentials(None).unwrap();
+        assert_eq!(creds.instance(), Some("crn:v1:bluemix:premium"));
+
+        // Unknown keys survive the round trip
+        let exported: serde_json::Value = serde_json::from_str(&store.to_qiskit_json()).unwrap();
+        assert_eq!(exported["default-ibm-quantum-platform"]["verify"], true);
+        assert_eq!(exported["premium"]["is_default_account"], true);
+        assert_eq!(exported["premium"]["channel"], "ibm_cloud");
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_debug_redacts_tokens() {
+        let path = temp_path("debug");
+        let mut store = open(&path, "hunter2").unwrap();
+        store
+            .save_account("default", &IbmCredentials::new(TOKEN), false)
+            .unwrap();
+
+        let debug = format!("{:?} {:?}", store, store.get("default").unwrap());
+        assert!(!debug.contains(TOKEN));
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains("****cdef"));
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -9,9 +9,21 @@
 //! Supports two authentication methods:
 //! - Direct token (IQP tokens): Used as Bearer token directly
 //! - API Key (IBM Cloud): Exchanged for IAM access token first
+//!
+//! IAM access tokens are cached per credential set (shared by clones) and
+//! refreshed `TOKEN_REFRESH_MARGIN` before they expire, taking the earlier
+//! of the reported `expires_in` and the token's own `exp` claim. Concurrent
+//! callers wait for a single exchange.
+//!
+//! Tokens, API keys and access tokens are redacted in every `Debug` and
+//! `Display` output; only `token()` exposes the secret.
 
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::collections::HashMap;
+use std::fmt;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -53,31 +65,130 @@ pub enum AuthError {
     /// HTTP request error
     #[error("HTTP request failed: {0}")]
     HttpError(String),
+
+    /// Encrypted credential file could not be read or written
+    #[error("Credential store error: {0}")]
+    CredentialStore(String),
+
+    /// Encrypted credential file did not decrypt
+    #[error("Wrong passphrase or corrupted credential file")]
+    WrongPassphrase,
+
+    /// Named account already saved
+    #[error("Account '{0}' already exists; pass overwrite to replace it")]
+    AccountExists(String),
+
+    /// Named account not saved
+    #[error("Account '{0}' not found")]
+    AccountNotFound(String),
+}
+
+/// Refresh IAM access tokens this long before they expire
+pub const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Redacted view of a secret: `****` plus its last four characters
+/// Gantree: Redacted // 비밀 가림
+struct Redacted<'a>(&'a str);
+
+impl fmt::Display for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chars: Vec<char> = self.0.chars().collect();
+        if chars.len() < 12 {
+            write!(f, "****")
+        } else {
+            let tail: String = chars[chars.len() - 4..].iter().collect();
+            write!(f, "****{}", tail)
+        }
+    }
+}
+
+impl fmt::Debug for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self)
+    }
 }
 
 /// Qiskit config file entry (matches ~/.qiskit/qiskit-ibm.json format)
-#[derive(Debug, Clone, Deserialize)]
-#[allow(dead_code)]
-struct QiskitConfigEntry {
+/// Gantree: QiskitConfigEntry // 계정 항목
+///
+/// Keys this crate does not interpret (`verify`, `proxies`,
+/// `private_endpoint`, ...) are kept in `extra`, so entries round-trip.
+/// `url` keeps qiskit's meaning (the platform/auth URL) and is only used
+/// to detect the channel; the endpoint overrides of `IbmCredentials` are
+/// stored under their own `runtime_url` / `iam_url` keys.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QiskitConfigEntry {
     /// API token
-    token: String,
+    pub token: String,
 
     /// Channel type
-    #[serde(default)]
-    channel: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
 
-    /// URL
-    #[serde(default)]
-    url: Option<String>,
+    /// Platform/auth URL (qiskit's `url`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Runtime API URL override
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_url: Option<String>,
+
+    /// IAM token endpoint override
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iam_url: Option<String>,
 
     /// Instance/CRN
-    #[serde(default)]
-    instance: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
 
     /// Plans preference
-    #[serde(default)]
-    #[allow(dead_code)]
-    plans_preference: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plans_preference: Option<Vec<String>>,
+
+    /// Other keys written by qiskit-ibm-runtime
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl QiskitConfigEntry {
+    /// Entry for `credentials`, as qiskit's `save_account` writes it
+    pub fn from_credentials(credentials: &IbmCredentials) -> Self {
+        let channel = match credentials.channel {
+            IbmChannel::IbmQuantum => "ibm_quantum_platform",
+            IbmChannel::IbmCloud => "ibm_cloud",
+        };
+        Self {
+            token: credentials.api_token.clone(),
+            channel: Some(channel.to_string()),
+            runtime_url: credentials.runtime_url.clone(),
+            iam_url: credentials.iam_url.clone(),
+            instance: credentials
+                .instance
+                .clone()
+                .or_else(|| credentials.service_crn.clone()),
+            ..Self::default()
+        }
+    }
+
+    /// Whether qiskit marked this entry as the default account
+    pub fn is_default_account(&self) -> bool {
+        self.extra.get("is_default_account") == Some(&Value::Bool(true))
+    }
+}
+
+impl fmt::Debug for QiskitConfigEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QiskitConfigEntry")
+            .field("token", &Redacted(&self.token))
+            .field("channel", &self.channel)
+            .field("url", &self.url)
+            .field("runtime_url", &self.runtime_url)
+            .field("iam_url", &self.iam_url)
+            .field("instance", &self.instance)
+            .field("plans_preference", &self.plans_preference)
+            .field("extra", &self.extra)
+            .finish()
+    }
 }
 
 /// Type of API token/key
@@ -101,26 +212,78 @@ impl TokenType {
 }
 
 /// IAM token response from IBM Cloud
-#[derive(Debug, Clone, Deserialize)]
-#[allow(dead_code)]
+#[derive(Clone, Deserialize)]
 struct IamTokenResponse {
     access_token: String,
     #[serde(default)]
     expires_in: u64, // seconds until expiry
     #[serde(default)]
-    #[allow(dead_code)]
-    token_type: String,
+    expiration: Option<i64>, // unix time of expiry
+}
+
+impl fmt::Debug for IamTokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IamTokenResponse")
+            .field("access_token", &Redacted(&self.access_token))
+            .field("expires_in", &self.expires_in)
+            .field("expiration", &self.expiration)
+            .finish()
+    }
 }
 
 /// Cached IAM access token
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 struct CachedToken {
     access_token: String,
     expires_at: Instant,
+    info: Option<TokenInfo>,
+}
+
+impl CachedToken {
+    /// Cache an exchanged token until the earliest reported expiry
+    fn new(response: IamTokenResponse) -> Self {
+        let now = chrono::Utc::now().timestamp();
+        let info = TokenInfo::from_jwt(&response.access_token);
+        let lifetime = [
+            info.as_ref()
+                .and_then(|i| i.expires_at)
+                .map(|exp| exp - now),
+            response.expiration.map(|exp| exp - now),
+            Some(response.expires_in as i64).filter(|&s| s > 0),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(0)
+        .max(0);
+
+        Self {
+            access_token: response.access_token,
+            expires_at: Instant::now() + Duration::from_secs(lifetime as u64),
+            info,
+        }
+    }
+
+    /// Usable for at least `TOKEN_REFRESH_MARGIN` more
+    fn is_fresh(&self) -> bool {
+        self.expires_at > Instant::now() + TOKEN_REFRESH_MARGIN
+    }
+}
+
+impl fmt::Debug for CachedToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedToken")
+            .field("access_token", &Redacted(&self.access_token))
+            .field("expires_at", &self.expires_at)
+            .field("info", &self.info)
+            .finish()
+    }
 }
 
 /// IBM Quantum credentials
-#[derive(Debug, Clone)]
+///
+/// `Debug` and `Display` redact the token.
+#[derive(Clone)]
 pub struct IbmCredentials {
     /// API token or API key
     api_token: String,
@@ -139,6 +302,46 @@ pub struct IbmCredentials {
 
     /// Cached IAM access token (for API keys)
     cached_iam_token: Arc<RwLock<Option<CachedToken>>>,
+
+    /// Runtime API URL override (default: channel URL)
+    runtime_url: Option<String>,
+
+    /// IAM token endpoint override
+    iam_url: Option<String>,
+}
+
+impl fmt::Debug for IbmCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IbmCredentials")
+            .field("api_token", &Redacted(&self.api_token))
+            .field("token_type", &self.token_type)
+            .field("instance", &self.instance)
+            .field("channel", &self.channel)
+            .field("service_crn", &self.service_crn)
+            .field("runtime_url", &self.runtime_url)
+            .field("iam_url", &self.iam_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for IbmCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.token_type {
+            TokenType::IqpToken => "token",
+            TokenType::ApiKey => "API key",
+        };
+        write!(
+            f,
+            "{:?} {} {}",
+            self.channel,
+            kind,
+            Redacted(&self.api_token)
+        )?;
+        if let Some(instance) = &self.instance {
+            write!(f, " ({})", instance)?;
+        }
+        Ok(())
+    }
 }
 
 /// IBM Quantum channel
@@ -188,6 +391,8 @@ impl IbmCredentials {
             channel: IbmChannel::default(),
             service_crn: None,
             cached_iam_token: Arc::new(RwLock::new(None)),
+            runtime_url: None,
+            iam_url: None,
         }
     }
 
@@ -222,6 +427,8 @@ impl IbmCredentials {
             channel,
             service_crn,
             cached_iam_token: Arc::new(RwLock::new(None)),
+            runtime_url: None,
+            iam_url: None,
         })
     }
 
@@ -260,28 +467,7 @@ impl IbmCredentials {
         }
 
         // Find the appropriate credential entry
-        let (entry_name, entry) = if let Some(requested_name) = name {
-            // Look for specific named entry
-            config
-                .iter()
-                .find(|(k, _)| k.as_str() == requested_name)
-                .ok_or_else(|| {
-                    AuthError::ConfigParseError(format!(
-                        "Credential '{}' not found",
-                        requested_name
-                    ))
-                })?
-        } else {
-            // Look for default entry, or use first one
-            config
-                .iter()
-                .find(|(k, _)| k.starts_with("default"))
-                .or_else(|| config.iter().next())
-                .ok_or_else(|| AuthError::ConfigParseError("No credentials found".into()))?
-        };
-
-        // Determine channel from entry
-        let channel = Self::parse_channel_from_entry(entry);
+        let (entry_name, entry) = Self::select_entry(&config, name)?;
 
         log::info!(
             "Loaded IBM Quantum credentials from {} [{}]",
@@ -289,16 +475,44 @@ impl IbmCredentials {
             entry_name
         );
 
-        let token_type = TokenType::detect(&entry.token);
+        Ok(Self::from_entry(entry))
+    }
 
-        Ok(Self {
-            api_token: entry.token.clone(),
-            token_type,
-            instance: entry.instance.clone(),
-            channel,
-            service_crn: None,
-            cached_iam_token: Arc::new(RwLock::new(None)),
-        })
+    /// Pick the named entry, or the default one
+    ///
+    /// Without a name: the entry marked `is_default_account`, else the first
+    /// whose name starts with "default", else the first.
+    pub(crate) fn select_entry<'a>(
+        config: impl IntoIterator<Item = (&'a String, &'a QiskitConfigEntry)>,
+        name: Option<&str>,
+    ) -> Result<(&'a String, &'a QiskitConfigEntry), AuthError> {
+        let mut entries: Vec<_> = config.into_iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+
+        if let Some(requested_name) = name {
+            return entries
+                .into_iter()
+                .find(|(k, _)| k.as_str() == requested_name)
+                .ok_or_else(|| AuthError::AccountNotFound(requested_name.to_string()));
+        }
+
+        entries
+            .iter()
+            .find(|(_, e)| e.is_default_account())
+            .or_else(|| entries.iter().find(|(k, _)| k.starts_with("default")))
+            .or_else(|| entries.first())
+            .copied()
+            .ok_or_else(|| AuthError::ConfigParseError("No credentials found".into()))
+    }
+
+    /// Create credentials from a qiskit config entry
+    pub fn from_entry(entry: &QiskitConfigEntry) -> Self {
+        let mut creds =
+            Self::new(entry.token.clone()).with_channel(Self::parse_channel_from_entry(entry));
+        creds.instance = entry.instance.clone();
+        creds.runtime_url = entry.runtime_url.clone();
+        creds.iam_url = entry.iam_url.clone();
+        creds
     }
 
     /// Parse channel type from config entry
@@ -362,6 +576,30 @@ impl IbmCredentials {
         self
     }
 
+    /// Override the runtime API URL (e.g. a local mock server)
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.runtime_url = Some(url.into());
+        self
+    }
+
+    /// Override the IAM token endpoint
+    pub fn with_iam_url(mut self, url: impl Into<String>) -> Self {
+        self.iam_url = Some(url.into());
+        self
+    }
+
+    /// Get runtime API URL (override or channel default)
+    pub fn runtime_url(&self) -> String {
+        self.runtime_url
+            .clone()
+            .unwrap_or_else(|| self.channel.runtime_url())
+    }
+
+    /// Get IAM token endpoint
+    pub fn iam_url(&self) -> &str {
+        self.iam_url.as_deref().unwrap_or(IAM_TOKEN_URL)
+    }
+
     /// Get API token
     pub fn token(&self) -> &str {
         &self.api_token
@@ -418,22 +656,54 @@ impl IbmCredentials {
         // Check cached token first
         {
             let cache = self.cached_iam_token.read().await;
-            if let Some(cached) = cache.as_ref() {
-                // Return cached token if not expired (with 60s buffer)
-                if cached.expires_at > Instant::now() + Duration::from_secs(60) {
-                    return Ok(cached.access_token.clone());
-                }
+            if let Some(cached) = cache.as_ref().filter(|c| c.is_fresh()) {
+                return Ok(cached.access_token.clone());
             }
         }
 
-        // Exchange API key for IAM access token
-        let access_token = self.exchange_api_key_for_iam_token().await?;
+        // Hold the write lock across the exchange so concurrent callers
+        // wait for this refresh instead of starting their own
+        let mut cache = self.cached_iam_token.write().await;
+        if let Some(cached) = cache.as_ref().filter(|c| c.is_fresh()) {
+            return Ok(cached.access_token.clone());
+        }
 
+        let response = self.exchange_api_key_for_iam_token().await?;
+        let cached = CachedToken::new(response);
+        log::info!(
+            "IAM token obtained, expires in {}s",
+            cached
+                .expires_at
+                .saturating_duration_since(Instant::now())
+                .as_secs()
+        );
+
+        let access_token = cached.access_token.clone();
+        *cache = Some(cached);
         Ok(access_token)
     }
 
+    /// Claims of the cached IAM access token, if it is a JWT
+    pub async fn token_info(&self) -> Option<TokenInfo> {
+        let cache = self.cached_iam_token.read().await;
+        cache.as_ref().and_then(|c| c.info.clone())
+    }
+
+    /// Time until the cached IAM access token expires
+    pub async fn token_expires_in(&self) -> Option<Duration> {
+        let cache = self.cached_iam_token.read().await;
+        cache
+            .as_ref()
+            .map(|c| c.expires_at.saturating_duration_since(Instant::now()))
+    }
+
+    /// Drop the cached IAM access token; the next request exchanges again
+    pub async fn invalidate_token(&self) {
+        *self.cached_iam_token.write().await = None;
+    }
+
     /// Exchange API key for IAM access token
-    async fn exchange_api_key_for_iam_token(&self) -> Result<String, AuthError> {
+    async fn exchange_api_key_for_iam_token(&self) -> Result<IamTokenResponse, AuthError> {
         let client = reqwest::Client::new();
 
         // Strip "ApiKey-" prefix if present
@@ -449,7 +719,7 @@ impl IbmCredentials {
         ];
 
         let response = client
-            .post(IAM_TOKEN_URL)
+            .post(self.iam_url())
             .header("Content-Type", "application/x-www-form-urlencoded")
             .header("Accept", "application/json")
             .form(&params)
@@ -466,27 +736,10 @@ impl IbmCredentials {
             )));
         }
 
-        let token_response: IamTokenResponse = response
+        response
             .json()
             .await
-            .map_err(|e| AuthError::IamTokenExchangeFailed(e.to_string()))?;
-
-        // Cache the token
-        let expires_at = Instant::now() + Duration::from_secs(token_response.expires_in);
-        {
-            let mut cache = self.cached_iam_token.write().await;
-            *cache = Some(CachedToken {
-                access_token: token_response.access_token.clone(),
-                expires_at,
-            });
-        }
-
-        log::info!(
-            "IAM token obtained, expires in {}s",
-            token_response.expires_in
-        );
-
-        Ok(token_response.access_token)
+            .map_err(|e| AuthError::IamTokenExchangeFailed(e.to_string()))
     }
 
     /// Validate token format (basic check)
@@ -511,21 +764,43 @@ impl IbmCredentials {
 }
 
 /// Token information response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Also the claims of a JWT access token (`TokenInfo::from_jwt`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TokenInfo {
     /// User ID
-    #[serde(rename = "userId")]
+    #[serde(rename = "userId", alias = "iam_id", default)]
     pub user_id: Option<String>,
 
     /// Token expiry
-    #[serde(rename = "exp")]
+    #[serde(rename = "exp", default)]
     pub expires_at: Option<i64>,
 
     /// Token issued at
-    #[serde(rename = "iat")]
+    #[serde(rename = "iat", default)]
     pub issued_at: Option<i64>,
 }
 
+impl TokenInfo {
+    /// Decode the claims of a JWT (the signature is not verified)
+    pub fn from_jwt(token: &str) -> Option<Self> {
+        let payload = token.split('.').nth(1)?;
+        let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    /// Time left until `exp`, zero once expired
+    pub fn expires_in(&self) -> Option<Duration> {
+        let left = self.expires_at? - chrono::Utc::now().timestamp();
+        Some(Duration::from_secs(left.max(0) as u64))
+    }
+
+    /// Whether the token expires within `margin`
+    pub fn expires_within(&self, margin: Duration) -> bool {
+        self.expires_in().is_some_and(|left| left <= margin)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -633,8 +908,11 @@ mod tests {
             token: "test".into(),
             channel: Some("ibm_quantum_platform".into()),
             url: None,
+            runtime_url: None,
+            iam_url: None,
             instance: None,
             plans_preference: None,
+            extra: Map::new(),
         };
         assert_eq!(
             IbmCredentials::parse_channel_from_entry(&quantum_entry),
@@ -646,8 +924,11 @@ mod tests {
             token: "test".into(),
             channel: Some("ibm_cloud".into()),
             url: None,
+            runtime_url: None,
+            iam_url: None,
             instance: None,
             plans_preference: None,
+            extra: Map::new(),
         };
         assert_eq!(
             IbmCredentials::parse_channel_from_entry(&cloud_entry),
@@ -685,4 +966,176 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_iam_exchange_is_cached() {
+        let mock = crate::mock::MockRuntime::start();
+        let creds = mock.api_key_credentials();
+        assert!(creds.requires_iam_exchange());
+
+        let first = creds.auth_header_async().await.unwrap();
+        let second = creds.auth_header_async().await.unwrap();
+
+        assert_eq!(first, "Bearer mock_iam_token_1");
+        assert_eq!(first, second);
+        assert_eq!(mock.iam_tokens_issued(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_iam_token_refreshed_near_expiry() {
+        let mock = crate::mock::MockRuntime::start();
+        // Within the 60s refresh buffer: every call exchanges again
+        mock.set_iam_expires_in(30);
+        let creds = mock.api_key_credentials();
+
+        creds.auth_header_async().await.unwrap();
+        let header = creds.auth_header_async().await.unwrap();
+
+        assert_eq!(header, "Bearer mock_iam_token_2");
+        assert_eq!(mock.iam_tokens_issued(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_iam_exchange_rejects_unknown_key() {
+        let mock = crate::mock::MockRuntime::start();
+        let creds = IbmCredentials::new("ApiKey-unknown0123456789abcdef0123456789")
+            .with_iam_url(mock.iam_url());
+
+        let result = creds.auth_header_async().await;
+        assert!(matches!(result, Err(AuthError::IamTokenExchangeFailed(_))));
+        assert_eq!(mock.iam_tokens_issued(), 0);
+    }
+
+    #[test]
+    fn test_debug_and_display_redact_token() {
+        let token = "secret_iqp_token_0123456789abcdef01234567wxyz";
+        let creds = IbmCredentials::new(token).with_instance("ibm-q/open/main");
+
+        for text in [format!("{:?}", creds), format!("{}", creds)] {
+            assert!(!text.contains(token), "{}", text);
+            assert!(text.contains("****wxyz"), "{}", text);
+        }
+        assert_eq!(
+            creds.to_string(),
+            "IbmQuantum token ****wxyz (ibm-q/open/main)"
+        );
+
+        // Short secrets are hidden entirely
+        assert_eq!(Redacted("abc").to_string(), "****");
+
+        let response = IamTokenResponse {
+            access_token: "eyJhbGciOi.payload.signature_abcd".into(),
+            expires_in: 3600,
+            expiration: None,
+        };
+        assert!(!format!("{:?}", response).contains("payload"));
+        assert!(!format!("{:?}", CachedToken::new(response)).contains("payload"));
+    }
+
+    #[test]
+    fn test_token_info_from_jwt() {
+        let claims = r#"{"iam_id":"IBMid-123","exp":2000000000,"iat":1999996400}"#;
+        let jwt = format!("e30.{}.sig", URL_SAFE_NO_PAD.encode(claims));
+
+        let info = TokenInfo::from_jwt(&jwt).unwrap();
+        assert_eq!(info.user_id.as_deref(), Some("IBMid-123"));
+        assert_eq!(info.expires_at, Some(2_000_000_000));
+        assert_eq!(info.issued_at, Some(1_999_996_400));
+        assert!(!info.expires_within(Duration::from_secs(60)));
+
+        let expired = TokenInfo {
+            expires_at: Some(1),
+            ..info
+        };
+        assert_eq!(expired.expires_in(), Some(Duration::ZERO));
+        assert!(expired.expires_within(Duration::ZERO));
+
+        assert!(TokenInfo::from_jwt("mock_iam_token_1").is_none());
+    }
+
+    #[test]
+    fn test_cached_token_uses_earliest_expiry() {
+        let now = chrono::Utc::now().timestamp();
+        let claims = format!(r#"{{"exp":{},"iat":{}}}"#, now + 30, now);
+        let response = IamTokenResponse {
+            access_token: format!("e30.{}.sig", URL_SAFE_NO_PAD.encode(claims)),
+            expires_in: 3600,
+            expiration: Some(now + 3600),
+        };
+
+        let cached = CachedToken::new(response);
+        assert!(cached.expires_at <= Instant::now() + Duration::from_secs(30));
+        assert!(!cached.is_fresh());
+    }
+
+    #[tokio::test]
+    async fn test_iam_token_refreshed_before_jwt_exp() {
+        let mock = crate::mock::MockRuntime::start();
+        // expires_in says an hour, the token itself expires in 30s
+        mock.set_iam_jwt_exp_in(30);
+        let creds = mock.api_key_credentials();
+
+        creds.auth_header_async().await.unwrap();
+        let info = creds.token_info().await.unwrap();
+        assert!(info.expires_within(TOKEN_REFRESH_MARGIN));
+        assert!(creds.token_expires_in().await.unwrap() <= Duration::from_secs(30));
+
+        creds.auth_header_async().await.unwrap();
+        assert_eq!(mock.iam_tokens_issued(), 2);
+
+        // A long-lived JWT is reused
+        mock.set_iam_jwt_exp_in(3600);
+        creds.invalidate_token().await;
+        creds.auth_header_async().await.unwrap();
+        creds.auth_header_async().await.unwrap();
+        assert_eq!(mock.iam_tokens_issued(), 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_callers_share_one_exchange() {
+        let mock = crate::mock::MockRuntime::start();
+        let creds = mock.api_key_credentials();
+
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let creds = creds.clone();
+                tokio::spawn(async move { creds.auth_header_async().await.unwrap() })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap(), "Bearer mock_iam_token_1");
+        }
+
+        assert_eq!(mock.iam_tokens_issued(), 1);
+    }
+
+    #[test]
+    fn test_select_entry_prefers_marked_default() {
+        let mut config = HashMap::new();
+        config.insert(
+            "default-old".to_string(),
+            QiskitConfigEntry {
+                token: "old".into(),
+                ..Default::default()
+            },
+        );
+        let mut marked = QiskitConfigEntry {
+            token: "marked".into(),
+            ..Default::default()
+        };
+        marked
+            .extra
+            .insert("is_default_account".into(), Value::Bool(true));
+        config.insert("team".to_string(), marked);
+
+        let (name, entry) = IbmCredentials::select_entry(&config, None).unwrap();
+        assert_eq!((name.as_str(), entry.token.as_str()), ("team", "marked"));
+
+        let (_, entry) = IbmCredentials::select_entry(&config, Some("default-old")).unwrap();
+        assert_eq!(entry.token, "old");
+        assert!(matches!(
+            IbmCredentials::select_entry(&config, Some("missing")),
+            Err(AuthError::AccountNotFound(_))
+        ));
+    }
 }
@@ -1,17 +1,36 @@
 //! IBM Quantum backend implementation
 //!
 //! Gantree: L10_Qiskit → IbmBackend
+//!
+//! `IbmBackend` implements both `Backend` (blocking) and `AsyncBackend`.
+//! Every request runs on the backend's own tokio runtime; the async
+//! methods only await the spawned tasks, so they can be used from any
+//! executor, including inside another tokio runtime where the blocking
+//! methods would panic. Inside a tokio runtime, build the backend with
+//! `IbmBackend::new_async` (`new` blocks); dropping it there is safe, but
+//! an open session is then left to expire instead of being closed.
+//!
+//! With a session open (`with_session`, `open_session`, `in_session`) every
+//! job is submitted into it, so an optimization keeps device priority
+//! across steps. A session closed by its max TTL is reopened on the next
+//! submission.
 
 use crate::auth::{AuthError, IbmCredentials};
+use crate::calibration::properties_to_calibration;
 use crate::client::{BackendConfig, BackendProperties, ClientError, IbmClient};
-use crate::job::{JobError, JobManager, JobParams, JobSubmission};
+use crate::job::{IbmJob, JobError, JobManager, JobParams, JobResult, JobStatus, JobSubmission};
+use crate::session::{IbmSession, SessionMode};
 use crate::transpiler::{Transpiler, TranspilerConfig};
-use niso_backend::{Backend, ExecutionMetadata, ExecutionResult};
+use niso_backend::{
+    AsyncBackend, Backend, BoxFuture, ExecutionMetadata, ExecutionResult, JobHandle, JobPoll,
+};
 use niso_calibration::CalibrationInfo;
 use niso_core::{Circuit, NisoError, NisoResult};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use thiserror::Error;
 use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
 
 /// IBM Backend errors
 #[derive(Debug, Error)]
@@ -41,6 +60,12 @@ pub enum IbmBackendError {
     Runtime(String),
 }
 
+impl From<IbmBackendError> for NisoError {
+    fn from(e: IbmBackendError) -> Self {
+        NisoError::BackendError(e.to_string())
+    }
+}
+
 /// IBM Quantum backend
 pub struct IbmBackend {
     /// Backend name
@@ -64,8 +89,8 @@ pub struct IbmBackend {
     /// Cached calibration
     calibration: Option<CalibrationInfo>,
 
-    /// Tokio runtime for async operations
-    runtime: Runtime,
+    /// Tokio runtime for async operations (taken only in `Drop`)
+    runtime: Option<Runtime>,
 
     /// Job timeout
     timeout: Duration,
@@ -75,33 +100,72 @@ pub struct IbmBackend {
 
     /// Number of qubits
     num_qubits: usize,
+
+    /// Open session jobs are submitted into
+    session: SessionScope,
 }
 
 impl IbmBackend {
     /// Create new IBM backend
     ///
     /// Handles both IQP tokens and API keys (with automatic IAM exchange).
+    /// Blocks on the backend runtime, so it panics inside a tokio runtime;
+    /// use `new_async` there.
     pub fn new(
         name: impl Into<String>,
         credentials: IbmCredentials,
     ) -> Result<Self, IbmBackendError> {
         let name = name.into();
+        let runtime = Runtime::new().map_err(|e| IbmBackendError::Runtime(e.to_string()))?;
+        let (client, config) = runtime.block_on(Self::connect(name.clone(), credentials))?;
+        Ok(Self::from_parts(name, client, config, runtime))
+    }
 
+    /// Create new IBM backend from async code
+    ///
+    /// Same as `new`, but the setup requests are awaited instead of
+    /// blocked on, so it can run inside any executor.
+    pub async fn new_async(
+        name: impl Into<String>,
+        credentials: IbmCredentials,
+    ) -> Result<Self, IbmBackendError> {
+        let name = name.into();
         let runtime = Runtime::new().map_err(|e| IbmBackendError::Runtime(e.to_string()))?;
+        let setup = runtime
+            .spawn(Self::connect(name.clone(), credentials))
+            .await;
+        let (client, config) = match setup {
+            Ok(connected) => connected?,
+            Err(e) => {
+                runtime.shutdown_background();
+                return Err(IbmBackendError::Runtime(e.to_string()));
+            }
+        };
+        Ok(Self::from_parts(name, client, config, runtime))
+    }
 
-        // Create client (async for API keys, sync for IQP tokens)
+    /// Create the client (async for API keys) and fetch the backend config
+    async fn connect(
+        name: String,
+        credentials: IbmCredentials,
+    ) -> Result<(IbmClient, Option<BackendConfig>), IbmBackendError> {
         let client = if credentials.requires_iam_exchange() {
-            runtime.block_on(async { IbmClient::new_async(credentials).await })?
+            IbmClient::new_async(credentials).await?
         } else {
             IbmClient::new(credentials)?
         };
+        let config = client.get_backend_config(&name).await.ok();
+        Ok((client, config))
+    }
 
+    /// Assemble a backend around a connected client
+    fn from_parts(
+        name: String,
+        client: IbmClient,
+        config: Option<BackendConfig>,
+        runtime: Runtime,
+    ) -> Self {
         let job_manager = JobManager::new(client.clone());
-
-        // Fetch backend config
-        let config: Option<BackendConfig> =
-            runtime.block_on(async { client.get_backend_config(&name).await.ok() });
-
         let num_qubits = config.as_ref().and_then(|c| c.n_qubits).unwrap_or(127);
 
         // Configure transpiler
@@ -128,7 +192,7 @@ impl IbmBackend {
             TranspilerConfig::default()
         };
 
-        Ok(Self {
+        Self {
             name,
             client,
             job_manager,
@@ -136,11 +200,19 @@ impl IbmBackend {
             config,
             properties: None,
             calibration: None,
-            runtime,
+            runtime: Some(runtime),
             timeout: Duration::from_secs(3600),
             use_qasm3: true,
             num_qubits,
-        })
+            session: SessionScope::default(),
+        }
+    }
+
+    /// Backend runtime (present until the backend is dropped)
+    fn runtime(&self) -> &Runtime {
+        self.runtime
+            .as_ref()
+            .expect("runtime is only taken on drop")
     }
 
     /// Create from environment variables only
@@ -179,12 +251,30 @@ impl IbmBackend {
         self
     }
 
+    /// Set the job status polling interval
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.job_manager = JobManager::new(self.client.clone()).with_poll_interval(interval);
+        self
+    }
+
     /// Use QASM 2 instead of QASM 3
     pub fn with_qasm2(mut self) -> Self {
         self.use_qasm3 = false;
         self
     }
 
+    /// Open a session that all later jobs run in
+    ///
+    /// The session is closed when the backend is dropped.
+    pub fn with_session(
+        self,
+        mode: SessionMode,
+        max_ttl: Duration,
+    ) -> Result<Self, IbmBackendError> {
+        self.open_session(mode, max_ttl)?;
+        Ok(self)
+    }
+
     /// Get backend configuration
     pub fn config(&self) -> Option<&BackendConfig> {
         self.config.as_ref()
@@ -193,7 +283,7 @@ impl IbmBackend {
     /// Refresh backend properties (calibration data)
     pub fn refresh_properties(&mut self) -> Result<&BackendProperties, IbmBackendError> {
         let props = self
-            .runtime
+            .runtime()
             .block_on(async { self.client.get_backend_properties(&self.name).await })?;
 
         self.properties = Some(props);
@@ -207,43 +297,11 @@ impl IbmBackend {
     /// Update calibration from properties
     fn update_calibration(&mut self) {
         if let Some(ref props) = self.properties {
-            let mut cal = CalibrationInfo::new(&self.name);
-
-            // Extract T1, T2, errors from properties
-            if let Some(ref qubits) = props.qubits {
-                for (i, qubit_props) in qubits.iter().enumerate() {
-                    for prop in qubit_props {
-                        match prop.name.as_str() {
-                            "T1" => {
-                                cal.t1_times.insert(i, prop.value);
-                            }
-                            "T2" => {
-                                cal.t2_times.insert(i, prop.value);
-                            }
-                            "readout_error" | "prob_meas0_prep1" => {
-                                cal.readout_errors.insert(i, prop.value);
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-            }
-
-            // Extract 2-qubit errors
-            if let Some(ref gates) = props.gates {
-                for gate in gates {
-                    if (gate.gate == "cx" || gate.gate == "ecr") && gate.qubits.len() >= 2 {
-                        for param in &gate.parameters {
-                            if param.name == "gate_error" {
-                                cal.gate_errors_2q
-                                    .insert((gate.qubits[0], gate.qubits[1]), param.value);
-                            }
-                        }
-                    }
-                }
-            }
-
-            self.calibration = Some(cal);
+            self.calibration = Some(properties_to_calibration(
+                &self.name,
+                props,
+                self.config.as_ref(),
+            ));
         }
     }
 
@@ -255,7 +313,7 @@ impl IbmBackend {
     /// Check if backend is operational
     pub fn is_operational(&self) -> Result<bool, IbmBackendError> {
         let status = self
-            .runtime
+            .runtime()
             .block_on(async { self.client.get_backend_status(&self.name).await })?;
         Ok(status.operational)
     }
@@ -263,7 +321,7 @@ impl IbmBackend {
     /// Get pending jobs count
     pub fn pending_jobs(&self) -> Result<u64, IbmBackendError> {
         let status = self
-            .runtime
+            .runtime()
             .block_on(async { self.client.get_backend_status(&self.name).await })?;
         Ok(status.pending_jobs.unwrap_or(0))
     }
@@ -274,87 +332,25 @@ impl IbmBackend {
         circuit: &Circuit,
         shots: u64,
     ) -> Result<ExecutionResult, IbmBackendError> {
-        // Validate circuit
-        self.transpiler
-            .validate(circuit)
-            .map_err(IbmBackendError::Transpilation)?;
-
-        // Transpile to QASM
-        let qasm = if self.use_qasm3 {
-            self.transpiler.to_qasm3(circuit)
-        } else {
-            self.transpiler.to_qasm2(circuit)
-        };
-
-        // Submit job
-        let result = self.runtime.block_on(async {
-            let submission = JobSubmission {
-                program_id: "sampler".to_string(),
-                backend: self.name.clone(),
-                params: JobParams::new(vec![qasm]).with_shots(shots),
-                tags: vec!["niso".to_string()],
-            };
-
-            let mut job = self.job_manager.submit(submission).await?;
-
-            // Wait for completion
-            job.wait(self.timeout).await?;
-
-            // Get results
-            job.result().await
-        })?;
-
-        // Convert to ExecutionResult
-        let counts = result
-            .results
-            .first()
-            .and_then(|r| r.counts.clone())
-            .unwrap_or_default();
-
-        Ok(ExecutionResult {
-            counts,
-            shots,
-            metadata: ExecutionMetadata {
-                backend: self.name.clone(),
-                job_id: result.job_id,
-                simulated: false,
-                ..Default::default()
-            },
-        })
+        self.execute_batch(std::slice::from_ref(circuit), shots)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| JobError::ExecutionFailed("job returned no results".to_string()).into())
     }
 
     /// Submit job asynchronously (returns job ID)
     pub fn submit_async(&self, circuit: &Circuit, shots: u64) -> Result<String, IbmBackendError> {
-        // Validate and transpile
-        self.transpiler
-            .validate(circuit)
-            .map_err(IbmBackendError::Transpilation)?;
-
-        let qasm = if self.use_qasm3 {
-            self.transpiler.to_qasm3(circuit)
-        } else {
-            self.transpiler.to_qasm2(circuit)
-        };
-
-        // Submit job
-        let job_id = self.runtime.block_on(async {
-            let submission = JobSubmission {
-                program_id: "sampler".to_string(),
-                backend: self.name.clone(),
-                params: JobParams::new(vec![qasm]).with_shots(shots),
-                tags: vec!["niso".to_string()],
-            };
-
-            let job = self.job_manager.submit(submission).await?;
-            Ok::<_, JobError>(job.id().to_string())
-        })?;
-
-        Ok(job_id)
+        let submission = self.submission(
+            self.transpile_all(std::slice::from_ref(circuit))?,
+            shots,
+            &["niso"],
+        );
+        self.submit_detached(submission)
     }
 
     /// Get results for a submitted job
     pub fn get_results(&self, job_id: &str) -> Result<ExecutionResult, IbmBackendError> {
-        let result = self.runtime.block_on(async {
+        let result = self.runtime().block_on(async {
             let mut job = self.job_manager.get_job(job_id).await?;
 
             if job.status().is_running() {
@@ -390,8 +386,85 @@ impl IbmBackend {
         circuits: &[Circuit],
         shots: u64,
     ) -> Result<Vec<ExecutionResult>, IbmBackendError> {
-        // Validate and transpile all circuits
-        let qasm_circuits: Result<Vec<String>, IbmBackendError> = circuits
+        let job = self.spawn_job(circuits, shots)?;
+        let result = self.runtime().block_on(join(job))?;
+        Ok(self.to_execution_results(result, shots))
+    }
+
+    /// Submit batch job asynchronously
+    pub fn submit_batch_async(
+        &self,
+        circuits: &[Circuit],
+        shots: u64,
+    ) -> Result<String, IbmBackendError> {
+        let submission = self.submission(self.transpile_all(circuits)?, shots, &["niso", "batch"]);
+        self.submit_detached(submission)
+    }
+
+    // ========================================================================
+    // Sessions
+    // ========================================================================
+
+    /// Open a session that later jobs run in, closing any current one
+    /// Gantree: open_session(mode,max_ttl) -> String // 세션 시작
+    pub fn open_session(
+        &self,
+        mode: SessionMode,
+        max_ttl: Duration,
+    ) -> Result<String, IbmBackendError> {
+        self.close_session()?;
+
+        let manager = self.job_manager.clone();
+        let name = self.name.clone();
+        let session = self
+            .runtime()
+            .block_on(join(self.runtime().spawn(async move {
+                IbmSession::open(&manager, name, mode, max_ttl).await
+            })))?;
+
+        let id = session.id().to_string();
+        self.session.set(Some(session));
+        Ok(id)
+    }
+
+    /// Close the current session; its queued jobs still run
+    pub fn close_session(&self) -> Result<(), IbmBackendError> {
+        match self.session.set(None) {
+            Some(session) => Ok(self
+                .runtime()
+                .block_on(join(self.runtime().spawn(session.close())))?),
+            None => Ok(()),
+        }
+    }
+
+    /// Current session, if one is open
+    pub fn session(&self) -> Option<IbmSession> {
+        self.session.current()
+    }
+
+    /// Run `f` with all jobs inside a fresh session, then close it
+    /// Gantree: in_session(mode,max_ttl,f) -> R // 세션 범위 실행
+    pub fn in_session<R>(
+        &self,
+        mode: SessionMode,
+        max_ttl: Duration,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Result<R, IbmBackendError> {
+        self.open_session(mode, max_ttl)?;
+        let output = f(self);
+        if let Err(e) = self.close_session() {
+            log::warn!("Failed to close session of {}: {}", self.name, e);
+        }
+        Ok(output)
+    }
+
+    // ========================================================================
+    // Job Helpers
+    // ========================================================================
+
+    /// Validate and transpile circuits to the configured QASM version
+    fn transpile_all(&self, circuits: &[Circuit]) -> Result<Vec<String>, IbmBackendError> {
+        circuits
             .iter()
             .map(|circuit| {
                 self.transpiler
@@ -404,26 +477,52 @@ impl IbmBackend {
                     self.transpiler.to_qasm2(circuit)
                 })
             })
-            .collect();
+            .collect()
+    }
 
-        let qasm_circuits = qasm_circuits?;
+    /// Sampler submission for transpiled circuits
+    fn submission(&self, qasm: Vec<String>, shots: u64, tags: &[&str]) -> JobSubmission {
+        JobSubmission {
+            program_id: "sampler".to_string(),
+            backend: self.name.clone(),
+            params: JobParams::new(qasm).with_shots(shots),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            session_id: None,
+        }
+    }
 
-        // Submit batch job
-        let result = self.runtime.block_on(async {
-            let submission = JobSubmission {
-                program_id: "sampler".to_string(),
-                backend: self.name.clone(),
-                params: JobParams::new(qasm_circuits).with_shots(shots),
-                tags: vec!["niso".to_string(), "batch".to_string()],
-            };
+    /// Submit without waiting; returns the job ID
+    fn submit_detached(&self, submission: JobSubmission) -> Result<String, IbmBackendError> {
+        let manager = self.job_manager.clone();
+        let scope = self.session.clone();
+        self.runtime()
+            .block_on(join(self.runtime().spawn(async move {
+                let job = scope.submit(&manager, submission).await?;
+                Ok(job.id().to_string())
+            })))
+    }
 
-            let mut job = self.job_manager.submit(submission).await?;
-            job.wait(self.timeout).await?;
+    /// Spawn submit → wait → results for one job on the backend runtime
+    fn spawn_job(
+        &self,
+        circuits: &[Circuit],
+        shots: u64,
+    ) -> Result<JoinHandle<Result<JobResult, JobError>>, IbmBackendError> {
+        let submission = self.submission(self.transpile_all(circuits)?, shots, &["niso", "batch"]);
+        let manager = self.job_manager.clone();
+        let scope = self.session.clone();
+        let timeout = self.timeout;
+
+        Ok(self.runtime().spawn(async move {
+            let mut job = scope.submit(&manager, submission).await?;
+            job.wait(timeout).await?;
             job.result().await
-        })?;
+        }))
+    }
 
-        // Convert results
-        let results: Vec<_> = result
+    /// Convert a job result to one `ExecutionResult` per circuit
+    fn to_execution_results(&self, result: JobResult, shots: u64) -> Vec<ExecutionResult> {
+        result
             .results
             .iter()
             .enumerate()
@@ -444,50 +543,83 @@ impl IbmBackend {
                     },
                 }
             })
-            .collect();
-
-        Ok(results)
+            .collect()
     }
+}
 
-    /// Submit batch job asynchronously
-    pub fn submit_batch_async(
-        &self,
-        circuits: &[Circuit],
-        shots: u64,
-    ) -> Result<String, IbmBackendError> {
-        let qasm_circuits: Result<Vec<String>, IbmBackendError> = circuits
-            .iter()
-            .map(|circuit| {
-                self.transpiler
-                    .validate(circuit)
-                    .map_err(IbmBackendError::Transpilation)?;
+impl Drop for IbmBackend {
+    fn drop(&mut self) {
+        // Blocking is only possible outside an async context; inside one
+        // the runtime must not be dropped (it would block) either
+        if tokio::runtime::Handle::try_current().is_err() {
+            if let Err(e) = self.close_session() {
+                log::warn!("Failed to close session of {}: {}", self.name, e);
+            }
+        } else if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
 
-                Ok(if self.use_qasm3 {
-                    self.transpiler.to_qasm3(circuit)
-                } else {
-                    self.transpiler.to_qasm2(circuit)
-                })
-            })
-            .collect();
+/// Session shared by a backend and its spawned job tasks
+#[derive(Clone, Default)]
+struct SessionScope(Arc<Mutex<Option<IbmSession>>>);
 
-        let qasm_circuits = qasm_circuits?;
+impl SessionScope {
+    fn current(&self) -> Option<IbmSession> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
 
-        let job_id = self.runtime.block_on(async {
-            let submission = JobSubmission {
-                program_id: "sampler".to_string(),
-                backend: self.name.clone(),
-                params: JobParams::new(qasm_circuits).with_shots(shots),
-                tags: vec!["niso".to_string(), "batch".to_string()],
-            };
+    fn set(&self, session: Option<IbmSession>) -> Option<IbmSession> {
+        std::mem::replace(
+            &mut *self.0.lock().unwrap_or_else(|e| e.into_inner()),
+            session,
+        )
+    }
 
-            let job = self.job_manager.submit(submission).await?;
-            Ok::<_, JobError>(job.id().to_string())
-        })?;
+    /// Submit into the current session, reopening it once if it closed
+    async fn submit(
+        &self,
+        manager: &JobManager,
+        submission: JobSubmission,
+    ) -> Result<IbmJob, JobError> {
+        let Some(session) = self.current() else {
+            return manager.submit(submission).await;
+        };
 
-        Ok(job_id)
+        match session.submit(submission.clone()).await {
+            Err(JobError::SessionClosed(id)) => {
+                log::warn!("Session {} closed, opening a new one", id);
+                let reopened = session.reopen().await?;
+
+                // Another task may have reopened it first
+                let active = {
+                    let mut current = self.0.lock().unwrap_or_else(|e| e.into_inner());
+                    match current.as_ref() {
+                        Some(s) if s.id() != id => s.clone(),
+                        _ => {
+                            *current = Some(reopened.clone());
+                            reopened.clone()
+                        }
+                    }
+                };
+                if active.id() != reopened.id() {
+                    let _ = reopened.cancel().await;
+                }
+                active.submit(submission).await
+            }
+            result => result,
+        }
     }
 }
 
+/// Await a task spawned on the backend runtime
+async fn join<T>(task: JoinHandle<Result<T, JobError>>) -> Result<T, IbmBackendError> {
+    Ok(task
+        .await
+        .map_err(|e| IbmBackendError::Runtime(e.to_string()))??)
+}
+
 // Implement Backend trait for integration with NISO
 impl Backend for IbmBackend {
     fn execute(&self, circuit: &Circuit, shots: u64) -> NisoResult<ExecutionResult> {
@@ -495,6 +627,31 @@ impl Backend for IbmBackend {
             .map_err(|e| NisoError::BackendError(e.to_string()))
     }
 
+    fn execute_batch(&self, circuits: &[Circuit], shots: u64) -> NisoResult<Vec<ExecutionResult>> {
+        Ok(IbmBackend::execute_batch(self, circuits, shots)?)
+    }
+
+    /// All batches are submitted before any is awaited, so their queue
+    /// waits overlap
+    fn execute_concurrent(
+        &self,
+        batches: &[Vec<Circuit>],
+        shots: u64,
+    ) -> NisoResult<Vec<Vec<ExecutionResult>>> {
+        let jobs = batches
+            .iter()
+            .map(|batch| self.spawn_job(batch, shots))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.runtime().block_on(async {
+            let mut results = Vec::with_capacity(jobs.len());
+            for job in jobs {
+                results.push(self.to_execution_results(join(job).await?, shots));
+            }
+            Ok(results)
+        })
+    }
+
     fn num_qubits(&self) -> usize {
         self.num_qubits
     }
@@ -512,6 +669,77 @@ impl Backend for IbmBackend {
     }
 }
 
+impl AsyncBackend for IbmBackend {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    fn submit<'a>(
+        &'a self,
+        circuits: &'a [Circuit],
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<JobHandle>> {
+        Box::pin(async move {
+            let submission = self.submission(self.transpile_all(circuits)?, shots, &["niso"]);
+            let manager = self.job_manager.clone();
+            let scope = self.session.clone();
+            let id = join(self.runtime().spawn(async move {
+                let job = scope.submit(&manager, submission).await?;
+                Ok(job.id().to_string())
+            }))
+            .await?;
+
+            Ok(JobHandle {
+                id,
+                backend: self.name.clone(),
+                num_circuits: circuits.len(),
+                shots,
+            })
+        })
+    }
+
+    fn poll<'a>(&'a self, job: &'a JobHandle) -> BoxFuture<'a, NisoResult<JobPoll>> {
+        Box::pin(async move {
+            let manager = self.job_manager.clone();
+            let id = job.id.clone();
+            let result = join(self.runtime().spawn(async move {
+                let mut ibm_job = manager.get_job(&id).await?;
+                match ibm_job.status() {
+                    JobStatus::Completed => ibm_job.result().await.map(Some),
+                    JobStatus::Cancelled => Err(JobError::Cancelled),
+                    JobStatus::Failed => {
+                        // Refresh reports the failure reason when there is one
+                        ibm_job.refresh().await?;
+                        Err(JobError::ExecutionFailed(format!("job {} failed", id)))
+                    }
+                    _ => Ok(None),
+                }
+            }))
+            .await?;
+
+            Ok(match result {
+                Some(result) => JobPoll::Ready(self.to_execution_results(result, job.shots)),
+                None => JobPoll::Pending,
+            })
+        })
+    }
+
+    fn execute_batch<'a>(
+        &'a self,
+        circuits: &'a [Circuit],
+        shots: u64,
+    ) -> BoxFuture<'a, NisoResult<Vec<ExecutionResult>>> {
+        Box::pin(async move {
+            let result = join(self.spawn_job(circuits, shots)?).await?;
+            Ok(self.to_execution_results(result, shots))
+        })
+    }
+}
+
 /// List available IBM backends
 pub fn list_backends(credentials: IbmCredentials) -> Result<Vec<String>, IbmBackendError> {
     let runtime = Runtime::new().map_err(|e| IbmBackendError::Runtime(e.to_string()))?;
@@ -537,6 +765,12 @@ pub fn list_backends_auto() -> Result<Vec<String>, IbmBackendError> {
 }
 
 /// Get recommended backend based on job requirements
+///
+/// Picks the smallest device with `min_qubits`, ignoring queue depth,
+/// status and calibration.
+#[deprecated(
+    note = "ignores queue and calibration; use `recommend::recommend_for_circuit` or `recommend::rank_backends`"
+)]
 pub fn recommend_backend(
     credentials: IbmCredentials,
     min_qubits: usize,
@@ -578,8 +812,15 @@ pub fn recommend_backend(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock::{MockFailure, MockRuntime};
     use niso_core::CircuitBuilder;
 
+    fn mock_backend(mock: &MockRuntime) -> IbmBackend {
+        IbmBackend::new("ibm_mock", mock.credentials())
+            .unwrap()
+            .with_poll_interval(Duration::from_millis(5))
+    }
+
     #[test]
     fn test_transpiler_integration() {
         let circuit = CircuitBuilder::new(2).h(0).cnot(0, 1).measure_all().build();
@@ -592,6 +833,263 @@ mod tests {
         assert!(qasm.contains("OPENQASM 3.0"));
     }
 
+    #[test]
+    fn test_backend_against_mock() {
+        let mock = MockRuntime::start();
+        mock.set_queue_polls(2);
+        let backend = mock_backend(&mock);
+
+        assert_eq!(Backend::num_qubits(&backend), 5);
+        assert!(backend.config().unwrap().basis_gates.is_some());
+        assert!(backend.is_operational().unwrap());
+        assert_eq!(backend.pending_jobs().unwrap(), 3);
+
+        let circuit = CircuitBuilder::new(2).h(0).cnot(0, 1).measure_all().build();
+        let result = Backend::execute(&backend, &circuit, 500).unwrap();
+        assert_eq!(result.counts.get("00"), Some(&500));
+        assert_eq!(result.metadata.backend, "ibm_mock");
+        assert!(!result.metadata.simulated);
+
+        let wide = CircuitBuilder::new(7).h(0).measure_all().build();
+        assert!(matches!(
+            backend.execute_sync(&wide, 100),
+            Err(IbmBackendError::Transpilation(_))
+        ));
+    }
+
+    #[test]
+    fn test_batch_and_async_submission() {
+        let mock = MockRuntime::start();
+        let backend = mock_backend(&mock).with_qasm2();
+        let circuits: Vec<_> = (0..3)
+            .map(|n| CircuitBuilder::new(n + 1).h(0).measure_all().build())
+            .collect();
+
+        let results = backend.execute_batch(&circuits, 200).unwrap();
+        assert_eq!(mock.job_ids().len(), 1);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[2].counts.get("000"), Some(&200));
+        assert_eq!(results[1].metadata.extra["circuit_index"], "1");
+
+        let job_id = backend.submit_async(&circuits[0], 100).unwrap();
+        let result = backend.get_results(&job_id).unwrap();
+        assert_eq!(result.shots, 100);
+        assert_eq!(result.metadata.job_id.as_deref(), Some(job_id.as_str()));
+        assert!(mock.job_circuits(&job_id).unwrap()[0].contains("qreg q[1];"));
+    }
+
+    #[test]
+    fn test_failed_job_surfaces_as_backend_error() {
+        let mock = MockRuntime::start();
+        let backend = mock_backend(&mock);
+        mock.fail_next_job("device maintenance");
+
+        let circuit = CircuitBuilder::new(1).x(0).measure_all().build();
+        match Backend::execute(&backend, &circuit, 100) {
+            Err(NisoError::BackendError(msg)) => assert!(msg.contains("device maintenance")),
+            other => panic!("expected backend error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_refresh_properties_sets_calibration() {
+        let mock = MockRuntime::start();
+        let mut backend = mock_backend(&mock);
+        assert!(Backend::calibration(&backend).is_none());
+
+        backend.refresh_properties().unwrap();
+        let cal = Backend::calibration(&backend).unwrap();
+        assert_eq!(cal.t1_times.len(), 5);
+        assert_eq!(cal.readout_lengths.get(&0), Some(&1200.0));
+        assert_eq!(cal.gate_errors_2q.get(&(3, 4)), Some(&0.008));
+        assert_eq!(cal.gate_times_1q_ns, Some(36.0));
+    }
+
+    #[test]
+    fn test_async_backend_from_any_executor() {
+        let mock = MockRuntime::start();
+        let backend = mock_backend(&mock);
+        let circuit = CircuitBuilder::new(2).x(1).measure_all().build();
+        mock.set_counts([("10".to_string(), 300)].into());
+
+        let result =
+            niso_backend::block_on(AsyncBackend::execute(&backend, &circuit, 300)).unwrap();
+        assert_eq!(result.counts.get("10"), Some(&300));
+
+        // Awaiting inside another tokio runtime does not hit `block_on`
+        let outer = Runtime::new().unwrap();
+        let results = outer
+            .block_on(AsyncBackend::execute_batch(
+                &backend,
+                &[circuit.clone(), circuit],
+                300,
+            ))
+            .unwrap();
+        assert_eq!(results.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_backend_lifecycle_inside_tokio() {
+        let mock = MockRuntime::start();
+        mock.set_counts([("01".to_string(), 100)].into());
+        let backend = IbmBackend::new_async("ibm_mock", mock.credentials())
+            .await
+            .unwrap()
+            .with_poll_interval(Duration::from_millis(5));
+
+        let circuit = CircuitBuilder::new(2).x(0).measure_all().build();
+        let result = AsyncBackend::execute(&backend, &circuit, 100)
+            .await
+            .unwrap();
+        assert_eq!(result.counts.get("01"), Some(&100));
+
+        // Dropping inside the runtime must not panic
+        drop(backend);
+    }
+
+    #[test]
+    fn test_async_submit_and_poll() {
+        let mock = MockRuntime::start();
+        mock.set_queue_polls(3);
+        let backend = mock_backend(&mock);
+        let circuits = [CircuitBuilder::new(3).h(0).measure_all().build()];
+
+        let job = niso_backend::block_on(backend.submit(&circuits, 128)).unwrap();
+        assert_eq!(job.num_circuits, 1);
+
+        let mut pending = 0;
+        let results = loop {
+            match niso_backend::block_on(backend.poll(&job)).unwrap() {
+                JobPoll::Pending => pending += 1,
+                JobPoll::Ready(results) => break results,
+            }
+        };
+        assert_eq!(pending, 3);
+        assert_eq!(results[0].counts.get("000"), Some(&128));
+
+        mock.set_queue_polls(0);
+        mock.fail_next_job("calibration in progress");
+        let job = niso_backend::block_on(backend.submit(&circuits, 128)).unwrap();
+        match niso_backend::block_on(backend.poll(&job)) {
+            Err(NisoError::BackendError(msg)) => assert!(msg.contains("calibration in progress")),
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_execute_concurrent_overlaps_jobs() {
+        let mock = MockRuntime::start();
+        mock.set_queue_polls(2);
+        let backend = mock_backend(&mock);
+        let circuit = CircuitBuilder::new(2).h(0).measure_all().build();
+        let batches = vec![vec![circuit.clone()], vec![circuit.clone(), circuit]];
+
+        let results = Backend::execute_concurrent(&backend, &batches, 64).unwrap();
+        assert_eq!(results.iter().map(Vec::len).collect::<Vec<_>>(), [1, 2]);
+        assert_ne!(results[0][0].metadata.job_id, results[1][0].metadata.job_id);
+
+        // Both jobs were queued before either one's results were fetched
+        let requests = mock.requests();
+        let last_submit = requests
+            .iter()
+            .rposition(|r| r == "POST /api/v1/jobs")
+            .unwrap();
+        let first_results = requests
+            .iter()
+            .position(|r| r.ends_with("/results"))
+            .unwrap();
+        assert!(last_submit < first_results);
+    }
+
+    #[test]
+    fn test_in_session_keeps_jobs_in_session() {
+        let mock = MockRuntime::start();
+        let backend = mock_backend(&mock);
+        let circuit = CircuitBuilder::new(2).h(0).measure_all().build();
+
+        let id = backend
+            .in_session(SessionMode::Dedicated, Duration::from_secs(600), |b| {
+                b.execute_sync(&circuit, 100).unwrap();
+                b.execute_batch(&[circuit.clone(), circuit.clone()], 100)
+                    .unwrap();
+                b.session().unwrap().id().to_string()
+            })
+            .unwrap();
+
+        let jobs = mock.job_ids();
+        assert_eq!(jobs.len(), 2);
+        assert!(jobs
+            .iter()
+            .all(|j| mock.job_session(j).as_deref() == Some(id.as_str())));
+        assert_eq!(
+            mock.request_count(&format!("PATCH /api/v1/sessions/{}", id)),
+            1
+        );
+
+        // Outside the scope jobs are independent again
+        assert!(backend.session().is_none());
+        let job_id = backend.submit_async(&circuit, 100).unwrap();
+        assert_eq!(mock.job_session(&job_id), None);
+    }
+
+    #[test]
+    fn test_expired_session_is_reopened() {
+        let mock = MockRuntime::start();
+        let backend = mock_backend(&mock)
+            .with_session(SessionMode::Dedicated, Duration::from_secs(600))
+            .unwrap();
+        let circuit = CircuitBuilder::new(1).x(0).measure_all().build();
+
+        let first = backend.session().unwrap().id().to_string();
+        mock.expire_session(&first);
+
+        let result = backend.execute_sync(&circuit, 50).unwrap();
+        let second = backend.session().unwrap().id().to_string();
+        assert_ne!(first, second);
+        assert_eq!(
+            mock.job_session(result.metadata.job_id.as_deref().unwrap()),
+            Some(second.clone())
+        );
+        assert_eq!(mock.session_ids(), [first, second.clone()]);
+
+        // Dropping the backend closes its session
+        drop(backend);
+        assert_eq!(
+            mock.request_count(&format!("PATCH /api/v1/sessions/{}", second)),
+            1
+        );
+    }
+
+    #[test]
+    fn test_api_key_backend_exchanges_once() {
+        let mock = MockRuntime::start();
+        let backend = IbmBackend::new("ibm_mock", mock.api_key_credentials()).unwrap();
+
+        assert!(backend.is_operational().unwrap());
+        assert_eq!(mock.iam_tokens_issued(), 1);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_list_and_recommend_against_mock() {
+        let mock = MockRuntime::start();
+
+        let names = list_backends(mock.credentials()).unwrap();
+        assert_eq!(names, ["ibm_mock", "ibm_mock_large", "simulator_mock"]);
+
+        let recommend = |min, sim| recommend_backend(mock.credentials(), min, sim);
+        assert_eq!(recommend(3, false).unwrap(), "ibm_mock");
+        assert_eq!(recommend(10, false).unwrap(), "ibm_mock_large");
+        assert_eq!(recommend(3, true).unwrap(), "simulator_mock");
+        assert!(matches!(
+            recommend(100, false),
+            Err(IbmBackendError::NotAvailable(_))
+        ));
+
+        mock.push_failure(MockFailure::new("/backends", 500));
+        assert!(list_backends(mock.credentials()).is_err());
+    }
+
     // Integration tests require IBM credentials
     #[test]
     #[ignore]
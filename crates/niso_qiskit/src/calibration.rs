@@ -0,0 +1,362 @@
+//! IBM backend properties to NISO calibration
+//!
+//! Gantree: L10_Qiskit → Calibration
+//!
+//! Converts `/backends/{name}/properties` (and the coupling map from
+//! `/configuration`) into a `CalibrationInfo`:
+//!
+//! - T1/T2 in microseconds, gate and readout lengths in nanoseconds,
+//!   whatever unit the device reports
+//! - single-qubit errors from `sx` (falling back to `x`)
+//! - two-qubit errors from the native entangler: `ecr`/`cx` on Eagle,
+//!   `cz` on Heron
+//! - readout error, with `prob_meas1_prep0`/`prob_meas0_prep1` kept
+//!   separately; their mean stands in when `readout_error` is missing
+//! - coupling map from the configuration, or from the two-qubit gates
+//! - timestamp from `last_update_date`, or the conversion time when the
+//!   date is missing or not RFC 3339
+
+use crate::client::{BackendConfig, BackendProperties};
+use niso_calibration::CalibrationInfo;
+use niso_core::QubitId;
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+/// Native two-qubit gates whose errors go into `gate_errors_2q`
+pub const NATIVE_2Q_GATES: [&str; 3] = ["ecr", "cz", "cx"];
+
+/// Single-qubit gates whose error stands for a qubit, by preference
+const ERROR_1Q_GATES: [&str; 2] = ["sx", "x"];
+
+/// Convert IBM backend properties into NISO calibration data
+/// Gantree: properties_to_calibration(name,props,config) -> CalibrationInfo // 캘리브레이션 변환
+pub fn properties_to_calibration(
+    backend_name: &str,
+    props: &BackendProperties,
+    config: Option<&BackendConfig>,
+) -> CalibrationInfo {
+    let mut cal = CalibrationInfo::new(backend_name);
+    if let Some(updated) = props
+        .last_update_date
+        .as_deref()
+        .and_then(parse_update_date)
+    {
+        cal.timestamp = updated;
+    }
+
+    // Per-qubit properties
+    for (q, qubit_props) in props.qubits.iter().flatten().enumerate() {
+        for prop in qubit_props {
+            let unit = prop.unit.as_deref();
+            match prop.name.as_str() {
+                "T1" => {
+                    cal.t1_times.insert(q, to_microseconds(prop.value, unit));
+                }
+                "T2" => {
+                    cal.t2_times.insert(q, to_microseconds(prop.value, unit));
+                }
+                "readout_error" => {
+                    cal.readout_errors.insert(q, prop.value);
+                }
+                "prob_meas1_prep0" => {
+                    cal.prob_meas1_prep0.insert(q, prop.value);
+                }
+                "prob_meas0_prep1" => {
+                    cal.prob_meas0_prep1.insert(q, prop.value);
+                }
+                "readout_length" => {
+                    cal.readout_lengths
+                        .insert(q, to_nanoseconds(prop.value, unit));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Readout error from the flip probabilities when not reported
+    let flip_qubits: Vec<QubitId> = cal
+        .prob_meas1_prep0
+        .keys()
+        .chain(cal.prob_meas0_prep1.keys())
+        .copied()
+        .collect();
+    for q in flip_qubits {
+        if !cal.readout_errors.contains_key(&q) {
+            let flips: Vec<f64> = [cal.prob_meas1_prep0.get(&q), cal.prob_meas0_prep1.get(&q)]
+                .into_iter()
+                .flatten()
+                .copied()
+                .collect();
+            cal.readout_errors
+                .insert(q, flips.iter().sum::<f64>() / flips.len() as f64);
+        }
+    }
+
+    // Gate errors and durations
+    let mut errors_1q: HashMap<&str, HashMap<QubitId, f64>> = HashMap::new();
+    let mut edges: Vec<(QubitId, QubitId)> = Vec::new();
+    for gate in props.gates.iter().flatten() {
+        let native_2q = NATIVE_2Q_GATES.contains(&gate.gate.as_str());
+        if let [q0, q1] = gate.qubits[..] {
+            if native_2q && !edges.contains(&(q0, q1)) {
+                edges.push((q0, q1));
+            }
+        }
+
+        for param in &gate.parameters {
+            let unit = param.unit.as_deref();
+            match (param.name.as_str(), gate.qubits.as_slice()) {
+                ("gate_error", &[q]) => {
+                    errors_1q
+                        .entry(gate.gate.as_str())
+                        .or_default()
+                        .insert(q, param.value);
+                }
+                ("gate_error", &[q0, q1]) if native_2q => {
+                    cal.gate_errors_2q.insert((q0, q1), param.value);
+                }
+                ("gate_length", &[q]) => {
+                    cal.gate_lengths_1q
+                        .entry(gate.gate.clone())
+                        .or_default()
+                        .insert(q, to_nanoseconds(param.value, unit));
+                }
+                ("gate_length", &[q0, q1]) => {
+                    cal.gate_lengths_2q
+                        .entry(gate.gate.clone())
+                        .or_default()
+                        .insert((q0, q1), to_nanoseconds(param.value, unit));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for name in ERROR_1Q_GATES.iter().rev() {
+        if let Some(errors) = errors_1q.get(name) {
+            cal.gate_errors_1q.extend(errors);
+        }
+    }
+
+    // Coupling map: configuration first, native gates otherwise
+    cal.coupling_map = config
+        .and_then(|c| c.coupling_map.as_ref())
+        .map(|map| {
+            map.iter()
+                .filter_map(|pair| match pair[..] {
+                    [q0, q1] => Some((q0, q1)),
+                    _ => None,
+                })
+                .collect()
+        })
+        .filter(|map: &Vec<_>| !map.is_empty())
+        .unwrap_or(edges);
+
+    // Scalar summaries for consumers that only need typical durations
+    let mean = |values: Vec<f64>| -> Option<f64> {
+        if values.is_empty() {
+            None
+        } else {
+            Some(values.iter().sum::<f64>() / values.len() as f64)
+        }
+    };
+    cal.gate_times_1q_ns = mean(
+        ERROR_1Q_GATES
+            .iter()
+            .filter_map(|name| cal.gate_lengths_1q.get(*name))
+            .flat_map(|m| m.values().copied())
+            .collect(),
+    );
+    cal.gate_times_2q_ns = mean(
+        cal.gate_lengths_2q
+            .iter()
+            .filter(|(name, _)| NATIVE_2Q_GATES.contains(&name.as_str()))
+            .flat_map(|(_, m)| m.values().copied())
+            .collect(),
+    );
+
+    cal
+}
+
+/// Parse an RFC 3339 `last_update_date`
+fn parse_update_date(date: &str) -> Option<SystemTime> {
+    chrono::DateTime::parse_from_rfc3339(date)
+        .ok()
+        .map(SystemTime::from)
+}
+
+/// Convert a reported duration to nanoseconds
+fn to_nanoseconds(value: f64, unit: Option<&str>) -> f64 {
+    match unit {
+        Some("s") => value * 1e9,
+        Some("ms") => value * 1e6,
+        Some("us") | Some("µs") => value * 1e3,
+        _ => value,
+    }
+}
+
+/// Convert a reported coherence time to microseconds
+fn to_microseconds(value: f64, unit: Option<&str>) -> f64 {
+    match unit {
+        Some("s") => value * 1e6,
+        Some("ms") => value * 1e3,
+        Some("ns") => value * 1e-3,
+        _ => value,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use niso_core::Gate;
+    use std::time::UNIX_EPOCH;
+
+    fn fixture(name: &str) -> BackendProperties {
+        let text = match name {
+            "eagle" => include_str!("../fixtures/properties_eagle.json"),
+            "heron" => include_str!("../fixtures/properties_heron.json"),
+            _ => unreachable!(),
+        };
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_invalid_update_date_falls_back_to_now() {
+        let mut props = fixture("eagle");
+        props.last_update_date = Some("yesterday".into());
+        let cal = properties_to_calibration("ibm_brisbane", &props, None);
+
+        assert!(cal.timestamp.elapsed().unwrap().as_secs() < 60);
+    }
+
+    #[test]
+    fn test_eagle_properties() {
+        let cal = properties_to_calibration("ibm_brisbane", &fixture("eagle"), None);
+
+        assert_eq!(cal.backend_name, "ibm_brisbane");
+        assert_eq!(cal.num_qubits(), 4);
+
+        // Timestamp of the device calibration, not of the conversion
+        let updated = cal.timestamp.duration_since(UNIX_EPOCH).unwrap();
+        assert_eq!(updated.as_secs(), 1_792_222_872);
+        assert_relative_eq!(cal.t1_times[&0], 231.52);
+        assert_relative_eq!(cal.t2_times[&1], 142.08);
+
+        // sx errors, not the (zero) rz errors
+        assert_relative_eq!(cal.gate_errors_1q[&2], 0.000318);
+        assert_eq!(cal.gate_errors_1q.len(), 4);
+
+        // ECR errors per direction; the coupling map follows the gates
+        assert_relative_eq!(cal.gate_errors_2q[&(1, 0)], 0.00687);
+        assert_eq!(cal.gate_errors_2q.len(), 3);
+        assert_eq!(cal.coupling_map, [(1, 0), (1, 2), (3, 2)]);
+
+        assert_relative_eq!(cal.readout_errors[&3], 0.0215);
+        assert_relative_eq!(cal.prob_meas0_prep1[&3], 0.0298);
+        assert_relative_eq!(cal.prob_meas1_prep0[&3], 0.0132);
+        assert_relative_eq!(cal.readout_asymmetry(3).unwrap(), 0.0166, epsilon = 1e-12);
+
+        assert_relative_eq!(cal.readout_lengths[&0], 1300.0);
+        assert_relative_eq!(cal.gate_lengths_1q["sx"][&0], 60.0);
+        assert_relative_eq!(cal.gate_lengths_2q["ecr"][&(3, 2)], 660.0);
+        assert_eq!(cal.gate_times_1q_ns, Some(60.0));
+        assert_eq!(cal.gate_times_2q_ns, Some(660.0));
+
+        let times = cal.to_gate_times();
+        assert_eq!(times.gate_duration(&Gate::Ecr(1, 2)), 660.0);
+        assert_eq!(times.gate_duration(&Gate::Measure(0)), 1300.0);
+    }
+
+    #[test]
+    fn test_heron_properties() {
+        let cal = properties_to_calibration("ibm_fez", &fixture("heron"), None);
+
+        // CZ is the native entangler on Heron
+        assert_relative_eq!(cal.gate_errors_2q[&(0, 1)], 0.00292);
+        assert_relative_eq!(cal.gate_errors_2q[&(2, 3)], 0.00411);
+        assert_eq!(cal.coupling_map, [(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(cal.gate_times_2q_ns, Some(84.0));
+
+        // Qubit 3 reports no sx error; its x error is used
+        assert_relative_eq!(cal.gate_errors_1q[&3], 0.000412);
+        assert_relative_eq!(cal.gate_errors_1q[&0], 0.000221);
+
+        // Coherence and readout lengths in other units
+        assert_relative_eq!(cal.t1_times[&2], 150.0);
+        assert_relative_eq!(cal.t2_times[&2], 98.0, epsilon = 1e-9);
+        assert_relative_eq!(cal.readout_lengths[&1], 1560.0);
+
+        // Readout error derived from the flip probabilities on qubit 1
+        assert_relative_eq!(cal.readout_errors[&1], (0.0056 + 0.0184) / 2.0);
+        assert_relative_eq!(cal.readout_errors[&0], 0.0089);
+        assert_eq!(cal.readout_asymmetry(2), None);
+
+        assert_eq!(cal.best_linear_chain(4), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_config_coupling_map_takes_precedence() {
+        let config = BackendConfig {
+            backend_name: Some("ibm_fez".to_string()),
+            n_qubits: Some(4),
+            basis_gates: None,
+            coupling_map: Some(vec![vec![0, 1], vec![1, 0], vec![1, 2], vec![2, 1]]),
+            max_shots: None,
+            max_experiments: None,
+            supported_features: None,
+        };
+
+        let cal = properties_to_calibration("ibm_fez", &fixture("heron"), Some(&config));
+        assert_eq!(cal.coupling_map, [(0, 1), (1, 0), (1, 2), (2, 1)]);
+    }
+
+    #[test]
+    fn test_properties_gate_lengths() {
+        let json = r#"{
+            "qubits": [
+                [{"name": "T1", "value": 120.0, "unit": "us"},
+                 {"name": "readout_length", "value": 1.216, "unit": "us"}],
+                [{"name": "T1", "value": 90.0, "unit": "us"}]
+            ],
+            "gates": [
+                {"gate": "sx", "qubits": [0], "parameters": [
+                    {"name": "gate_error", "value": 0.0002},
+                    {"name": "gate_length", "value": 60.0, "unit": "ns"}]},
+                {"gate": "ecr", "qubits": [0, 1], "parameters": [
+                    {"name": "gate_error", "value": 0.008},
+                    {"name": "gate_length", "value": 660.0, "unit": "ns"}]},
+                {"gate": "ecr", "qubits": [1, 2], "parameters": [
+                    {"name": "gate_length", "value": 1320.0, "unit": "ns"}]}
+            ]
+        }"#;
+        let props: BackendProperties = serde_json::from_str(json).unwrap();
+
+        let cal = properties_to_calibration("ibm_test", &props, None);
+        assert_eq!(cal.readout_lengths.get(&0), Some(&1216.0));
+        assert_eq!(cal.gate_lengths_1q["sx"].get(&0), Some(&60.0));
+        assert_eq!(cal.gate_lengths_2q["ecr"].get(&(1, 2)), Some(&1320.0));
+        assert_eq!(cal.gate_errors_2q.get(&(0, 1)), Some(&0.008));
+        assert_eq!(cal.gate_times_2q_ns, Some(990.0));
+
+        let times = cal.to_gate_times();
+        assert_eq!(times.gate_duration(&Gate::Ecr(0, 1)), 660.0);
+        assert_eq!(times.gate_duration(&Gate::Ecr(1, 2)), 1320.0);
+        assert_eq!(times.gate_duration(&Gate::Measure(0)), 1216.0);
+    }
+
+    #[test]
+    fn test_unit_conversions() {
+        assert_eq!(to_nanoseconds(1.2, Some("us")), 1200.0);
+        assert_eq!(to_nanoseconds(36.0, Some("ns")), 36.0);
+        assert_eq!(to_nanoseconds(2.0, Some("ms")), 2e6);
+        assert_eq!(to_microseconds(0.15, Some("ms")), 150.0);
+        assert_eq!(to_microseconds(98000.0, Some("ns")), 98.0);
+        assert_eq!(to_microseconds(120.0, Some("us")), 120.0);
+        assert_eq!(to_microseconds(120.0, None), 120.0);
+    }
+}
@@ -64,6 +64,9 @@ pub struct IbmClient {
 
     /// Base URL
     base_url: String,
+
+    /// Wait before retrying after 503 Service Unavailable
+    retry_delay: std::time::Duration,
 }
 
 impl IbmClient {
@@ -73,16 +76,16 @@ impl IbmClient {
     pub fn new(credentials: IbmCredentials) -> Result<Self, ClientError> {
         credentials.validate()?;
 
-        let base_url = credentials.channel().runtime_url();
+        let base_url = credentials.runtime_url();
 
         let mut headers = HeaderMap::new();
         // Note: For API keys, the auth header will be set per-request
         if credentials.token_type() == TokenType::IqpToken {
-            headers.insert(
-                AUTHORIZATION,
-                HeaderValue::from_str(&credentials.auth_header())
-                    .map_err(|_| AuthError::InvalidTokenFormat)?,
-            );
+            let mut auth = HeaderValue::from_str(&credentials.auth_header())
+                .map_err(|_| AuthError::InvalidTokenFormat)?;
+            // Keeps the token out of the client's Debug output
+            auth.set_sensitive(true);
+            headers.insert(AUTHORIZATION, auth);
         }
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
@@ -121,6 +124,7 @@ impl IbmClient {
             client,
             credentials,
             base_url,
+            retry_delay: std::time::Duration::from_secs(5),
         })
     }
 
@@ -132,7 +136,7 @@ impl IbmClient {
         // This ensures the token is ready and validates the API key
         let _ = credentials.auth_header_async().await?;
 
-        let base_url = credentials.channel().runtime_url();
+        let base_url = credentials.runtime_url();
 
         let mut headers = HeaderMap::new();
         // Note: For API keys, we DON'T set AUTHORIZATION in default headers
@@ -174,9 +178,22 @@ impl IbmClient {
             client,
             credentials,
             base_url,
+            retry_delay: std::time::Duration::from_secs(5),
         })
     }
 
+    /// Override the API base URL (e.g. a local mock server)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Set the wait before retrying a 503 response
+    pub fn with_retry_delay(mut self, delay: std::time::Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+
     /// Create from environment
     pub fn from_env() -> Result<Self, ClientError> {
         let credentials = IbmCredentials::from_env()?;
@@ -257,6 +274,31 @@ impl IbmClient {
         }
     }
 
+    /// PATCH request (response body ignored)
+    pub async fn patch<B: Serialize>(&self, path: &str, body: &B) -> Result<(), ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+
+        // Get fresh auth header for API keys
+        let mut request = self.client.patch(&url).json(body);
+        if self.credentials.requires_iam_exchange() {
+            let auth = self.get_auth_header().await?;
+            request = request.header(AUTHORIZATION, auth);
+        }
+
+        let response = request.send().await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let status = response.status().as_u16();
+            let text = response.text().await.unwrap_or_default();
+            Err(ClientError::ApiError {
+                code: status,
+                message: text,
+            })
+        }
+    }
+
     /// Handle API response
     async fn handle_response<T: DeserializeOwned>(
         &self,
@@ -322,7 +364,7 @@ impl IbmClient {
                         return Err(ClientError::ServiceUnavailable);
                     }
                     retries += 1;
-                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                    tokio::time::sleep(self.retry_delay).await;
                 }
                 Err(e) => return Err(e),
             }
@@ -352,7 +394,7 @@ impl IbmClient {
                         return Err(ClientError::ServiceUnavailable);
                     }
                     retries += 1;
-                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                    tokio::time::sleep(self.retry_delay).await;
                 }
                 Err(e) => return Err(e),
             }
@@ -730,6 +772,8 @@ pub struct GateParameter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock::{BackendsFormat, MockFailure, MockRuntime};
+    use std::time::Duration;
 
     #[test]
     fn test_backend_info_deserialize() {
@@ -779,4 +823,100 @@ mod tests {
         assert!(props.qubits.is_some());
         assert!(props.gates.is_some());
     }
+
+    #[tokio::test]
+    async fn test_list_backends_response_formats() {
+        let mock = MockRuntime::start();
+        let client = IbmClient::new(mock.credentials()).unwrap();
+
+        for format in [
+            BackendsFormat::Devices,
+            BackendsFormat::Legacy,
+            BackendsFormat::Array,
+        ] {
+            mock.set_backends_format(format);
+            let backends = client.list_backends().await.unwrap();
+
+            assert_eq!(backends.len(), 3, "{:?}", format);
+            assert_eq!(backends[0].name, "ibm_mock");
+            assert_eq!(backends[0].num_qubits, Some(5));
+            assert!(!backends[0].simulator);
+            assert!(backends[2].simulator, "{:?}", format);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backend_endpoints() {
+        let mock = MockRuntime::start();
+        let client = IbmClient::new(mock.credentials()).unwrap();
+
+        let info = client.get_backend("ibm_mock_large").await.unwrap();
+        assert_eq!(info.num_qubits, Some(27));
+
+        let status = client.get_backend_status("ibm_mock").await.unwrap();
+        assert!(status.operational);
+        assert_eq!(status.pending_jobs, Some(3));
+
+        let config = client.get_backend_config("ibm_mock").await.unwrap();
+        assert_eq!(config.n_qubits, Some(5));
+        assert_eq!(config.coupling_map.map(|c| c.len()), Some(4));
+
+        let props = client.get_backend_properties("ibm_mock").await.unwrap();
+        assert_eq!(props.qubits.map(|q| q.len()), Some(5));
+
+        match client.get_backend_config("ibm_missing").await {
+            Err(ClientError::ApiError { code, .. }) => assert_eq!(code, 404),
+            other => panic!("expected 404, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_token_is_auth_error() {
+        let mock = MockRuntime::start();
+        let credentials =
+            IbmCredentials::new("not_the_mock_token_0123456789abcdef").with_url(mock.url());
+        let client = IbmClient::new(credentials).unwrap();
+
+        let result = client.get_backend_status("ibm_mock").await;
+        assert!(matches!(result, Err(ClientError::Auth(_))));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_retry() {
+        let mock = MockRuntime::start();
+        let client = IbmClient::new(mock.credentials()).unwrap();
+        let path = "/backends/ibm_mock/status";
+
+        mock.push_failure(MockFailure::new(path, 429).with_retry_after(0).times(2));
+        let status: BackendStatus = client.get_with_retry(path, 3).await.unwrap();
+        assert!(status.operational);
+        assert_eq!(mock.request_count(path), 3);
+
+        mock.push_failure(MockFailure::new(path, 429).with_retry_after(0).times(5));
+        match client.get_with_retry::<BackendStatus>(path, 2).await {
+            Err(ClientError::RateLimited { retry_after }) => assert_eq!(retry_after, 0),
+            other => panic!("expected rate limit, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_service_unavailable_retry() {
+        let mock = MockRuntime::start();
+        let client = IbmClient::new(mock.credentials())
+            .unwrap()
+            .with_retry_delay(Duration::from_millis(10));
+
+        mock.push_failure(MockFailure::new("/backends", 503).with_method("GET"));
+        let status: BackendStatus = client
+            .get_with_retry("/backends/ibm_mock/status", 1)
+            .await
+            .unwrap();
+        assert_eq!(status.backend_name.as_deref(), Some("ibm_mock"));
+
+        mock.push_failure(MockFailure::new("/backends", 503).times(3));
+        let result = client
+            .get_with_retry::<BackendStatus>("/backends/ibm_mock/status", 1)
+            .await;
+        assert!(matches!(result, Err(ClientError::ServiceUnavailable)));
+    }
 }
@@ -0,0 +1,741 @@
+//! Estimator V2 primitive: observables, resilience options and results
+//!
+//! Gantree: L10_Qiskit → Estimator
+//!
+//! An estimator PUB is one circuit with a list of Pauli observables and a
+//! target precision. IBM measures the expectation values itself, applying
+//! the requested error mitigation (readout mitigation, ZNE, twirling), so
+//! the results can be compared against NISO's own counts-based estimates.
+//!
+//! ```rust,ignore
+//! let parity = SparsePauliOp::from_list(&[("ZZZZZ", 1.0)])?;
+//! let pub_ = EstimatorPub::new(qasm).with_observable(parity.clone()).with_precision(0.01);
+//! let options = EstimatorOptions::default()
+//!     .with_readout_mitigation(true)
+//!     .with_zne(ZneOptions::default());
+//! let mut job = manager.submit_estimator("ibm_brisbane", vec![pub_], options).await?;
+//! job.wait(timeout).await?;
+//! let estimate = job.estimator_result().await?.pubs[0].operator_result(&parity)?;
+//! ```
+
+use niso_backend::EstimatorResult;
+use niso_core::{PauliString, SparsePauliOp};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+/// Estimator V2 errors
+#[derive(Debug, Error)]
+pub enum EstimatorError {
+    /// PUB is inconsistent (observables, precision)
+    #[error("Invalid PUB: {0}")]
+    InvalidPub(String),
+
+    /// Result payload could not be decoded
+    #[error("Invalid estimator result: {0}")]
+    Decode(String),
+}
+
+/// Estimator primitive version sent with PUB submissions
+pub const ESTIMATOR_VERSION: u32 = 2;
+
+// ============================================================================
+// PUB
+// ============================================================================
+
+/// How a PUB's observables relate to the operator being estimated
+/// Gantree: ObservableMode // 관측량 구성 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObservableMode {
+    /// Each observable is a whole operator (coefficients included)
+    #[default]
+    Whole,
+
+    /// One unit-coefficient observable per term (`EstimatorPub::for_operator`)
+    PerTerm,
+}
+
+/// Estimator PUB: circuit, observables and precision
+/// Gantree: EstimatorPub // 추정기 PUB
+///
+/// Serialized as `[circuit, observables]` or
+/// `[circuit, observables, null, precision]`; each observable is a
+/// `{label: coefficient}` map over the circuit's qubits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EstimatorPub {
+    /// OpenQASM 3 circuit (no measurements needed)
+    pub circuit: String,
+
+    /// Observables; one expectation value is returned per entry
+    pub observables: Vec<SparsePauliOp>,
+
+    /// Target precision (job default if `None`)
+    pub precision: Option<f64>,
+
+    /// How `observables` were built (client side only, not sent)
+    pub mode: ObservableMode,
+}
+
+impl EstimatorPub {
+    /// Create a PUB without observables
+    pub fn new(circuit: impl Into<String>) -> Self {
+        Self {
+            circuit: circuit.into(),
+            observables: Vec::new(),
+            precision: None,
+            mode: ObservableMode::Whole,
+        }
+    }
+
+    /// One observable per term of `op` (unit coefficients)
+    /// Gantree: for_operator(circuit,op) -> EstimatorPub // 항별 관측량
+    ///
+    /// Gives per-term values; `EstimatorPubResult::operator_result`
+    /// recombines them with the coefficients of `op`.
+    pub fn for_operator(circuit: impl Into<String>, op: &SparsePauliOp) -> Self {
+        let mut pub_ = Self::new(circuit);
+        for (pauli, _) in op.terms() {
+            pub_ = pub_.with_observable(SparsePauliOp::new().with_term(pauli.clone(), 1.0));
+        }
+        pub_.mode = ObservableMode::PerTerm;
+        pub_
+    }
+
+    /// Append an observable
+    pub fn with_observable(mut self, observable: SparsePauliOp) -> Self {
+        self.observables.push(observable);
+        self
+    }
+
+    /// Set target precision
+    pub fn with_precision(mut self, precision: f64) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Number of qubits declared in the circuit
+    pub fn num_qubits(&self) -> usize {
+        qasm_num_qubits(&self.circuit)
+    }
+
+    /// Check observables and precision
+    pub fn validate(&self) -> Result<(), EstimatorError> {
+        if self.observables.is_empty() || self.observables.iter().any(|o| o.is_empty()) {
+            return Err(EstimatorError::InvalidPub(
+                "every PUB needs at least one non-empty observable".to_string(),
+            ));
+        }
+
+        let num_qubits = self.num_qubits();
+        if let Some(o) = self
+            .observables
+            .iter()
+            .find(|o| o.min_qubits() > num_qubits)
+        {
+            return Err(EstimatorError::InvalidPub(format!(
+                "observable acts on {} qubits, circuit has {}",
+                o.min_qubits(),
+                num_qubits
+            )));
+        }
+        if self.precision.is_some_and(|p| p <= 0.0 || !p.is_finite()) {
+            return Err(EstimatorError::InvalidPub(
+                "precision must be positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Observable as a `{label: coefficient}` map
+    fn observable_json(&self, observable: &SparsePauliOp) -> Value {
+        let num_qubits = self.num_qubits().max(observable.min_qubits());
+        let mut map = Map::new();
+        for (pauli, coeff) in observable.terms() {
+            map.insert(pauli.to_label(num_qubits), Value::from(*coeff));
+        }
+        Value::Object(map)
+    }
+}
+
+impl Serialize for EstimatorPub {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let observables: Vec<Value> = self
+            .observables
+            .iter()
+            .map(|o| self.observable_json(o))
+            .collect();
+
+        match self.precision {
+            Some(precision) => {
+                (&self.circuit, observables, Value::Null, precision).serialize(serializer)
+            }
+            None => (&self.circuit, observables).serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EstimatorPub {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let items = Vec::<Value>::deserialize(deserializer)?;
+        let mut items = items.into_iter();
+
+        let circuit = match items.next() {
+            Some(Value::String(circuit)) => circuit,
+            _ => return Err(D::Error::custom("PUB must start with a circuit string")),
+        };
+        let observables = match items.next() {
+            Some(Value::Array(list)) => list
+                .iter()
+                .map(parse_observable)
+                .collect::<Result<_, _>>()
+                .map_err(D::Error::custom)?,
+            Some(single) => vec![parse_observable(&single).map_err(D::Error::custom)?],
+            None => return Err(D::Error::custom("PUB has no observables")),
+        };
+        // Parameter values (index 2) are not used by NISO
+        let precision = match items.nth(1) {
+            None | Some(Value::Null) => None,
+            Some(precision) => Some(serde_json::from_value(precision).map_err(D::Error::custom)?),
+        };
+
+        Ok(Self {
+            circuit,
+            observables,
+            precision,
+            mode: ObservableMode::Whole,
+        })
+    }
+}
+
+/// Parse `"ZZI"` or `{"ZZI": 0.5, ...}`
+fn parse_observable(value: &Value) -> Result<SparsePauliOp, String> {
+    let terms: Vec<(String, f64)> = match value {
+        Value::String(label) => vec![(label.clone(), 1.0)],
+        Value::Object(map) => map
+            .iter()
+            .map(|(label, coeff)| {
+                coeff
+                    .as_f64()
+                    .map(|c| (label.clone(), c))
+                    .ok_or_else(|| format!("coefficient of {} is not real", label))
+            })
+            .collect::<Result<_, _>>()?,
+        other => return Err(format!("invalid observable {}", other)),
+    };
+
+    let mut op = SparsePauliOp::new();
+    for (label, coeff) in terms {
+        op.add_term(
+            PauliString::from_label(&label).map_err(|e| e.to_string())?,
+            coeff,
+        );
+    }
+    Ok(op)
+}
+
+/// Width of the `qubit[n]` register (0 if undeclared)
+fn qasm_num_qubits(qasm: &str) -> usize {
+    qasm.split(';')
+        .find_map(|statement| {
+            let rest = statement.trim().strip_prefix("qubit[")?;
+            rest.split_once(']')?.0.trim().parse().ok()
+        })
+        .unwrap_or(0)
+}
+
+// ============================================================================
+// Options
+// ============================================================================
+
+/// ZNE extrapolation model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ZneExtrapolator {
+    /// Exponential decay
+    Exponential,
+    /// Double exponential decay
+    DoubleExponential,
+    /// Linear fit
+    Linear,
+    /// Quadratic fit
+    #[serde(rename = "polynomial_degree_2")]
+    Quadratic,
+}
+
+/// Zero-noise extrapolation settings
+/// Gantree: ZneOptions // ZNE 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZneOptions {
+    /// Noise amplification factors (≥ 1)
+    pub noise_factors: Vec<f64>,
+
+    /// Extrapolation model
+    pub extrapolator: ZneExtrapolator,
+}
+
+impl Default for ZneOptions {
+    fn default() -> Self {
+        Self {
+            noise_factors: vec![1.0, 3.0, 5.0],
+            extrapolator: ZneExtrapolator::Exponential,
+        }
+    }
+}
+
+/// Error mitigation settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResilienceOptions {
+    /// Readout (TREX) mitigation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub measure_mitigation: Option<bool>,
+
+    /// Zero-noise extrapolation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zne_mitigation: Option<bool>,
+
+    /// ZNE settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zne: Option<ZneOptions>,
+}
+
+/// Pauli twirling settings
+/// Gantree: TwirlingOptions // 트월링 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TwirlingOptions {
+    /// Twirl two-qubit gates
+    pub enable_gates: bool,
+
+    /// Twirl measurements
+    pub enable_measure: bool,
+
+    /// Number of randomizations (server default if `None`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_randomizations: Option<u32>,
+
+    /// Shots per randomization (server default if `None`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shots_per_randomization: Option<u64>,
+}
+
+impl Default for TwirlingOptions {
+    fn default() -> Self {
+        Self {
+            enable_gates: true,
+            enable_measure: true,
+            num_randomizations: None,
+            shots_per_randomization: None,
+        }
+    }
+}
+
+/// Estimator V2 options (`params.options`)
+/// Gantree: EstimatorOptions // 추정기 옵션
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EstimatorOptions {
+    /// Precision of PUBs without their own
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_precision: Option<f64>,
+
+    /// Resilience level preset (0-2); explicit settings override it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resilience_level: Option<u8>,
+
+    /// Error mitigation settings
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub resilience: ResilienceOptions,
+
+    /// Twirling settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub twirling: Option<TwirlingOptions>,
+
+    /// Seed for randomizations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed_estimator: Option<u64>,
+}
+
+impl EstimatorOptions {
+    /// Set default precision
+    pub fn with_precision(mut self, precision: f64) -> Self {
+        self.default_precision = Some(precision);
+        self
+    }
+
+    /// Set resilience level preset
+    pub fn with_resilience_level(mut self, level: u8) -> Self {
+        self.resilience_level = Some(level);
+        self
+    }
+
+    /// Enable or disable readout mitigation
+    pub fn with_readout_mitigation(mut self, enabled: bool) -> Self {
+        self.resilience.measure_mitigation = Some(enabled);
+        self
+    }
+
+    /// Enable zero-noise extrapolation
+    pub fn with_zne(mut self, zne: ZneOptions) -> Self {
+        self.resilience.zne_mitigation = Some(true);
+        self.resilience.zne = Some(zne);
+        self
+    }
+
+    /// Enable twirling
+    pub fn with_twirling(mut self, twirling: TwirlingOptions) -> Self {
+        self.twirling = Some(twirling);
+        self
+    }
+
+    /// Set seed
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed_estimator = Some(seed);
+        self
+    }
+
+    /// Check option ranges
+    pub fn validate(&self) -> Result<(), EstimatorError> {
+        if self.resilience_level.is_some_and(|l| l > 2) {
+            return Err(EstimatorError::InvalidPub(
+                "resilience level must be 0, 1 or 2".to_string(),
+            ));
+        }
+        if let Some(zne) = &self.resilience.zne {
+            if zne.noise_factors.is_empty() || zne.noise_factors.iter().any(|&f| f < 1.0) {
+                return Err(EstimatorError::InvalidPub(
+                    "ZNE noise factors must be at least 1".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_default(resilience: &ResilienceOptions) -> bool {
+    *resilience == ResilienceOptions::default()
+}
+
+// ============================================================================
+// Results
+// ============================================================================
+
+/// Result of one estimator PUB
+/// Gantree: EstimatorPubResult // PUB 결과
+#[derive(Debug, Clone)]
+pub struct EstimatorPubResult {
+    /// Expectation values, one per observable
+    pub evs: Vec<f64>,
+
+    /// Standard errors of `evs`
+    pub stds: Vec<f64>,
+
+    /// Standard errors across twirling randomizations, if reported
+    pub ensemble_standard_error: Option<Vec<f64>>,
+
+    /// Execution metadata (`shots`, `target_precision`, ...)
+    pub metadata: Value,
+
+    /// How the PUB's observables were built (`Whole` unless known)
+    pub mode: ObservableMode,
+}
+
+impl EstimatorPubResult {
+    /// Shots the server spent on this PUB
+    pub fn shots(&self) -> u64 {
+        self.metadata["shots"].as_u64().unwrap_or(0)
+    }
+
+    /// Map into a NISO `EstimatorResult` for `op`
+    /// Gantree: operator_result(&self,op) -> EstimatorResult // NISO 결과 변환
+    ///
+    /// A `PerTerm` PUB (one value per term) is recombined as Σ c_i <P_i>,
+    /// with standard errors added in quadrature; a `Whole` PUB with `op` as
+    /// its single observable is taken as is.
+    pub fn operator_result(&self, op: &SparsePauliOp) -> Result<EstimatorResult, EstimatorError> {
+        let (value, std_error, term_values) = match self.mode {
+            ObservableMode::PerTerm => {
+                if self.evs.len() != op.len() {
+                    return Err(EstimatorError::Decode(format!(
+                        "{} values for an operator with {} terms",
+                        self.evs.len(),
+                        op.len()
+                    )));
+                }
+                let coeffs = op.terms().iter().map(|(_, c)| *c);
+                let value = coeffs.clone().zip(&self.evs).map(|(c, ev)| c * ev).sum();
+                let variance: f64 = coeffs.zip(&self.stds).map(|(c, s)| (c * s).powi(2)).sum();
+                (value, variance.sqrt(), self.evs.clone())
+            }
+            ObservableMode::Whole => {
+                if self.evs.len() != 1 {
+                    return Err(EstimatorError::Decode(format!(
+                        "{} values for a single observable",
+                        self.evs.len()
+                    )));
+                }
+                let (Some(&value), Some(&std_error)) = (self.evs.first(), self.stds.first()) else {
+                    return Err(EstimatorError::Decode("missing standard error".to_string()));
+                };
+                (value, std_error, Vec::new())
+            }
+        };
+
+        Ok(EstimatorResult {
+            value,
+            std_error,
+            term_values,
+            num_circuits: 1,
+            shots: self.shots(),
+        })
+    }
+}
+
+/// Estimator V2 job result (`GET /jobs/{id}/results`)
+/// Gantree: EstimatorJobResult // 추정기 결과
+#[derive(Debug, Clone)]
+pub struct EstimatorJobResult {
+    /// Results in PUB order
+    pub pubs: Vec<EstimatorPubResult>,
+
+    /// Job metadata
+    pub metadata: Value,
+}
+
+impl EstimatorJobResult {
+    /// Decode a result payload
+    pub fn from_json(value: &Value) -> Result<Self, EstimatorError> {
+        let results = value["results"]
+            .as_array()
+            .ok_or_else(|| EstimatorError::Decode("missing results".to_string()))?;
+
+        let pubs = results
+            .iter()
+            .map(|result| {
+                let data = &result["data"];
+                let evs = flatten(&data["evs"])?;
+                let stds = flatten(&data["stds"])?;
+                if evs.len() != stds.len() {
+                    return Err(EstimatorError::Decode(format!(
+                        "{} values but {} standard errors",
+                        evs.len(),
+                        stds.len()
+                    )));
+                }
+                let ensemble_standard_error = match &data["ensemble_standard_error"] {
+                    Value::Null => None,
+                    errors => Some(flatten(errors)?),
+                };
+
+                Ok(EstimatorPubResult {
+                    evs,
+                    stds,
+                    ensemble_standard_error,
+                    metadata: result["metadata"].clone(),
+                    mode: ObservableMode::Whole,
+                })
+            })
+            .collect::<Result<_, EstimatorError>>()?;
+
+        Ok(Self {
+            pubs,
+            metadata: value["metadata"].clone(),
+        })
+    }
+
+    /// Take each result's observable mode from the submitted PUBs (same order)
+    /// Gantree: with_modes(self,modes) -> Self // 관측량 방식 적용
+    pub fn with_modes(mut self, modes: impl IntoIterator<Item = ObservableMode>) -> Self {
+        for (result, mode) in self.pubs.iter_mut().zip(modes) {
+            result.mode = mode;
+        }
+        self
+    }
+}
+
+/// Flatten a scalar or nested array of numbers (row-major)
+fn flatten(value: &Value) -> Result<Vec<f64>, EstimatorError> {
+    match value {
+        Value::Number(n) => Ok(vec![n.as_f64().unwrap_or(f64::NAN)]),
+        Value::Array(items) => {
+            let mut out = Vec::new();
+            for item in items {
+                out.extend(flatten(item)?);
+            }
+            Ok(out)
+        }
+        other => Err(EstimatorError::Decode(format!(
+            "expected numbers, got {}",
+            other
+        ))),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use serde_json::json;
+
+    const QASM: &str = "OPENQASM 3.0;\ninclude \"stdgates.inc\";\nqubit[3] q;\nh q[0];";
+
+    #[test]
+    fn test_pub_serialization() {
+        // Labels in map (sorted) order, so the round trip keeps term order
+        let op = SparsePauliOp::from_list(&[("X", -1.0), ("ZZ", 0.5)]).unwrap();
+        let pub_ = EstimatorPub::new(QASM)
+            .with_observable(op.clone())
+            .with_precision(0.01);
+        assert!(pub_.validate().is_ok());
+
+        let json = serde_json::to_value(&pub_).unwrap();
+        assert_eq!(json[1], json!([{"IZZ": 0.5, "IIX": -1.0}]));
+        assert!(json[2].is_null());
+        assert_eq!(json[3], 0.01);
+
+        let back: EstimatorPub = serde_json::from_value(json).unwrap();
+        assert_eq!(back, pub_);
+
+        let plain: EstimatorPub = serde_json::from_str(r#"["OPENQASM 3.0;", "ZI"]"#).unwrap();
+        assert_eq!(plain.observables.len(), 1);
+        assert_eq!(plain.precision, None);
+
+        let per_term = EstimatorPub::for_operator(QASM, &op);
+        assert_eq!(per_term.observables.len(), 2);
+        assert_eq!(
+            serde_json::to_value(&per_term).unwrap()[1],
+            json!([{"IIX": 1.0}, {"IZZ": 1.0}])
+        );
+    }
+
+    #[test]
+    fn test_pub_validation() {
+        assert!(EstimatorPub::new(QASM).validate().is_err());
+
+        let wide = SparsePauliOp::from_list(&[("ZZZZ", 1.0)]).unwrap();
+        assert!(EstimatorPub::new(QASM)
+            .with_observable(wide)
+            .validate()
+            .is_err());
+
+        let z = SparsePauliOp::from_list(&[("Z", 1.0)]).unwrap();
+        assert!(EstimatorPub::new(QASM)
+            .with_observable(z)
+            .with_precision(0.0)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_options_serialization() {
+        assert_eq!(
+            serde_json::to_value(EstimatorOptions::default()).unwrap(),
+            json!({})
+        );
+
+        let options = EstimatorOptions::default()
+            .with_precision(0.02)
+            .with_readout_mitigation(true)
+            .with_zne(ZneOptions {
+                noise_factors: vec![1.0, 2.0, 3.0],
+                extrapolator: ZneExtrapolator::Quadratic,
+            })
+            .with_twirling(TwirlingOptions {
+                num_randomizations: Some(32),
+                ..Default::default()
+            });
+        assert!(options.validate().is_ok());
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            json!({
+                "default_precision": 0.02,
+                "resilience": {
+                    "measure_mitigation": true,
+                    "zne_mitigation": true,
+                    "zne": {"noise_factors": [1.0, 2.0, 3.0], "extrapolator": "polynomial_degree_2"},
+                },
+                "twirling": {"enable_gates": true, "enable_measure": true, "num_randomizations": 32},
+            })
+        );
+
+        assert!(EstimatorOptions::default()
+            .with_resilience_level(3)
+            .validate()
+            .is_err());
+        assert!(EstimatorOptions::default()
+            .with_zne(ZneOptions {
+                noise_factors: vec![0.5],
+                ..Default::default()
+            })
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_decode_fixture() {
+        let payload: Value =
+            serde_json::from_str(include_str!("../fixtures/estimator_v2_result.json")).unwrap();
+        let result = EstimatorJobResult::from_json(&payload)
+            .unwrap()
+            .with_modes([ObservableMode::PerTerm, ObservableMode::Whole]);
+        assert_eq!(result.pubs.len(), 2);
+
+        // Per-term PUB recombined with the operator's coefficients
+        let op = SparsePauliOp::from_list(&[("ZZ", 0.5), ("XX", -0.25)]).unwrap();
+        let terms = &result.pubs[0];
+        assert_eq!(terms.shots(), 10240);
+        assert!(terms.ensemble_standard_error.is_some());
+
+        let estimate = terms.operator_result(&op).unwrap();
+        assert_relative_eq!(estimate.value, 0.5 * 0.912 - 0.25 * 0.874, epsilon = 1e-12);
+        assert_relative_eq!(
+            estimate.std_error,
+            ((0.5f64 * 0.011).powi(2) + (0.25f64 * 0.013).powi(2)).sqrt(),
+            epsilon = 1e-12
+        );
+        assert_eq!(estimate.term_values, [0.912, 0.874]);
+        assert_eq!(estimate.shots, 10240);
+
+        // Scalar single-observable PUB
+        let parity = result.pubs[1].operator_result(&op).unwrap();
+        assert_relative_eq!(parity.value, 0.781);
+        assert_relative_eq!(parity.std_error, 0.009);
+        assert!(parity.term_values.is_empty());
+
+        let three = SparsePauliOp::from_list(&[("Z", 1.0), ("X", 1.0), ("Y", 1.0)]).unwrap();
+        assert!(terms.operator_result(&three).is_err());
+    }
+
+    #[test]
+    fn test_single_term_operator_modes() {
+        // <0.5 ZZ> measured as one whole observable already includes the 0.5
+        let op = SparsePauliOp::from_list(&[("ZZ", 0.5)]).unwrap();
+        let payload = json!({"results": [{"data": {"evs": 0.4, "stds": 0.02}}]});
+
+        let whole = EstimatorJobResult::from_json(&payload).unwrap();
+        let estimate = whole.pubs[0].operator_result(&op).unwrap();
+        assert_relative_eq!(estimate.value, 0.4);
+        assert_relative_eq!(estimate.std_error, 0.02);
+
+        // Per-term <ZZ> is rescaled by the coefficient
+        let per_term = whole.with_modes([EstimatorPub::for_operator(QASM, &op).mode]);
+        let estimate = per_term.pubs[0].operator_result(&op).unwrap();
+        assert_relative_eq!(estimate.value, 0.2);
+        assert_relative_eq!(estimate.std_error, 0.01);
+
+        // A whole-operator PUB without values is an error, not a panic
+        let empty = json!({"results": [{"data": {"evs": [], "stds": []}}]});
+        let empty = EstimatorJobResult::from_json(&empty).unwrap();
+        assert!(empty.pubs[0].operator_result(&op).is_err());
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let decode = |v: Value| EstimatorJobResult::from_json(&v);
+        assert!(decode(json!({})).is_err());
+        assert!(decode(json!({"results": [{"data": {"evs": [1.0], "stds": []}}]})).is_err());
+        assert!(decode(json!({"results": [{"data": {"evs": ["x"], "stds": [0.1]}}]})).is_err());
+    }
+}
@@ -3,6 +3,11 @@
 //! Gantree: L10_Qiskit → Job
 
 use crate::client::{ClientError, IbmClient};
+use crate::estimator::{
+    EstimatorError, EstimatorJobResult, EstimatorOptions, EstimatorPub, ObservableMode,
+    ESTIMATOR_VERSION,
+};
+use crate::sampler::{SamplerError, SamplerPub, SamplerResult, SAMPLER_VERSION};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -39,8 +44,23 @@ pub enum JobError {
     /// Result not available
     #[error("Job results not yet available")]
     ResultsNotReady,
+
+    /// Runtime session closed or past its max TTL
+    #[error("Session {0} is closed")]
+    SessionClosed(String),
+
+    /// Sampler V2 PUB or result error
+    #[error("Sampler error: {0}")]
+    Sampler(#[from] SamplerError),
+
+    /// Estimator V2 PUB or result error
+    #[error("Estimator error: {0}")]
+    Estimator(#[from] EstimatorError),
 }
 
+/// Default status polling interval
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
 /// Job status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -121,15 +141,27 @@ pub struct JobSubmission {
     /// Job tags
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+
+    /// Runtime session or batch the job belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
 }
 
 /// Job parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JobParams {
     /// Circuits (as OpenQASM strings)
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub circuits: Vec<String>,
 
+    /// V2 primitive PUBs (replace `circuits`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pubs: Vec<Pub>,
+
+    /// Primitive version (2 for PUB submissions)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+
     /// Number of shots
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shots: Option<u64>,
@@ -146,19 +178,67 @@ pub struct JobParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub optimization_level: Option<u8>,
 
+    /// Estimator V2 options (sent as `options`)
+    #[serde(default, rename = "options", skip_serializing_if = "Option::is_none")]
+    pub estimator_options: Option<EstimatorOptions>,
+
     /// Additional options
     #[serde(flatten)]
     pub options: HashMap<String, serde_json::Value>,
 }
 
+/// V2 primitive PUB
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Pub {
+    /// Sampler PUB
+    Sampler(SamplerPub),
+
+    /// Estimator PUB
+    Estimator(EstimatorPub),
+}
+
+impl Pub {
+    /// Circuit of the PUB
+    pub fn circuit(&self) -> &str {
+        match self {
+            Self::Sampler(p) => &p.circuit,
+            Self::Estimator(p) => &p.circuit,
+        }
+    }
+
+    /// Mutable circuit of the PUB
+    pub fn circuit_mut(&mut self) -> &mut String {
+        match self {
+            Self::Sampler(p) => &mut p.circuit,
+            Self::Estimator(p) => &mut p.circuit,
+        }
+    }
+}
+
+impl From<SamplerPub> for Pub {
+    fn from(pub_: SamplerPub) -> Self {
+        Self::Sampler(pub_)
+    }
+}
+
+impl From<EstimatorPub> for Pub {
+    fn from(pub_: EstimatorPub) -> Self {
+        Self::Estimator(pub_)
+    }
+}
+
 impl Default for JobParams {
     fn default() -> Self {
         Self {
             circuits: Vec::new(),
+            pubs: Vec::new(),
+            version: None,
             shots: Some(4096),
             seed_simulator: None,
             skip_transpilation: None,
             optimization_level: Some(1),
+            estimator_options: None,
             options: HashMap::new(),
         }
     }
@@ -173,6 +253,30 @@ impl JobParams {
         }
     }
 
+    /// Create Sampler V2 params from PUBs
+    ///
+    /// The job-level `shots` applies to PUBs without their own count.
+    pub fn from_pubs(pubs: Vec<SamplerPub>) -> Self {
+        Self {
+            pubs: pubs.into_iter().map(Pub::from).collect(),
+            version: Some(SAMPLER_VERSION),
+            optimization_level: None,
+            ..Default::default()
+        }
+    }
+
+    /// Create Estimator V2 params from PUBs and options
+    pub fn from_estimator_pubs(pubs: Vec<EstimatorPub>, options: EstimatorOptions) -> Self {
+        Self {
+            pubs: pubs.into_iter().map(Pub::from).collect(),
+            version: Some(ESTIMATOR_VERSION),
+            shots: None,
+            optimization_level: None,
+            estimator_options: Some(options),
+            ..Default::default()
+        }
+    }
+
     /// Set number of shots
     pub fn with_shots(mut self, shots: u64) -> Self {
         self.shots = Some(shots);
@@ -324,6 +428,12 @@ pub struct IbmJob {
 
     /// Backend name
     backend: String,
+
+    /// Status polling interval for `wait`
+    poll_interval: Duration,
+
+    /// Observable modes of the submitted estimator PUBs (empty if unknown)
+    observable_modes: Vec<ObservableMode>,
 }
 
 impl IbmJob {
@@ -335,9 +445,17 @@ impl IbmJob {
             status,
             backend: response.backend.unwrap_or_default(),
             client,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            observable_modes: Vec::new(),
         }
     }
 
+    /// Set the status polling interval for `wait`
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
     /// Get job ID
     pub fn id(&self) -> &str {
         &self.id
@@ -379,7 +497,6 @@ impl IbmJob {
     /// Wait for job completion
     pub async fn wait(&mut self, timeout: Duration) -> Result<JobStatus, JobError> {
         let start = std::time::Instant::now();
-        let poll_interval = Duration::from_secs(5);
 
         loop {
             let status = self.refresh().await?;
@@ -397,7 +514,7 @@ impl IbmJob {
                 return Err(JobError::Timeout(timeout.as_secs()));
             }
 
-            tokio::time::sleep(poll_interval).await;
+            tokio::time::sleep(self.poll_interval).await;
         }
     }
 
@@ -412,6 +529,32 @@ impl IbmJob {
         Ok(result)
     }
 
+    /// Get Sampler V2 results of a PUB job
+    pub async fn sampler_result(&self) -> Result<SamplerResult, JobError> {
+        if !self.status.is_terminal() {
+            return Err(JobError::ResultsNotReady);
+        }
+
+        let path = format!("/jobs/{}/results", self.id);
+        let payload: serde_json::Value = self.client.get(&path).await?;
+        Ok(SamplerResult::from_json(&payload)?)
+    }
+
+    /// Get Estimator V2 results of an estimator job
+    ///
+    /// Jobs submitted with `JobManager::submit_estimator` tag each result
+    /// with its PUB's `ObservableMode`; for jobs fetched by ID, apply the
+    /// modes with `EstimatorJobResult::with_modes`.
+    pub async fn estimator_result(&self) -> Result<EstimatorJobResult, JobError> {
+        if !self.status.is_terminal() {
+            return Err(JobError::ResultsNotReady);
+        }
+
+        let path = format!("/jobs/{}/results", self.id);
+        let payload: serde_json::Value = self.client.get(&path).await?;
+        Ok(EstimatorJobResult::from_json(&payload)?.with_modes(self.observable_modes.clone()))
+    }
+
     /// Cancel job
     pub async fn cancel(&mut self) -> Result<(), JobError> {
         let path = format!("/jobs/{}", self.id);
@@ -422,14 +565,30 @@ impl IbmJob {
 }
 
 /// Job manager for submitting and tracking jobs
+#[derive(Clone)]
 pub struct JobManager {
     client: IbmClient,
+    poll_interval: Duration,
 }
 
 impl JobManager {
     /// Create new job manager
     pub fn new(client: IbmClient) -> Self {
-        Self { client }
+        Self {
+            client,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Set the status polling interval of the jobs it creates
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Get the API client
+    pub fn client(&self) -> &IbmClient {
+        &self.client
     }
 
     /// Submit a job
@@ -445,7 +604,7 @@ impl JobManager {
             ));
         }
 
-        Ok(IbmJob::new(response, self.client.clone()))
+        Ok(IbmJob::new(response, self.client.clone()).with_poll_interval(self.poll_interval))
     }
 
     /// Submit sampler job
@@ -460,16 +619,64 @@ impl JobManager {
             backend: backend.to_string(),
             params: JobParams::new(circuits).with_shots(shots),
             tags: vec!["niso".to_string()],
+            session_id: None,
+        };
+
+        self.submit(submission).await
+    }
+
+    /// Submit Sampler V2 PUBs
+    pub async fn submit_pubs(
+        &self,
+        backend: &str,
+        pubs: Vec<SamplerPub>,
+    ) -> Result<IbmJob, JobError> {
+        for pub_ in &pubs {
+            pub_.validate()?;
+        }
+
+        let submission = JobSubmission {
+            program_id: "sampler".to_string(),
+            backend: backend.to_string(),
+            params: JobParams::from_pubs(pubs),
+            tags: vec!["niso".to_string()],
+            session_id: None,
         };
 
         self.submit(submission).await
     }
 
+    /// Submit Estimator V2 PUBs
+    pub async fn submit_estimator(
+        &self,
+        backend: &str,
+        pubs: Vec<EstimatorPub>,
+        options: EstimatorOptions,
+    ) -> Result<IbmJob, JobError> {
+        options.validate()?;
+        for pub_ in &pubs {
+            pub_.validate()?;
+        }
+
+        let observable_modes = pubs.iter().map(|p| p.mode).collect();
+        let submission = JobSubmission {
+            program_id: "estimator".to_string(),
+            backend: backend.to_string(),
+            params: JobParams::from_estimator_pubs(pubs, options),
+            tags: vec!["niso".to_string()],
+            session_id: None,
+        };
+
+        let mut job = self.submit(submission).await?;
+        job.observable_modes = observable_modes;
+        Ok(job)
+    }
+
     /// Get existing job by ID
     pub async fn get_job(&self, job_id: &str) -> Result<IbmJob, JobError> {
         let path = format!("/jobs/{}", job_id);
         let response: JobResponse = self.client.get(&path).await?;
-        Ok(IbmJob::new(response, self.client.clone()))
+        Ok(IbmJob::new(response, self.client.clone()).with_poll_interval(self.poll_interval))
     }
 
     /// List recent jobs
@@ -489,6 +696,16 @@ struct JobsListResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock::MockRuntime;
+    use niso_core::SparsePauliOp;
+
+    const QASM: &str =
+        "OPENQASM 3.0;\ninclude \"stdgates.inc\";\nqubit[2] q;\nbit[2] c;\nc = measure q;";
+
+    fn manager(mock: &MockRuntime) -> JobManager {
+        JobManager::new(IbmClient::new(mock.credentials()).unwrap())
+            .with_poll_interval(Duration::from_millis(5))
+    }
 
     #[test]
     fn test_job_status_terminal() {
@@ -558,4 +775,196 @@ mod tests {
         assert_eq!(counts.get("00"), Some(&512));
         assert_eq!(counts.get("11"), Some(&512));
     }
+
+    #[tokio::test]
+    async fn test_submit_wait_and_result() {
+        let mock = MockRuntime::start();
+        mock.set_queue_polls(3);
+        let manager = manager(&mock);
+
+        let mut job = manager
+            .submit_sampler("ibm_mock", vec![QASM.to_string(); 2], 1000)
+            .await
+            .unwrap();
+        assert_eq!(job.backend(), "ibm_mock");
+        assert_eq!(job.status(), JobStatus::Queued);
+        assert!(matches!(job.result().await, Err(JobError::ResultsNotReady)));
+
+        let status = job.wait(Duration::from_secs(10)).await.unwrap();
+        assert_eq!(status, JobStatus::Completed);
+        assert_eq!(
+            mock.request_count(&format!("GET /api/v1/jobs/{}", job.id())),
+            4
+        );
+
+        let result = job.result().await.unwrap();
+        assert_eq!(result.job_id.as_deref(), Some(job.id()));
+        assert_eq!(result.results.len(), 2);
+        assert_eq!(result.results[0].counts.as_ref().unwrap()["00"], 1000);
+        assert_eq!(mock.job_circuits(job.id()).unwrap()[0], QASM);
+    }
+
+    #[tokio::test]
+    async fn test_submit_pubs_and_sampler_result() {
+        let mock = MockRuntime::start();
+        mock.set_counts(HashMap::from([
+            ("00".to_string(), 3),
+            ("11".to_string(), 1),
+        ]));
+        let manager = manager(&mock);
+
+        let parametric = "OPENQASM 3.0;\ninput float[64] delta;\nqubit[2] q;\nbit[2] c;\nrz(delta) q[0];\nc = measure q;";
+        assert!(matches!(
+            manager
+                .submit_pubs("ibm_mock", vec![SamplerPub::new(parametric)])
+                .await,
+            Err(JobError::Sampler(_))
+        ));
+
+        let pubs = vec![
+            SamplerPub::sweep(parametric, &[-0.1, 0.0, 0.1]).with_shots(8),
+            SamplerPub::new(QASM),
+        ];
+        let mut job = manager.submit_pubs("ibm_mock", pubs).await.unwrap();
+        assert_eq!(mock.job_circuits(job.id()).unwrap()[0], parametric);
+        job.wait(Duration::from_secs(10)).await.unwrap();
+
+        let result = job.sampler_result().await.unwrap();
+        assert_eq!(result.pubs.len(), 2);
+
+        let sweep = result.pubs[0].register("c").unwrap();
+        assert_eq!(sweep.shape(), [3]);
+        assert_eq!(sweep.num_shots(), 8);
+        assert_eq!(
+            sweep.counts(2),
+            HashMap::from([("00".to_string(), 6), ("11".to_string(), 2)])
+        );
+
+        // Job-level default shots for the PUB without its own
+        let plain = result.pubs[1].register("c").unwrap();
+        assert!(plain.shape().is_empty());
+        assert_eq!(plain.num_shots(), 4096);
+    }
+
+    #[tokio::test]
+    async fn test_submit_estimator_and_result() {
+        let mock = MockRuntime::start();
+        let manager = manager(&mock);
+
+        let op = SparsePauliOp::from_list(&[("ZZ", 0.5), ("XX", -0.25), ("II", 0.1)]).unwrap();
+        let invalid = EstimatorOptions::default().with_resilience_level(5);
+        assert!(matches!(
+            manager
+                .submit_estimator("ibm_mock", vec![EstimatorPub::new(QASM)], invalid)
+                .await,
+            Err(JobError::Estimator(_))
+        ));
+
+        let options = EstimatorOptions::default()
+            .with_readout_mitigation(true)
+            .with_twirling(Default::default());
+        let pubs = vec![
+            EstimatorPub::for_operator(QASM, &op),
+            EstimatorPub::new(QASM)
+                .with_observable(op.clone())
+                .with_precision(0.01),
+        ];
+        let mut job = manager
+            .submit_estimator("ibm_mock", pubs, options)
+            .await
+            .unwrap();
+        job.wait(Duration::from_secs(10)).await.unwrap();
+
+        let result = job.estimator_result().await.unwrap();
+        let per_term = result.pubs[0].operator_result(&op).unwrap();
+        assert_eq!(per_term.term_values, [1.0, 0.0, 1.0]);
+        assert!((per_term.value - 0.6).abs() < 1e-12);
+
+        let whole = result.pubs[1].operator_result(&op).unwrap();
+        assert!((whole.value - 0.6).abs() < 1e-12);
+        assert!(whole.term_values.is_empty());
+    }
+
+    #[test]
+    fn test_pub_params_roundtrip() {
+        let op = SparsePauliOp::from_list(&[("ZZ", 1.0)]).unwrap();
+        let params = JobParams::from_estimator_pubs(
+            vec![EstimatorPub::new(QASM).with_observable(op)],
+            EstimatorOptions::default().with_readout_mitigation(true),
+        );
+
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json["version"], 2);
+        assert_eq!(json["options"]["resilience"]["measure_mitigation"], true);
+        assert!(json.get("shots").is_none());
+
+        let back: JobParams = serde_json::from_value(json).unwrap();
+        assert!(matches!(back.pubs[0], Pub::Estimator(_)));
+        assert_eq!(back.estimator_options, params.estimator_options);
+
+        let sampler = JobParams::from_pubs(vec![SamplerPub::sweep(QASM, &[0.1])]);
+        let back: JobParams =
+            serde_json::from_value(serde_json::to_value(&sampler).unwrap()).unwrap();
+        assert_eq!(back.pubs, sampler.pubs);
+    }
+
+    #[tokio::test]
+    async fn test_failed_job_reports_reason() {
+        let mock = MockRuntime::start();
+        mock.fail_next_job("circuit too deep");
+
+        let mut job = manager(&mock)
+            .submit_sampler("ibm_mock", vec![QASM.to_string()], 100)
+            .await
+            .unwrap();
+
+        match job.wait(Duration::from_secs(10)).await {
+            Err(JobError::ExecutionFailed(reason)) => assert_eq!(reason, "circuit too deep"),
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_get_and_list_jobs() {
+        let mock = MockRuntime::start();
+        mock.set_queue_polls(100);
+        let manager = manager(&mock);
+
+        let mut first = manager
+            .submit_sampler("ibm_mock", vec![QASM.to_string()], 100)
+            .await
+            .unwrap();
+        let second = manager
+            .submit_sampler("ibm_mock", vec![QASM.to_string()], 100)
+            .await
+            .unwrap();
+
+        first.cancel().await.unwrap();
+        let mut fetched = manager.get_job(first.id()).await.unwrap();
+        assert_eq!(fetched.status(), JobStatus::Cancelled);
+        assert!(matches!(
+            fetched.wait(Duration::from_secs(1)).await,
+            Err(JobError::Cancelled)
+        ));
+
+        let jobs = manager.list_jobs(1).await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].id, second.id());
+        assert_eq!(manager.list_jobs(10).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_wait_timeout() {
+        let mock = MockRuntime::start();
+        mock.set_queue_polls(usize::MAX);
+
+        let mut job = manager(&mock)
+            .submit_sampler("ibm_mock", vec![QASM.to_string()], 100)
+            .await
+            .unwrap();
+
+        let result = job.wait(Duration::from_millis(20)).await;
+        assert!(matches!(result, Err(JobError::Timeout(_))));
+        assert_eq!(job.status(), JobStatus::Queued);
+    }
 }
@@ -34,15 +34,19 @@
 //! use niso_engine::prelude::*;
 //! use niso_qiskit::IbmBackend;
 //!
-//! // Create IBM backend
-//! let ibm_backend = IbmBackend::from_env("ibm_brisbane")?;
+//! // Create IBM backend and fetch its calibration data
+//! let mut ibm_backend = IbmBackend::from_env("ibm_brisbane")?;
+//! ibm_backend.refresh_properties()?;
 //!
 //! // Use with NISO optimizer
 //! let config = NisoConfig::default_7q()
 //!     .with_noise(0.02);
 //!
-//! // Note: Integration with NisoOptimizer requires
-//! // using the Backend trait implementation
+//! // Calibration comes from the properties fetched above; without
+//! // `refresh_properties()` the backend has none and the run reports no
+//! // calibration summary
+//! let mut optimizer = NisoOptimizer::new(config).with_backend(Box::new(ibm_backend));
+//! let result = optimizer.optimize()?;
 //! ```
 //!
 //! ## Async Job Submission
@@ -59,6 +63,53 @@
 //! // Later, retrieve results
 //! let result = backend.get_results(&job_id)?;
 //! ```
+//!
+//! ## Sessions
+//!
+//! ```rust,ignore
+//! use niso_qiskit::prelude::*;
+//!
+//! // Every job of the optimization runs in one session and keeps priority
+//! let backend = IbmBackend::from_env("ibm_brisbane")?
+//!     .with_session(SessionMode::Dedicated, Duration::from_secs(3600))?;
+//! ```
+//!
+//! ## Sampler V2 Sweeps
+//!
+//! ```rust,ignore
+//! use niso_qiskit::prelude::*;
+//!
+//! // One PUB runs the parametric circuit for every δ
+//! let sweep = SamplerPub::sweep(parametric_qasm, &[-0.1, 0.0, 0.1]).with_shots(1024);
+//! let mut job = manager.submit_pubs("ibm_brisbane", vec![sweep]).await?;
+//! job.wait(timeout).await?;
+//! let counts = job.sampler_result().await?.pubs[0].counts(1)?;
+//! ```
+//!
+//! ## Estimator V2
+//!
+//! ```rust,ignore
+//! use niso_qiskit::prelude::*;
+//!
+//! // IBM's mitigated parity estimate, to compare with TQQC's own
+//! let parity = SparsePauliOp::from_list(&[("ZZZ", 1.0)])?;
+//! let options = EstimatorOptions::default().with_readout_mitigation(true);
+//! let pub_ = EstimatorPub::new(qasm).with_observable(parity.clone());
+//! let mut job = manager.submit_estimator("ibm_brisbane", vec![pub_], options).await?;
+//! job.wait(timeout).await?;
+//! let estimate = job.estimator_result().await?.pubs[0].operator_result(&parity)?;
+//! ```
+//!
+//! ## Encrypted Accounts
+//!
+//! ```rust,ignore
+//! use niso_qiskit::prelude::*;
+//!
+//! // Same layout as ~/.qiskit/qiskit-ibm.json, encrypted with a passphrase
+//! let mut store = AccountStore::open(AccountStore::default_path()?, &passphrase)?;
+//! store.import_qiskit_config(home.join(".qiskit/qiskit-ibm.json"))?;
+//! let backend = IbmBackend::new("ibm_brisbane", store.credentials(None)?)?;
+//! ```
 
 #![warn(missing_docs)]
 
@@ -69,31 +120,76 @@
 /// Authentication (Gantree: L10_Qiskit ??Auth)
 pub mod auth;
 
+/// Encrypted account store (Gantree: L10_Qiskit ??AccountStore)
+pub mod accounts;
+
 /// REST API client (Gantree: L10_Qiskit ??Client)
 pub mod client;
 
 /// Job management (Gantree: L10_Qiskit ??Job)
 pub mod job;
 
+/// Runtime sessions and batches (Gantree: L10_Qiskit ??Session)
+pub mod session;
+
+/// Sampler V2 PUBs and results (Gantree: L10_Qiskit ??Sampler)
+pub mod sampler;
+
+/// Estimator V2 PUBs, options and results (Gantree: L10_Qiskit ??Estimator)
+pub mod estimator;
+
 /// Circuit transpilation (Gantree: L10_Qiskit ??Transpiler)
 pub mod transpiler;
 
+/// Backend properties to calibration conversion (Gantree: L10_Qiskit ??Calibration)
+pub mod calibration;
+
 /// IBM backend implementation (Gantree: L10_Qiskit ??Backend)
 pub mod backend;
 
+/// Calibration- and queue-aware backend ranking (Gantree: L10_Qiskit ??Recommend)
+pub mod recommend;
+
+/// Durable job store (Gantree: L10_Qiskit ??JobStore)
+pub mod store;
+
+/// Offline mock runtime server (Gantree: L10_Qiskit ??MockRuntime)
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+
 // ============================================================================
 // Re-exports
 // ============================================================================
 
-pub use auth::{AuthError, IbmChannel, IbmCredentials, TokenType};
-pub use backend::{list_backends, recommend_backend, IbmBackend, IbmBackendError};
+pub use accounts::{
+    AccountStore, ACCOUNT_STORE_VERSION, DEFAULT_KDF_ITERATIONS, KDF_ITERATIONS_RANGE,
+};
+pub use auth::{
+    AuthError, IbmChannel, IbmCredentials, QiskitConfigEntry, TokenInfo, TokenType,
+    TOKEN_REFRESH_MARGIN,
+};
+#[allow(deprecated)]
+pub use backend::recommend_backend;
+pub use backend::{list_backends, IbmBackend, IbmBackendError};
+pub use calibration::{properties_to_calibration, NATIVE_2Q_GATES};
 pub use client::{
     BackendConfig, BackendInfo, BackendProperties, BackendStatus, ClientError, IbmClient,
 };
+pub use estimator::{
+    EstimatorError, EstimatorJobResult, EstimatorOptions, EstimatorPub, EstimatorPubResult,
+    ObservableMode, ResilienceOptions, TwirlingOptions, ZneExtrapolator, ZneOptions,
+    ESTIMATOR_VERSION,
+};
 pub use job::{
     CircuitResult, IbmJob, JobError, JobManager, JobParams, JobResponse, JobResult, JobStatus,
-    JobSubmission,
+    JobSubmission, Pub,
+};
+pub use recommend::{rank_backends, recommend_for_circuit, BackendScore, QUEUE_SCALE};
+pub use sampler::{BitArray, PubResult, SamplerError, SamplerPub, SamplerResult, SAMPLER_VERSION};
+pub use session::{
+    IbmBatch, IbmSession, SessionDetails, SessionMode, SessionState, DEFAULT_MAX_TTL,
 };
+pub use store::{circuit_hash, JobRecord, JobStore, StoreError, SyncReport};
 pub use transpiler::{Transpiler, TranspilerConfig, IBM_BASIS_GATES};
 
 // ============================================================================
@@ -103,10 +199,18 @@ pub use transpiler::{Transpiler, TranspilerConfig, IBM_BASIS_GATES};
 // Convenient imports below
 /// Prelude module for convenient imports.
 pub mod prelude {
+    pub use crate::accounts::AccountStore;
     pub use crate::auth::{IbmChannel, IbmCredentials};
-    pub use crate::backend::{list_backends, recommend_backend, IbmBackend};
+    #[allow(deprecated)]
+    pub use crate::backend::recommend_backend;
+    pub use crate::backend::{list_backends, IbmBackend};
     pub use crate::client::IbmClient;
+    pub use crate::estimator::{EstimatorOptions, EstimatorPub};
     pub use crate::job::{JobManager, JobParams, JobStatus};
+    pub use crate::recommend::{rank_backends, recommend_for_circuit, BackendScore};
+    pub use crate::sampler::{SamplerPub, SamplerResult};
+    pub use crate::session::{IbmBatch, IbmSession, SessionMode};
+    pub use crate::store::{JobRecord, JobStore};
     pub use crate::transpiler::{Transpiler, TranspilerConfig};
 }
 
@@ -0,0 +1,1193 @@
+//! Offline mock of the IBM Quantum Runtime API
+//!
+//! Gantree: L10_Qiskit → MockRuntime
+//!
+//! An in-process HTTP server emulating the endpoints `IbmClient`,
+//! `JobManager` and `IbmCredentials` talk to:
+//!
+//! - `GET  /api/v1/backends` (2025 `devices`, legacy `backends` or bare array)
+//! - `GET  /api/v1/backends/{name}[/properties|configuration|status]`
+//! - `POST /api/v1/jobs`, `GET /api/v1/jobs[?limit]`,
+//!   `GET /api/v1/jobs/{id}`, `GET /api/v1/jobs/{id}/results`,
+//!   `DELETE /api/v1/jobs/{id}` (Sampler V2 PUB jobs get `BitArray`
+//!   results, Estimator V2 jobs exact |0…0⟩ expectation values)
+//! - `POST /identity/token` (IAM API-key exchange)
+//!
+//! Queue delays, failed jobs and HTTP failures (429/503/...) are scripted
+//! per test. The server runs on its own thread and stops on drop.
+//!
+//! ```rust,ignore
+//! let mock = MockRuntime::start();
+//! let backend = IbmBackend::new("ibm_mock", mock.credentials())?;
+//! ```
+
+use crate::auth::IbmCredentials;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
+
+/// Shape of the `GET /backends` response
+/// Gantree: BackendsFormat // 백엔드 목록 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackendsFormat {
+    /// 2025 API: `{"devices": [...]}`
+    #[default]
+    Devices,
+    /// Legacy: `{"backends": [...]}`
+    Legacy,
+    /// Bare array: `[...]`
+    Array,
+}
+
+/// Backend served by the mock
+/// Gantree: MockBackend // 모의 백엔드
+#[derive(Debug, Clone)]
+pub struct MockBackend {
+    /// Backend name
+    pub name: String,
+
+    /// Number of qubits
+    pub num_qubits: usize,
+
+    /// Whether it is a simulator
+    pub simulator: bool,
+
+    /// Whether it accepts jobs
+    pub operational: bool,
+
+    /// Reported queue length
+    pub pending_jobs: u64,
+
+    /// `/properties` body
+    pub properties: Value,
+}
+
+impl MockBackend {
+    /// Operational device with uniform calibration on a line coupling map
+    pub fn new(name: impl Into<String>, num_qubits: usize) -> Self {
+        let qubits: Vec<Value> = (0..num_qubits)
+            .map(|_| {
+                json!([
+                    {"name": "T1", "value": 120.0, "unit": "us"},
+                    {"name": "T2", "value": 90.0, "unit": "us"},
+                    {"name": "readout_error", "value": 0.015},
+                    {"name": "readout_length", "value": 1.2, "unit": "us"}
+                ])
+            })
+            .collect();
+        let mut gates: Vec<Value> = (0..num_qubits)
+            .map(|q| {
+                json!({"gate": "sx", "qubits": [q], "parameters": [
+                    {"name": "gate_error", "value": 0.0003},
+                    {"name": "gate_length", "value": 36.0, "unit": "ns"}]})
+            })
+            .collect();
+        gates.extend((1..num_qubits).map(|q| {
+            json!({"gate": "ecr", "qubits": [q - 1, q], "parameters": [
+                {"name": "gate_error", "value": 0.008},
+                {"name": "gate_length", "value": 660.0, "unit": "ns"}]})
+        }));
+
+        Self {
+            name: name.into(),
+            num_qubits,
+            simulator: false,
+            operational: true,
+            pending_jobs: 0,
+            properties: json!({
+                "last_update_date": "2025-01-01T00:00:00Z",
+                "qubits": qubits,
+                "gates": gates,
+            }),
+        }
+    }
+
+    /// Mark as simulator
+    pub fn simulator(mut self) -> Self {
+        self.simulator = true;
+        self
+    }
+
+    /// Set queue length
+    pub fn with_pending_jobs(mut self, pending: u64) -> Self {
+        self.pending_jobs = pending;
+        self
+    }
+
+    /// Set operational flag
+    pub fn with_operational(mut self, operational: bool) -> Self {
+        self.operational = operational;
+        self
+    }
+
+    /// Set the ECR error of every edge
+    pub fn with_gate_error_2q(mut self, error: f64) -> Self {
+        for gate in self.properties["gates"]
+            .as_array_mut()
+            .into_iter()
+            .flatten()
+        {
+            if gate["gate"] == "ecr" {
+                gate["parameters"][0]["value"] = json!(error);
+            }
+        }
+        self
+    }
+
+    /// Line coupling map
+    fn coupling_map(&self) -> Vec<[usize; 2]> {
+        (1..self.num_qubits).map(|q| [q - 1, q]).collect()
+    }
+
+    fn device_json(&self) -> Value {
+        json!({
+            "name": self.name,
+            "qubits": self.num_qubits,
+            "processor_type": {"family": "Mock", "revision": "1"},
+            "queue_length": self.pending_jobs,
+            "status": {"name": if self.operational { "online" } else { "offline" }},
+            "clops": {"type": if self.simulator { "simulator" } else { "hardware" }, "value": 5000},
+        })
+    }
+
+    fn legacy_json(&self) -> Value {
+        json!({
+            "name": self.name,
+            "n_qubits": self.num_qubits,
+            "simulator": self.simulator,
+            "operational": self.operational,
+            "queue_length": self.pending_jobs,
+        })
+    }
+
+    fn configuration_json(&self) -> Value {
+        json!({
+            "backend_name": self.name,
+            "n_qubits": self.num_qubits,
+            "basis_gates": ["ecr", "id", "rz", "sx", "x"],
+            "coupling_map": self.coupling_map(),
+            "max_shots": 100_000,
+            "max_experiments": 300,
+        })
+    }
+
+    fn status_json(&self) -> Value {
+        json!({
+            "backend_name": self.name,
+            "operational": self.operational,
+            "pending_jobs": self.pending_jobs,
+            "status_msg": if self.operational { "active" } else { "maintenance" },
+        })
+    }
+}
+
+/// Scripted HTTP failure
+/// Gantree: MockFailure // 실패 스크립트
+#[derive(Debug, Clone)]
+pub struct MockFailure {
+    /// Request method (`GET`, `POST`, ...); `None` matches any
+    pub method: Option<String>,
+
+    /// Substring the request path must contain
+    pub path: String,
+
+    /// HTTP status to return
+    pub status: u16,
+
+    /// `Retry-After` header value (seconds)
+    pub retry_after: Option<u64>,
+
+    /// Number of matching requests to fail
+    pub times: usize,
+}
+
+impl MockFailure {
+    /// Fail the next request whose path contains `path` with `status`
+    pub fn new(path: impl Into<String>, status: u16) -> Self {
+        Self {
+            method: None,
+            path: path.into(),
+            status,
+            retry_after: None,
+            times: 1,
+        }
+    }
+
+    /// Only match one request method
+    pub fn with_method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    /// Send a `Retry-After` header
+    pub fn with_retry_after(mut self, seconds: u64) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
+
+    /// Fail `times` matching requests
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = times;
+        self
+    }
+}
+
+/// Job held by the mock
+#[derive(Debug, Clone)]
+struct MockJob {
+    id: String,
+    backend: String,
+    circuits: Vec<String>,
+    shots: u64,
+    polls_left: usize,
+    failure: Option<String>,
+    cancelled: bool,
+    session_id: Option<String>,
+    /// (bindings, shots) per Sampler V2 PUB; empty for circuit jobs
+    pubs: Vec<(usize, u64)>,
+    /// Expectation values per Estimator V2 PUB
+    estimates: Vec<Vec<f64>>,
+}
+
+impl MockJob {
+    fn status(&self) -> &'static str {
+        if self.cancelled {
+            "Cancelled"
+        } else if self.polls_left > 1 {
+            "Queued"
+        } else if self.polls_left == 1 {
+            "Running"
+        } else if self.failure.is_some() {
+            "Failed"
+        } else {
+            "Completed"
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "backend": self.backend,
+            "state": {"status": self.status(), "reason": self.failure},
+        })
+    }
+}
+
+/// Session or batch held by the mock
+#[derive(Debug, Clone)]
+struct MockSession {
+    id: String,
+    backend: String,
+    mode: String,
+    max_ttl: u64,
+    accepting_jobs: bool,
+    closed_reason: Option<String>,
+}
+
+impl MockSession {
+    fn to_json(&self) -> Value {
+        let state = if self.closed_reason.is_some() {
+            "closed"
+        } else {
+            "open"
+        };
+        json!({
+            "id": self.id,
+            "backend_name": self.backend,
+            "mode": self.mode,
+            "state": state,
+            "accepting_jobs": self.accepting_jobs && self.closed_reason.is_none(),
+            "max_time": self.max_ttl,
+            "closed_reason": self.closed_reason,
+        })
+    }
+}
+
+/// Mutable server state
+#[derive(Debug)]
+struct MockState {
+    backends: Vec<MockBackend>,
+    backends_format: BackendsFormat,
+    jobs: Vec<MockJob>,
+    sessions: Vec<MockSession>,
+    queue_polls: usize,
+    next_job_failure: Option<String>,
+    counts: Option<HashMap<String, u64>>,
+    failures: VecDeque<MockFailure>,
+    valid_tokens: Vec<String>,
+    iam_expires_in: u64,
+    iam_jwt_exp_in: Option<i64>,
+    iam_issued: usize,
+    requests: Vec<String>,
+}
+
+/// Handle to a running mock server
+/// Gantree: MockRuntime // 모의 IBM 런타임
+pub struct MockRuntime {
+    addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockRuntime {
+    /// IQP token accepted by the mock
+    pub const TOKEN: &'static str = "mock_iqp_token_0123456789abcdef0123456789abcdef";
+
+    /// API key accepted by the mock IAM endpoint
+    pub const API_KEY: &'static str = "ApiKey-mock0123456789abcdef0123456789abcdef";
+
+    /// Start a server with the default backends
+    ///
+    /// `ibm_mock` (5 qubits), `ibm_mock_large` (27 qubits, busy) and
+    /// `simulator_mock` (32 qubits).
+    pub fn start() -> Self {
+        Self::with_backends(vec![
+            MockBackend::new("ibm_mock", 5).with_pending_jobs(3),
+            MockBackend::new("ibm_mock_large", 27).with_pending_jobs(40),
+            MockBackend::new("simulator_mock", 32).simulator(),
+        ])
+    }
+
+    /// Start a server serving `backends`
+    pub fn with_backends(backends: Vec<MockBackend>) -> Self {
+        let state = Arc::new(Mutex::new(MockState {
+            backends,
+            backends_format: BackendsFormat::default(),
+            jobs: Vec::new(),
+            sessions: Vec::new(),
+            queue_polls: 0,
+            next_job_failure: None,
+            counts: None,
+            failures: VecDeque::new(),
+            valid_tokens: vec![Self::TOKEN.to_string()],
+            iam_expires_in: 3600,
+            iam_jwt_exp_in: None,
+            iam_issued: 0,
+            requests: Vec::new(),
+        }));
+
+        let listener =
+            std::net::TcpListener::bind("127.0.0.1:0").expect("mock server: bind failed");
+        listener
+            .set_nonblocking(true)
+            .expect("mock server: set_nonblocking failed");
+        let addr = listener.local_addr().expect("mock server: no local addr");
+        let (shutdown, stop) = oneshot::channel();
+
+        let server_state = Arc::clone(&state);
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("mock server: runtime");
+            runtime.block_on(serve(listener, server_state, stop));
+        });
+
+        Self {
+            addr,
+            state,
+            shutdown: Some(shutdown),
+        }
+    }
+
+    // ========================================================================
+    // Endpoints & Credentials
+    // ========================================================================
+
+    /// Runtime API base URL (`http://127.0.0.1:port/api/v1`)
+    pub fn url(&self) -> String {
+        format!("http://{}/api/v1", self.addr)
+    }
+
+    /// IAM token endpoint
+    pub fn iam_url(&self) -> String {
+        format!("http://{}/identity/token", self.addr)
+    }
+
+    /// IQP-token credentials pointing at this server
+    pub fn credentials(&self) -> IbmCredentials {
+        IbmCredentials::new(Self::TOKEN).with_url(self.url())
+    }
+
+    /// API-key credentials pointing at this server and its IAM endpoint
+    pub fn api_key_credentials(&self) -> IbmCredentials {
+        IbmCredentials::new(Self::API_KEY)
+            .with_url(self.url())
+            .with_iam_url(self.iam_url())
+    }
+
+    // ========================================================================
+    // Scripting
+    // ========================================================================
+
+    /// Select the `GET /backends` response shape
+    pub fn set_backends_format(&self, format: BackendsFormat) {
+        self.lock().backends_format = format;
+    }
+
+    /// Jobs submitted from now on report `polls` non-terminal statuses
+    /// (queued, then running) before finishing
+    pub fn set_queue_polls(&self, polls: usize) {
+        self.lock().queue_polls = polls;
+    }
+
+    /// The next submitted job fails with `reason`
+    pub fn fail_next_job(&self, reason: impl Into<String>) {
+        self.lock().next_job_failure = Some(reason.into());
+    }
+
+    /// Counts returned for every circuit (default: all shots on |0…0⟩)
+    pub fn set_counts(&self, counts: HashMap<String, u64>) {
+        self.lock().counts = Some(counts);
+    }
+
+    /// Queue a scripted HTTP failure
+    pub fn push_failure(&self, failure: MockFailure) {
+        self.lock().failures.push_back(failure);
+    }
+
+    /// Close session `id` server-side, as its max TTL would
+    pub fn expire_session(&self, id: &str) {
+        if let Some(session) = self.lock().sessions.iter_mut().find(|s| s.id == id) {
+            session.closed_reason = Some("max TTL exceeded".to_string());
+        }
+    }
+
+    /// Lifetime of IAM tokens issued from now on
+    pub fn set_iam_expires_in(&self, seconds: u64) {
+        self.lock().iam_expires_in = seconds;
+    }
+
+    /// Issue IAM tokens as JWTs whose `exp` claim is `seconds` from now
+    /// (independent of the reported `expires_in`)
+    pub fn set_iam_jwt_exp_in(&self, seconds: i64) {
+        self.lock().iam_jwt_exp_in = Some(seconds);
+    }
+
+    // ========================================================================
+    // Inspection
+    // ========================================================================
+
+    /// Requests received so far, as `"METHOD /path"`
+    pub fn requests(&self) -> Vec<String> {
+        self.lock().requests.clone()
+    }
+
+    /// Number of received requests whose `"METHOD /path"` contains `pattern`
+    pub fn request_count(&self, pattern: &str) -> usize {
+        self.lock()
+            .requests
+            .iter()
+            .filter(|r| r.contains(pattern))
+            .count()
+    }
+
+    /// Number of IAM tokens issued
+    pub fn iam_tokens_issued(&self) -> usize {
+        self.lock().iam_issued
+    }
+
+    /// IDs of submitted jobs, oldest first
+    pub fn job_ids(&self) -> Vec<String> {
+        self.lock().jobs.iter().map(|j| j.id.clone()).collect()
+    }
+
+    /// Circuits submitted with job `id`
+    pub fn job_circuits(&self, id: &str) -> Option<Vec<String>> {
+        self.lock()
+            .jobs
+            .iter()
+            .find(|j| j.id == id)
+            .map(|j| j.circuits.clone())
+    }
+
+    /// Session job `id` was submitted into
+    pub fn job_session(&self, id: &str) -> Option<String> {
+        self.lock()
+            .jobs
+            .iter()
+            .find(|j| j.id == id)
+            .and_then(|j| j.session_id.clone())
+    }
+
+    /// IDs of opened sessions and batches, oldest first
+    pub fn session_ids(&self) -> Vec<String> {
+        self.lock().sessions.iter().map(|s| s.id.clone()).collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for MockRuntime {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+// ============================================================================
+// HTTP Server
+// ============================================================================
+
+/// Minimal HTTP/1.1 request
+struct Request {
+    method: String,
+    path: String,
+    query: HashMap<String, String>,
+    authorization: Option<String>,
+    body: String,
+}
+
+/// Response to write back
+struct Response {
+    status: u16,
+    body: String,
+    retry_after: Option<u64>,
+}
+
+impl Response {
+    fn json(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            body: body.to_string(),
+            retry_after: None,
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, json!({"errors": [{"message": message}]}))
+    }
+}
+
+async fn serve(
+    listener: std::net::TcpListener,
+    state: Arc<Mutex<MockState>>,
+    mut stop: oneshot::Receiver<()>,
+) {
+    let Ok(listener) = TcpListener::from_std(listener) else {
+        return;
+    };
+
+    loop {
+        tokio::select! {
+            _ = &mut stop => break,
+            accepted = listener.accept() => {
+                if let Ok((stream, _)) = accepted {
+                    let state = Arc::clone(&state);
+                    tokio::spawn(async move {
+                        let _ = handle_connection(stream, state).await;
+                    });
+                }
+            }
+        }
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    state: Arc<Mutex<MockState>>,
+) -> std::io::Result<()> {
+    let Some(request) = read_request(&mut stream).await? else {
+        return Ok(());
+    };
+
+    let response = {
+        let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+        route(&mut state, &request)
+    };
+
+    let reason = match response.status {
+        200 => "OK",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        409 => "Conflict",
+        429 => "Too Many Requests",
+        503 => "Service Unavailable",
+        _ => "Error",
+    };
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        reason,
+        response.body.len()
+    );
+    if let Some(seconds) = response.retry_after {
+        head.push_str(&format!("Retry-After: {}\r\n", seconds));
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(response.body.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+async fn read_request(stream: &mut TcpStream) -> std::io::Result<Option<Request>> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut parts = lines.next().unwrap_or_default().split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    let mut authorization = None;
+    for line in lines {
+        if let Some((name, value)) = line.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse().unwrap_or(0),
+                "authorization" => authorization = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    while buf.len() < header_end + content_length {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    let body = String::from_utf8_lossy(&buf[header_end..]).to_string();
+
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), parse_query(query)),
+        None => (target, HashMap::new()),
+    };
+
+    Ok(Some(Request {
+        method,
+        path,
+        query,
+        authorization,
+        body,
+    }))
+}
+
+/// Parse `a=1&b=2` (query strings and form bodies)
+fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.to_string(), v.replace("%3A", ":").replace("%3a", ":")))
+        .collect()
+}
+
+// ============================================================================
+// Routing
+// ============================================================================
+
+fn route(state: &mut MockState, request: &Request) -> Response {
+    state
+        .requests
+        .push(format!("{} {}", request.method, request.path));
+
+    // Scripted failures take precedence
+    let matched = state.failures.iter().position(|f| {
+        request.path.contains(&f.path)
+            && f.method
+                .as_deref()
+                .is_none_or(|m| m.eq_ignore_ascii_case(&request.method))
+    });
+    if let Some(index) = matched {
+        let failure = state.failures[index].clone();
+        if failure.times <= 1 {
+            state.failures.remove(index);
+        } else {
+            state.failures[index].times -= 1;
+        }
+        let mut response = Response::error(failure.status, "scripted failure");
+        response.retry_after = failure.retry_after;
+        return response;
+    }
+
+    if request.path == "/identity/token" && request.method == "POST" {
+        return iam_token(state, request);
+    }
+
+    let Some(path) = request.path.strip_prefix("/api/v1") else {
+        return Response::error(404, "not found");
+    };
+
+    let authorized = request
+        .authorization
+        .as_deref()
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .is_some_and(|token| state.valid_tokens.iter().any(|t| t == token));
+    if !authorized {
+        return Response::error(401, "invalid or missing token");
+    }
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["backends"]) => list_backends(state),
+        ("GET", ["backends", name]) => backend_resource(state, name, "info"),
+        ("GET", ["backends", name, resource]) => backend_resource(state, name, resource),
+        ("POST", ["jobs"]) => submit_job(state, &request.body),
+        ("GET", ["jobs"]) => list_jobs(state, request),
+        ("GET", ["jobs", id]) => job_status(state, id),
+        ("GET", ["jobs", id, "results"]) => job_results(state, id),
+        ("DELETE", ["jobs", id]) => cancel_job(state, id),
+        ("POST", ["sessions"]) => open_session(state, &request.body),
+        ("GET", ["sessions", id]) => session_details(state, id),
+        ("PATCH", ["sessions", id]) => update_session(state, id, &request.body),
+        ("DELETE", ["sessions", id, "close"]) => cancel_session(state, id),
+        _ => Response::error(404, "not found"),
+    }
+}
+
+fn iam_token(state: &mut MockState, request: &Request) -> Response {
+    let form = parse_query(&request.body);
+    let expected = MockRuntime::API_KEY.trim_start_matches("ApiKey-");
+
+    if form.get("apikey").map(String::as_str) != Some(expected) {
+        return Response::json(
+            400,
+            json!({"errorCode": "BXNIM0415E", "errorMessage": "Provided API key could not be found"}),
+        );
+    }
+
+    state.iam_issued += 1;
+    let mut token = format!("mock_iam_token_{}", state.iam_issued);
+    if let Some(exp_in) = state.iam_jwt_exp_in {
+        let now = chrono::Utc::now().timestamp();
+        let claims = json!({"iam_id": "IBMid-mock", "iat": now, "exp": now + exp_in});
+        token = format!(
+            "eyJhbGciOiJub25lIn0.{}.{}",
+            URL_SAFE_NO_PAD.encode(claims.to_string()),
+            token
+        );
+    }
+    state.valid_tokens.push(token.clone());
+
+    Response::json(
+        200,
+        json!({
+            "access_token": token,
+            "expires_in": state.iam_expires_in,
+            "token_type": "Bearer",
+        }),
+    )
+}
+
+fn list_backends(state: &MockState) -> Response {
+    let body = match state.backends_format {
+        BackendsFormat::Devices => {
+            json!({"devices": state.backends.iter().map(MockBackend::device_json).collect::<Vec<_>>()})
+        }
+        BackendsFormat::Legacy => {
+            json!({"backends": state.backends.iter().map(MockBackend::legacy_json).collect::<Vec<_>>()})
+        }
+        BackendsFormat::Array => Value::Array(
+            state
+                .backends
+                .iter()
+                .map(MockBackend::legacy_json)
+                .collect(),
+        ),
+    };
+    Response::json(200, body)
+}
+
+fn backend_resource(state: &MockState, name: &str, resource: &str) -> Response {
+    let Some(backend) = state.backends.iter().find(|b| b.name == name) else {
+        return Response::error(404, &format!("backend {} not found", name));
+    };
+
+    match resource {
+        "info" => Response::json(200, backend.legacy_json()),
+        "properties" => Response::json(200, backend.properties.clone()),
+        "configuration" => Response::json(200, backend.configuration_json()),
+        "status" => Response::json(200, backend.status_json()),
+        _ => Response::error(404, "not found"),
+    }
+}
+
+fn submit_job(state: &mut MockState, body: &str) -> Response {
+    let Ok(submission) = serde_json::from_str::<Value>(body) else {
+        return Response::error(400, "invalid JSON body");
+    };
+
+    let backend = submission["backend"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    match state.backends.iter().find(|b| b.name == backend) {
+        None => return Response::error(404, &format!("backend {} not found", backend)),
+        Some(b) if !b.operational => {
+            return Response::error(409, &format!("backend {} is not operational", backend))
+        }
+        Some(_) => {}
+    }
+
+    let session_id = submission["session_id"].as_str().map(String::from);
+    if let Some(id) = &session_id {
+        match state.sessions.iter().find(|s| &s.id == id) {
+            None => return Response::error(404, &format!("session {} not found", id)),
+            Some(s) if !s.accepting_jobs || s.closed_reason.is_some() => {
+                return Response::error(400, &format!("session {} is closed", id))
+            }
+            Some(_) => {}
+        }
+    }
+
+    let params = &submission["params"];
+    let shots = params["shots"].as_u64().unwrap_or(4096);
+    let mut circuits: Vec<String> = params["circuits"]
+        .as_array()
+        .map(|c| {
+            c.iter()
+                .filter_map(|q| q.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Sampler V2: [circuit, parameter_values, shots]
+    // Estimator V2: [circuit, observables, parameter_values, precision]
+    let estimator = submission["program_id"] == "estimator";
+    let mut pubs = Vec::new();
+    let mut estimates = Vec::new();
+    for pub_ in params["pubs"].as_array().into_iter().flatten() {
+        let Some(circuit) = pub_[0].as_str() else {
+            return Response::error(400, "PUB must start with a circuit");
+        };
+        circuits.push(circuit.to_string());
+        if estimator {
+            estimates.push(
+                pub_[1]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(zero_state_expectation)
+                    .collect(),
+            );
+        } else {
+            pubs.push((
+                pub_[1].as_array().map_or(0, Vec::len),
+                pub_[2].as_u64().unwrap_or(shots),
+            ));
+        }
+    }
+
+    let job = MockJob {
+        id: format!("mock_job_{:04}", state.jobs.len() + 1),
+        backend,
+        circuits,
+        shots,
+        polls_left: state.queue_polls,
+        failure: state.next_job_failure.take(),
+        cancelled: false,
+        session_id,
+        pubs,
+        estimates,
+    };
+
+    // The 2025 API returns only id and backend on submit
+    let response = json!({"id": job.id, "backend": job.backend});
+    state.jobs.push(job);
+    Response::json(200, response)
+}
+
+fn list_jobs(state: &MockState, request: &Request) -> Response {
+    let limit = request
+        .query
+        .get("limit")
+        .and_then(|l| l.parse().ok())
+        .unwrap_or(usize::MAX);
+    let jobs: Vec<Value> = state
+        .jobs
+        .iter()
+        .rev()
+        .take(limit)
+        .map(MockJob::to_json)
+        .collect();
+    Response::json(200, json!({ "jobs": jobs }))
+}
+
+fn job_status(state: &mut MockState, id: &str) -> Response {
+    let Some(job) = state.jobs.iter_mut().find(|j| j.id == id) else {
+        return Response::error(404, &format!("job {} not found", id));
+    };
+
+    let response = job.to_json();
+    job.polls_left = job.polls_left.saturating_sub(1);
+    Response::json(200, response)
+}
+
+fn job_results(state: &MockState, id: &str) -> Response {
+    let Some(job) = state.jobs.iter().find(|j| j.id == id) else {
+        return Response::error(404, &format!("job {} not found", id));
+    };
+    if job.status() != "Completed" {
+        return Response::error(409, &format!("job {} is {}", id, job.status()));
+    }
+    if !job.pubs.is_empty() {
+        return sampler_results(state, job);
+    }
+    if !job.estimates.is_empty() {
+        return estimator_results(job);
+    }
+
+    let results: Vec<Value> = job
+        .circuits
+        .iter()
+        .map(|qasm| {
+            let counts = state
+                .counts
+                .clone()
+                .unwrap_or_else(|| HashMap::from([("0".repeat(qasm_width(qasm)), job.shots)]));
+            json!({"counts": counts, "success": true, "shots": job.shots})
+        })
+        .collect();
+
+    Response::json(
+        200,
+        json!({
+            "job_id": job.id,
+            "results": results,
+            "metadata": {"num_circuits": job.circuits.len()},
+        }),
+    )
+}
+
+/// Sampler V2 results: hex samples per binding of every PUB
+fn sampler_results(state: &MockState, job: &MockJob) -> Response {
+    let results: Vec<Value> = job
+        .circuits
+        .iter()
+        .zip(&job.pubs)
+        .map(|(qasm, &(bindings, shots))| {
+            let (register, width) = qasm_register(qasm);
+            let outcomes: Vec<String> = match &state.counts {
+                Some(counts) => {
+                    let mut keys: Vec<_> = counts.iter().collect();
+                    keys.sort();
+                    keys.into_iter()
+                        .flat_map(|(bits, &n)| std::iter::repeat_n(bits, n as usize))
+                        .map(|bits| format!("0x{:x}", u128::from_str_radix(bits, 2).unwrap_or(0)))
+                        .collect()
+                }
+                None => vec!["0x0".to_string()],
+            };
+            let samples: Vec<Value> = outcomes
+                .iter()
+                .cycle()
+                .take(shots as usize)
+                .map(|s| json!(s))
+                .collect();
+            let samples = if bindings == 0 {
+                json!(samples)
+            } else {
+                json!(vec![samples; bindings])
+            };
+
+            json!({
+                "data": {register: {"samples": samples, "num_bits": width}},
+                "metadata": {"circuit_metadata": {}},
+            })
+        })
+        .collect();
+
+    Response::json(200, json!({"results": results, "metadata": {"version": 2}}))
+}
+
+/// Estimator V2 results with a fixed standard error
+fn estimator_results(job: &MockJob) -> Response {
+    let results: Vec<Value> = job
+        .estimates
+        .iter()
+        .map(|evs| {
+            json!({
+                "data": {"evs": evs, "stds": vec![0.01; evs.len()]},
+                "metadata": {"shots": job.shots, "target_precision": 0.01},
+            })
+        })
+        .collect();
+
+    Response::json(200, json!({"results": results, "metadata": {"version": 2}}))
+}
+
+/// <0…0|O|0…0> of a `{label: coeff}` observable: terms of I and Z only
+fn zero_state_expectation(observable: &Value) -> f64 {
+    observable
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(label, _)| label.chars().all(|c| matches!(c, 'I' | 'Z')))
+        .filter_map(|(_, coeff)| coeff.as_f64())
+        .sum()
+}
+
+fn cancel_job(state: &mut MockState, id: &str) -> Response {
+    let Some(job) = state.jobs.iter_mut().find(|j| j.id == id) else {
+        return Response::error(404, &format!("job {} not found", id));
+    };
+
+    job.cancelled = true;
+    Response {
+        status: 204,
+        body: String::new(),
+        retry_after: None,
+    }
+}
+
+fn open_session(state: &mut MockState, body: &str) -> Response {
+    let Ok(request) = serde_json::from_str::<Value>(body) else {
+        return Response::error(400, "invalid JSON body");
+    };
+
+    let backend = request["backend"].as_str().unwrap_or_default().to_string();
+    if !state.backends.iter().any(|b| b.name == backend) {
+        return Response::error(404, &format!("backend {} not found", backend));
+    }
+
+    let session = MockSession {
+        id: format!("mock_session_{:04}", state.sessions.len() + 1),
+        backend,
+        mode: request["mode"].as_str().unwrap_or("dedicated").to_string(),
+        max_ttl: request["max_ttl"].as_u64().unwrap_or(28_800),
+        accepting_jobs: true,
+        closed_reason: None,
+    };
+    let response = session.to_json();
+    state.sessions.push(session);
+    Response::json(200, response)
+}
+
+fn session_details(state: &MockState, id: &str) -> Response {
+    match state.sessions.iter().find(|s| s.id == id) {
+        Some(session) => Response::json(200, session.to_json()),
+        None => Response::error(404, &format!("session {} not found", id)),
+    }
+}
+
+fn update_session(state: &mut MockState, id: &str, body: &str) -> Response {
+    let Some(session) = state.sessions.iter_mut().find(|s| s.id == id) else {
+        return Response::error(404, &format!("session {} not found", id));
+    };
+
+    if let Some(accepting) = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|b| b["accepting_jobs"].as_bool())
+    {
+        session.accepting_jobs = accepting;
+    }
+    Response {
+        status: 204,
+        body: String::new(),
+        retry_after: None,
+    }
+}
+
+fn cancel_session(state: &mut MockState, id: &str) -> Response {
+    let Some(session) = state.sessions.iter_mut().find(|s| s.id == id) else {
+        return Response::error(404, &format!("session {} not found", id));
+    };
+
+    session.closed_reason = Some("cancelled".to_string());
+    for job in state
+        .jobs
+        .iter_mut()
+        .filter(|j| j.session_id.as_deref() == Some(id) && j.polls_left > 0)
+    {
+        job.cancelled = true;
+    }
+    Response {
+        status: 204,
+        body: String::new(),
+        retry_after: None,
+    }
+}
+
+/// Register width declared in OpenQASM 2 (`qreg q[n]`) or 3 (`qubit[n]`)
+fn qasm_width(qasm: &str) -> usize {
+    ["qubit[", "qreg q["]
+        .iter()
+        .find_map(|marker| {
+            let start = qasm.find(marker)? + marker.len();
+            let end = start + qasm[start..].find(']')?;
+            qasm[start..end].parse().ok()
+        })
+        .unwrap_or(1)
+}
+
+/// Name and width of the first classical register (`bit[n] name;`)
+fn qasm_register(qasm: &str) -> (String, usize) {
+    qasm.split(';')
+        .find_map(|statement| {
+            let rest = statement.trim().strip_prefix("bit[")?;
+            let (width, name) = rest.split_once(']')?;
+            Some((name.trim().to_string(), width.parse().ok()?))
+        })
+        .unwrap_or_else(|| ("c".to_string(), qasm_width(qasm)))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qasm_width() {
+        assert_eq!(qasm_width("OPENQASM 3.0;\nqubit[5] q;\nbit[5] c;"), 5);
+        assert_eq!(qasm_width("OPENQASM 2.0;\nqreg q[3];\ncreg c[3];"), 3);
+        assert_eq!(qasm_width("garbage"), 1);
+    }
+
+    #[test]
+    fn test_qasm_register() {
+        assert_eq!(
+            qasm_register("OPENQASM 3.0;\nqubit[3] q;\nbit[2] meas;"),
+            ("meas".to_string(), 2)
+        );
+        assert_eq!(
+            qasm_register("OPENQASM 2.0;\nqreg q[3];"),
+            ("c".to_string(), 3)
+        );
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let form = parse_query("grant_type=urn%3Aibm%3Aparams&apikey=abc");
+        assert_eq!(form["grant_type"], "urn:ibm:params");
+        assert_eq!(form["apikey"], "abc");
+    }
+
+    #[tokio::test]
+    async fn test_unauthorized_without_token() {
+        let mock = MockRuntime::start();
+        let response = reqwest::get(format!("{}/backends", mock.url()))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status().as_u16(), 401);
+        assert_eq!(mock.request_count("GET /api/v1/backends"), 1);
+    }
+}
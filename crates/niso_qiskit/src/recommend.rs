@@ -0,0 +1,465 @@
+//! Calibration- and queue-aware backend ranking
+//!
+//! Gantree: L10_Qiskit → Recommend
+//!
+//! Every device is scored for a specific circuit:
+//!
+//! - expected fidelity: the circuit is laid on the device's best linear
+//!   chain (`CalibrationInfo::best_linear_chain`) and scored with
+//!   `Scheduler::score_circuit` against that chain's noise and gate times
+//! - queue factor: `1 / (1 + pending_jobs / QUEUE_SCALE)`
+//! - score: fidelity × queue factor; devices that are offline, too small
+//!   or without calibration score zero and carry the reason
+//!
+//! The ranked list keeps every device with its breakdown, so the choice
+//! can be inspected rather than trusted.
+
+use crate::auth::IbmCredentials;
+use crate::backend::IbmBackendError;
+use crate::calibration::properties_to_calibration;
+use crate::client::{BackendInfo, BackendStatus, IbmClient};
+use niso_calibration::CalibrationInfo;
+use niso_core::{Circuit, QubitId};
+use niso_schedule::Scheduler;
+use std::cmp::Ordering;
+use std::fmt;
+use tokio::runtime::Runtime;
+
+/// Pending jobs at which the queue factor halves
+pub const QUEUE_SCALE: f64 = 100.0;
+
+/// Score breakdown of one backend for one circuit
+/// Gantree: BackendScore // 백엔드 점수
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendScore {
+    /// Backend name
+    pub name: String,
+
+    /// Device size
+    pub num_qubits: usize,
+
+    /// Whether the backend accepts jobs
+    pub operational: bool,
+
+    /// Jobs ahead in the queue
+    pub pending_jobs: u64,
+
+    /// Physical qubits the circuit's qubits map to, in order
+    pub chain: Option<Vec<QubitId>>,
+
+    /// `Scheduler::score_circuit` on the chain (0 when not scored)
+    pub expected_fidelity: f64,
+
+    /// Queue penalty in (0, 1]
+    pub queue_factor: f64,
+
+    /// Combined score
+    pub score: f64,
+
+    /// Why the backend cannot run the circuit, if it cannot
+    pub excluded: Option<String>,
+}
+
+impl BackendScore {
+    /// Score a backend from its status and calibration
+    /// Gantree: score(info,status,cal,circuit) -> BackendScore // 점수 계산
+    pub fn score(
+        info: &BackendInfo,
+        status: &BackendStatus,
+        calibration: Option<&CalibrationInfo>,
+        circuit: &Circuit,
+    ) -> Self {
+        let pending_jobs = status.pending_jobs.or(info.queue_length).unwrap_or(0);
+        let mut score = Self {
+            name: info.name.clone(),
+            num_qubits: info.num_qubits.unwrap_or(0),
+            operational: status.operational,
+            pending_jobs,
+            chain: None,
+            expected_fidelity: 0.0,
+            queue_factor: 1.0 / (1.0 + pending_jobs as f64 / QUEUE_SCALE),
+            score: 0.0,
+            excluded: None,
+        };
+
+        let needed = circuit.num_qubits();
+        let excluded = if !status.operational {
+            Some(
+                status
+                    .status_msg
+                    .clone()
+                    .unwrap_or_else(|| "not operational".to_string()),
+            )
+        } else if score.num_qubits < needed {
+            Some(format!("needs {} qubits, has {}", needed, score.num_qubits))
+        } else if let Some(cal) = calibration {
+            match cal.best_linear_chain(needed) {
+                Some(chain) => {
+                    score.expected_fidelity = chain_fidelity(cal, &chain, circuit);
+                    score.chain = Some(chain);
+                    None
+                }
+                None => Some(format!("no {}-qubit chain", needed)),
+            }
+        } else {
+            Some("no calibration data".to_string())
+        };
+
+        match excluded {
+            Some(reason) => score.excluded = Some(reason),
+            None => score.score = score.expected_fidelity * score.queue_factor,
+        }
+        score
+    }
+
+    /// Whether the backend can run the circuit
+    pub fn is_eligible(&self) -> bool {
+        self.excluded.is_none()
+    }
+}
+
+impl fmt::Display for BackendScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.excluded, &self.chain) {
+            (Some(reason), _) => write!(f, "{}: excluded ({})", self.name, reason),
+            (None, chain) => write!(
+                f,
+                "{}: score {:.4} (fidelity {:.4}, queue {:.3} / {} pending, chain {:?})",
+                self.name,
+                self.score,
+                self.expected_fidelity,
+                self.queue_factor,
+                self.pending_jobs,
+                chain.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+/// Expected fidelity of `circuit` with qubit `i` placed on `chain[i]`
+/// Gantree: chain_fidelity(cal,chain,circuit) -> f64 // 체인 충실도
+pub fn chain_fidelity(cal: &CalibrationInfo, chain: &[QubitId], circuit: &Circuit) -> f64 {
+    let local = relabel(cal, chain);
+    let vectors = local.to_noise_vectors();
+    Scheduler::score_circuit(circuit, vectors.vectors(), &local.to_gate_times())
+}
+
+/// Calibration of `chain` alone, with `chain[i]` renamed to `i`
+fn relabel(cal: &CalibrationInfo, chain: &[QubitId]) -> CalibrationInfo {
+    let mut local = CalibrationInfo::new(&cal.backend_name);
+    local.gate_times_1q_ns = cal.gate_times_1q_ns;
+    local.gate_times_2q_ns = cal.gate_times_2q_ns;
+
+    for (i, p) in chain.iter().enumerate() {
+        let per_qubit = [
+            (&cal.t1_times, &mut local.t1_times),
+            (&cal.t2_times, &mut local.t2_times),
+            (&cal.gate_errors_1q, &mut local.gate_errors_1q),
+            (&cal.readout_errors, &mut local.readout_errors),
+            (&cal.readout_lengths, &mut local.readout_lengths),
+        ];
+        for (from, to) in per_qubit {
+            if let Some(&value) = from.get(p) {
+                to.insert(i, value);
+            }
+        }
+        for (name, lengths) in &cal.gate_lengths_1q {
+            if let Some(&ns) = lengths.get(p) {
+                local
+                    .gate_lengths_1q
+                    .entry(name.clone())
+                    .or_default()
+                    .insert(i, ns);
+            }
+        }
+    }
+
+    for (i, pair) in chain.windows(2).enumerate() {
+        let edge = (pair[0], pair[1]);
+        let reverse = (pair[1], pair[0]);
+        local.coupling_map.push((i, i + 1));
+        if let Some(&error) = cal
+            .gate_errors_2q
+            .get(&edge)
+            .or_else(|| cal.gate_errors_2q.get(&reverse))
+        {
+            local.gate_errors_2q.insert((i, i + 1), error);
+        }
+        for (name, lengths) in &cal.gate_lengths_2q {
+            if let Some(&ns) = lengths.get(&edge).or_else(|| lengths.get(&reverse)) {
+                let local_lengths = local.gate_lengths_2q.entry(name.clone()).or_default();
+                local_lengths.insert((i, i + 1), ns);
+                local_lengths.insert((i + 1, i), ns);
+            }
+        }
+    }
+
+    local
+}
+
+/// Order scores best first: eligible before excluded, then by score,
+/// then by shorter queue
+/// Gantree: rank(scores) -> Vec<BackendScore> // 순위
+pub fn rank(mut scores: Vec<BackendScore>) -> Vec<BackendScore> {
+    scores.sort_by(|a, b| {
+        b.is_eligible()
+            .cmp(&a.is_eligible())
+            .then(b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal))
+            .then(a.pending_jobs.cmp(&b.pending_jobs))
+    });
+    scores
+}
+
+/// Rank every hardware backend for `circuit`
+/// Gantree: rank_backends(credentials,circuit) -> Vec<BackendScore> // 백엔드 순위
+///
+/// Status, properties and configuration are fetched per device. A
+/// device whose status or properties cannot be fetched is kept, excluded
+/// with the reason, and the ranking continues.
+pub fn rank_backends(
+    credentials: IbmCredentials,
+    circuit: &Circuit,
+) -> Result<Vec<BackendScore>, IbmBackendError> {
+    let runtime = Runtime::new().map_err(|e| IbmBackendError::Runtime(e.to_string()))?;
+
+    // Create client (async for API keys)
+    let client = if credentials.requires_iam_exchange() {
+        runtime.block_on(async { IbmClient::new_async(credentials).await })?
+    } else {
+        IbmClient::new(credentials)?
+    };
+
+    let scores = runtime.block_on(async {
+        let mut scores = Vec::new();
+        for info in client.list_backends().await? {
+            if info.simulator {
+                continue;
+            }
+
+            let status = match client.get_backend_status(&info.name).await {
+                Ok(status) => status,
+                Err(e) => {
+                    let status = BackendStatus {
+                        backend_name: Some(info.name.clone()),
+                        operational: false,
+                        pending_jobs: None,
+                        status_msg: Some(format!("status unavailable: {}", e)),
+                    };
+                    scores.push(BackendScore::score(&info, &status, None, circuit));
+                    continue;
+                }
+            };
+            let calibration = match client.get_backend_properties(&info.name).await {
+                Ok(props) => {
+                    let config = client.get_backend_config(&info.name).await.ok();
+                    properties_to_calibration(&info.name, &props, config.as_ref())
+                }
+                Err(e) => {
+                    let mut score = BackendScore::score(&info, &status, None, circuit);
+                    score.excluded = Some(format!("properties unavailable: {}", e));
+                    scores.push(score);
+                    continue;
+                }
+            };
+
+            scores.push(BackendScore::score(
+                &info,
+                &status,
+                Some(&calibration),
+                circuit,
+            ));
+        }
+        Ok::<_, IbmBackendError>(scores)
+    })?;
+
+    Ok(rank(scores))
+}
+
+/// Name of the best-ranked backend for `circuit`
+/// Gantree: recommend_for_circuit(credentials,circuit) -> String // 회로별 추천
+pub fn recommend_for_circuit(
+    credentials: IbmCredentials,
+    circuit: &Circuit,
+) -> Result<String, IbmBackendError> {
+    rank_backends(credentials, circuit)?
+        .into_iter()
+        .find(BackendScore::is_eligible)
+        .map(|s| s.name)
+        .ok_or_else(|| {
+            IbmBackendError::NotAvailable(format!(
+                "No backend can run a {}-qubit circuit",
+                circuit.num_qubits()
+            ))
+        })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockBackend, MockFailure, MockRuntime};
+    use niso_core::CircuitBuilder;
+
+    fn ghz(n: usize) -> Circuit {
+        let mut builder = CircuitBuilder::new(n).h(0);
+        for q in 1..n {
+            builder = builder.cnot(q - 1, q);
+        }
+        builder.measure_all().build()
+    }
+
+    fn info(name: &str, num_qubits: usize) -> BackendInfo {
+        serde_json::from_value(serde_json::json!({"name": name, "n_qubits": num_qubits})).unwrap()
+    }
+
+    fn status(operational: bool, pending: u64) -> BackendStatus {
+        BackendStatus {
+            backend_name: None,
+            operational,
+            pending_jobs: Some(pending),
+            status_msg: None,
+        }
+    }
+
+    /// Line of `n` qubits, with one bad edge between 1 and 2
+    fn line_calibration(n: usize) -> CalibrationInfo {
+        let mut cal = CalibrationInfo::new("ibm_line");
+        for q in 0..n {
+            cal.t1_times.insert(q, 200.0);
+            cal.t2_times.insert(q, 150.0);
+            cal.gate_errors_1q.insert(q, 0.0003);
+            cal.readout_errors.insert(q, 0.01);
+        }
+        for q in 1..n {
+            let error = if q == 2 { 0.2 } else { 0.005 };
+            cal.gate_errors_2q.insert((q - 1, q), error);
+            cal.coupling_map.push((q - 1, q));
+        }
+        cal
+    }
+
+    #[test]
+    fn test_chain_fidelity_uses_chain_calibration() {
+        let cal = line_calibration(5);
+        let circuit = ghz(2);
+
+        let good = chain_fidelity(&cal, &[3, 4], &circuit);
+        let bad = chain_fidelity(&cal, &[1, 2], &circuit);
+        assert!(good > 0.9 && good < 1.0);
+        assert!(good > bad);
+
+        // Edge direction does not matter
+        assert_eq!(chain_fidelity(&cal, &[4, 3], &circuit), good);
+    }
+
+    #[test]
+    fn test_score_breakdown() {
+        let cal = line_calibration(5);
+        let circuit = ghz(3);
+
+        let idle = BackendScore::score(&info("a", 5), &status(true, 0), Some(&cal), &circuit);
+        assert!(idle.is_eligible());
+        assert_eq!(idle.chain.as_ref().unwrap().len(), 3);
+        assert_eq!(idle.queue_factor, 1.0);
+        assert_eq!(idle.score, idle.expected_fidelity);
+
+        let busy = BackendScore::score(&info("b", 5), &status(true, 100), Some(&cal), &circuit);
+        assert_eq!(busy.queue_factor, 0.5);
+        assert_eq!(busy.score, busy.expected_fidelity / 2.0);
+
+        let offline = BackendScore::score(&info("c", 5), &status(false, 0), Some(&cal), &circuit);
+        assert_eq!(offline.excluded.as_deref(), Some("not operational"));
+        assert_eq!(offline.score, 0.0);
+
+        let small = BackendScore::score(&info("d", 2), &status(true, 0), Some(&cal), &circuit);
+        assert_eq!(small.excluded.as_deref(), Some("needs 3 qubits, has 2"));
+
+        let unknown = BackendScore::score(&info("e", 5), &status(true, 0), None, &circuit);
+        assert!(!unknown.is_eligible());
+        assert!(unknown
+            .to_string()
+            .contains("excluded (no calibration data)"));
+
+        let ranked = rank(vec![offline, busy, unknown, idle]);
+        let names: Vec<_> = ranked.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names[..2], ["a", "b"]);
+        assert!(ranked[0].to_string().starts_with("a: score"));
+    }
+
+    #[test]
+    fn test_rank_backends_against_mock() {
+        let mock = MockRuntime::with_backends(vec![
+            MockBackend::new("ibm_noisy", 5).with_gate_error_2q(0.1),
+            MockBackend::new("ibm_quiet", 5).with_pending_jobs(5),
+            MockBackend::new("ibm_down", 5).with_operational(false),
+            MockBackend::new("ibm_tiny", 2),
+            MockBackend::new("simulator_mock", 32).simulator(),
+        ]);
+        let circuit = ghz(3);
+
+        let ranked = rank_backends(mock.credentials(), &circuit).unwrap();
+        let names: Vec<_> = ranked.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names[..2], ["ibm_quiet", "ibm_noisy"]);
+        assert_eq!(ranked.len(), 4);
+        assert!(ranked[1].expected_fidelity < ranked[0].expected_fidelity);
+        assert_eq!(ranked[0].pending_jobs, 5);
+        assert!(ranked[2..].iter().all(|s| !s.is_eligible()));
+
+        assert_eq!(
+            recommend_for_circuit(mock.credentials(), &circuit).unwrap(),
+            "ibm_quiet"
+        );
+        assert!(matches!(
+            recommend_for_circuit(mock.credentials(), &ghz(6)),
+            Err(IbmBackendError::NotAvailable(_))
+        ));
+    }
+
+    #[test]
+    fn test_rank_backends_survives_status_error() {
+        let mock = MockRuntime::with_backends(vec![
+            MockBackend::new("ibm_broken", 5),
+            MockBackend::new("ibm_quiet", 5),
+        ]);
+        mock.push_failure(MockFailure::new("/backends/ibm_broken/status", 404));
+
+        let ranked = rank_backends(mock.credentials(), &ghz(3)).unwrap();
+
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].name, "ibm_quiet");
+        assert!(ranked[0].is_eligible());
+        assert_eq!(ranked[1].name, "ibm_broken");
+        assert!(ranked[1]
+            .excluded
+            .as_deref()
+            .unwrap()
+            .starts_with("status unavailable"));
+    }
+
+    #[test]
+    fn test_rank_backends_survives_properties_error() {
+        let mock = MockRuntime::with_backends(vec![
+            MockBackend::new("ibm_uncalibrated", 5),
+            MockBackend::new("ibm_quiet", 5),
+        ]);
+        mock.push_failure(MockFailure::new(
+            "/backends/ibm_uncalibrated/properties",
+            500,
+        ));
+
+        let ranked = rank_backends(mock.credentials(), &ghz(3)).unwrap();
+
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].name, "ibm_quiet");
+        assert!(ranked[0].is_eligible());
+        assert_eq!(ranked[1].name, "ibm_uncalibrated");
+        assert!(ranked[1]
+            .excluded
+            .as_deref()
+            .unwrap()
+            .starts_with("properties unavailable"));
+    }
+}
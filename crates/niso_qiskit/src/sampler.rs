@@ -0,0 +1,591 @@
+//! Sampler V2 primitive: PUBs and BitArray results
+//!
+//! Gantree: L10_Qiskit → Sampler
+//!
+//! A PUB (primitive unified bloc) is one circuit with an array of parameter
+//! bindings and a shot count, so a whole δ sweep is a single PUB. Results
+//! come back per PUB and per classical register as packed samples
+//! (`BitArray`), which decode into `Counts` and per-shot memory.
+//!
+//! ```rust,ignore
+//! let pub_ = SamplerPub::sweep(PARAMETRIC_QASM, &[-0.1, 0.0, 0.1]).with_shots(1024);
+//! let mut job = manager.submit_pubs("ibm_brisbane", vec![pub_]).await?;
+//! job.wait(timeout).await?;
+//! let result = job.sampler_result().await?;
+//! let counts = result.pubs[0].register("c").unwrap().counts(1);
+//! ```
+
+use niso_core::Counts;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Sampler V2 errors
+#[derive(Debug, Error)]
+pub enum SamplerError {
+    /// PUB is inconsistent (binding widths, parameters)
+    #[error("Invalid PUB: {0}")]
+    InvalidPub(String),
+
+    /// Result payload could not be decoded
+    #[error("Invalid sampler result: {0}")]
+    Decode(String),
+}
+
+/// Sampler primitive version sent with PUB submissions
+pub const SAMPLER_VERSION: u32 = 2;
+
+// ============================================================================
+// PUB
+// ============================================================================
+
+/// Sampler PUB: circuit, parameter bindings and shots
+/// Gantree: SamplerPub // 샘플러 PUB
+///
+/// Serialized as `[circuit, parameter_values, shots]`. Each binding row
+/// holds one value per `input` parameter of the circuit, in declaration
+/// order; a circuit without parameters has no rows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SamplerPub {
+    /// Parametric OpenQASM 3 circuit
+    pub circuit: String,
+
+    /// Binding rows, one value per circuit parameter
+    pub parameter_values: Vec<Vec<f64>>,
+
+    /// Shots (job default if `None`)
+    pub shots: Option<u64>,
+}
+
+impl SamplerPub {
+    /// Create a PUB without bindings
+    pub fn new(circuit: impl Into<String>) -> Self {
+        Self {
+            circuit: circuit.into(),
+            parameter_values: Vec::new(),
+            shots: None,
+        }
+    }
+
+    /// One binding per value of a single-parameter circuit
+    /// Gantree: sweep(circuit,values) -> SamplerPub // δ 스윕
+    pub fn sweep(circuit: impl Into<String>, values: &[f64]) -> Self {
+        Self::new(circuit).with_bindings(values.iter().map(|&v| vec![v]).collect())
+    }
+
+    /// Set all binding rows
+    pub fn with_bindings(mut self, bindings: Vec<Vec<f64>>) -> Self {
+        self.parameter_values = bindings;
+        self
+    }
+
+    /// Append one binding row
+    pub fn bind(mut self, values: Vec<f64>) -> Self {
+        self.parameter_values.push(values);
+        self
+    }
+
+    /// Set shots
+    pub fn with_shots(mut self, shots: u64) -> Self {
+        self.shots = Some(shots);
+        self
+    }
+
+    /// Parameter names declared as `input` in the circuit, in order
+    pub fn parameters(&self) -> Vec<String> {
+        qasm_inputs(&self.circuit)
+    }
+
+    /// Number of circuit executions (1 for an unparameterized circuit)
+    pub fn num_bindings(&self) -> usize {
+        self.parameter_values.len().max(1)
+    }
+
+    /// Check bindings against the declared parameters
+    pub fn validate(&self) -> Result<(), SamplerError> {
+        let num_params = self.parameters().len();
+
+        if num_params > 0 && self.parameter_values.is_empty() {
+            return Err(SamplerError::InvalidPub(format!(
+                "circuit declares {} parameters but has no bindings",
+                num_params
+            )));
+        }
+        if let Some((i, row)) = self
+            .parameter_values
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != num_params)
+        {
+            return Err(SamplerError::InvalidPub(format!(
+                "binding {} has {} values, circuit declares {} parameters",
+                i,
+                row.len(),
+                num_params
+            )));
+        }
+        if self.shots == Some(0) {
+            return Err(SamplerError::InvalidPub(
+                "shots must be positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for SamplerPub {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.shots {
+            Some(shots) => (&self.circuit, &self.parameter_values, shots).serialize(serializer),
+            None => (&self.circuit, &self.parameter_values).serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SamplerPub {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let items = Vec::<Value>::deserialize(deserializer)?;
+        let mut items = items.into_iter();
+
+        let circuit = match items.next() {
+            Some(Value::String(circuit)) => circuit,
+            _ => return Err(D::Error::custom("PUB must start with a circuit string")),
+        };
+        let parameter_values = match items.next() {
+            None | Some(Value::Null) => Vec::new(),
+            Some(values) => serde_json::from_value(values).map_err(D::Error::custom)?,
+        };
+        let shots = match items.next() {
+            None | Some(Value::Null) => None,
+            Some(shots) => Some(serde_json::from_value(shots).map_err(D::Error::custom)?),
+        };
+
+        Ok(Self {
+            circuit,
+            parameter_values,
+            shots,
+        })
+    }
+}
+
+/// Names of `input float[..] name;` / `input angle name;` declarations
+fn qasm_inputs(qasm: &str) -> Vec<String> {
+    qasm.split(';')
+        .filter_map(|statement| {
+            let rest = statement.trim().strip_prefix("input")?;
+            if !rest.starts_with(char::is_whitespace) {
+                return None;
+            }
+            let name = rest.split_whitespace().last()?;
+            Some(name.to_string())
+        })
+        .collect()
+}
+
+// ============================================================================
+// BitArray
+// ============================================================================
+
+/// Measured samples of one classical register
+/// Gantree: BitArray // 패킹된 샘플
+///
+/// Each shot is stored as `ceil(num_bits / 8)` big-endian bytes; `shape`
+/// is the shape of the PUB's bindings (empty for a single execution).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitArray {
+    num_bits: usize,
+    shape: Vec<usize>,
+    num_shots: usize,
+    bytes: Vec<u8>,
+}
+
+impl BitArray {
+    /// Decode `{"samples": [...hex...], "num_bits": n}` or the packed
+    /// `{"array": [...[byte, ...]...], "num_bits": n}` form
+    /// Gantree: from_json(value) -> BitArray // 샘플 디코딩
+    pub fn from_json(value: &Value) -> Result<Self, SamplerError> {
+        let num_bits = value["num_bits"]
+            .as_u64()
+            .ok_or_else(|| SamplerError::Decode("missing num_bits".to_string()))?
+            as usize;
+
+        let mut array = Self {
+            num_bits,
+            shape: Vec::new(),
+            num_shots: 0,
+            bytes: Vec::new(),
+        };
+
+        if let Some(samples) = value.get("samples") {
+            let dims = array.collect(samples, 0, &mut |array, leaf| match leaf {
+                Value::String(hex) => array.push_hex(hex),
+                other => Err(SamplerError::Decode(format!(
+                    "expected hex sample, got {}",
+                    other
+                ))),
+            })?;
+            array.set_dims(&dims)?;
+        } else if let Some(packed) = value.get("array") {
+            let dims = array.collect(packed, 1, &mut |array, leaf| array.push_packed(leaf))?;
+            array.set_dims(&dims)?;
+        } else {
+            return Err(SamplerError::Decode(
+                "expected `samples` or `array`".to_string(),
+            ));
+        }
+
+        Ok(array)
+    }
+
+    /// Number of bits per shot
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// Shape of the bindings
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Number of bindings (product of the shape)
+    pub fn size(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Shots per binding
+    pub fn num_shots(&self) -> usize {
+        self.num_shots
+    }
+
+    /// Per-shot bitstrings of binding `index` (flattened, row-major)
+    pub fn bitstrings(&self, index: usize) -> Vec<String> {
+        let stride = self.num_bytes();
+        let start = index * self.num_shots;
+        (start..start + self.num_shots)
+            .filter_map(|shot| self.bytes.get(shot * stride..(shot + 1) * stride))
+            .map(|bytes| self.bitstring(bytes))
+            .collect()
+    }
+
+    /// Counts of binding `index`
+    pub fn counts(&self, index: usize) -> Counts {
+        let mut counts = Counts::new();
+        for bitstring in self.bitstrings(index) {
+            *counts.entry(bitstring).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Counts of every binding, in flattened order
+    pub fn all_counts(&self) -> Vec<Counts> {
+        (0..self.size()).map(|i| self.counts(i)).collect()
+    }
+
+    fn num_bytes(&self) -> usize {
+        self.num_bits.div_ceil(8).max(1)
+    }
+
+    /// Bitstring of one shot, clbit 0 rightmost
+    fn bitstring(&self, bytes: &[u8]) -> String {
+        let all: String = bytes.iter().map(|b| format!("{:08b}", b)).collect();
+        all[all.len() - self.num_bits..].to_string()
+    }
+
+    /// Walk nested arrays; leaves are `leaf_depth` levels above the bottom
+    fn collect(
+        &mut self,
+        value: &Value,
+        leaf_depth: usize,
+        push: &mut dyn FnMut(&mut Self, &Value) -> Result<(), SamplerError>,
+    ) -> Result<Vec<usize>, SamplerError> {
+        if nesting(value) == leaf_depth {
+            push(self, value)?;
+            return Ok(Vec::new());
+        }
+
+        let items = value
+            .as_array()
+            .ok_or_else(|| SamplerError::Decode(format!("unexpected sample {}", value)))?;
+        let mut inner: Option<Vec<usize>> = None;
+        for item in items {
+            let dims = self.collect(item, leaf_depth, push)?;
+            if inner.as_ref().is_some_and(|d| *d != dims) {
+                return Err(SamplerError::Decode("ragged sample array".to_string()));
+            }
+            inner = Some(dims);
+        }
+
+        let mut dims = vec![items.len()];
+        dims.extend(inner.unwrap_or_default());
+        Ok(dims)
+    }
+
+    /// Split collected dims into binding shape and shots
+    fn set_dims(&mut self, dims: &[usize]) -> Result<(), SamplerError> {
+        let (shots, shape) = dims
+            .split_last()
+            .ok_or_else(|| SamplerError::Decode("samples must be an array".to_string()))?;
+        self.num_shots = *shots;
+        self.shape = shape.to_vec();
+        Ok(())
+    }
+
+    fn push_hex(&mut self, hex: &str) -> Result<(), SamplerError> {
+        let digits = hex.trim_start_matches("0x").trim_start_matches("0X");
+        let value = digits
+            .chars()
+            .rev()
+            .map(|c| c.to_digit(16).map(|d| d as u8))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| SamplerError::Decode(format!("invalid hex sample {}", hex)))?;
+
+        // Nibbles are least significant first; pack into big-endian bytes
+        let num_bytes = self.num_bytes();
+        let mut bytes = vec![0u8; num_bytes];
+        for (i, nibble) in value.iter().take(2 * num_bytes).enumerate() {
+            bytes[num_bytes - 1 - i / 2] |= nibble << (4 * (i % 2));
+        }
+
+        let spare_bits = 8 * num_bytes - self.num_bits;
+        if value.iter().skip(2 * num_bytes).any(|&d| d != 0)
+            || (spare_bits > 0 && bytes[0] >> (8 - spare_bits) != 0)
+        {
+            return Err(SamplerError::Decode(format!(
+                "sample {} exceeds {} bits",
+                hex, self.num_bits
+            )));
+        }
+        self.bytes.extend(bytes);
+        Ok(())
+    }
+
+    fn push_packed(&mut self, row: &Value) -> Result<(), SamplerError> {
+        let bytes = row
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|b| b.as_u64().filter(|&b| b <= 255).map(|b| b as u8))
+                    .collect::<Option<Vec<u8>>>()
+            })
+            .unwrap_or_default()
+            .ok_or_else(|| SamplerError::Decode(format!("invalid packed row {}", row)))?;
+
+        if bytes.len() != self.num_bytes() {
+            return Err(SamplerError::Decode(format!(
+                "packed row has {} bytes, expected {}",
+                bytes.len(),
+                self.num_bytes()
+            )));
+        }
+        self.bytes.extend(bytes);
+        Ok(())
+    }
+}
+
+/// Depth of array nesting down to the first non-array element
+fn nesting(value: &Value) -> usize {
+    match value {
+        Value::Array(items) => 1 + items.first().map_or(0, nesting),
+        _ => 0,
+    }
+}
+
+// ============================================================================
+// Results
+// ============================================================================
+
+/// Result of one PUB
+/// Gantree: PubResult // PUB 결과
+#[derive(Debug, Clone)]
+pub struct PubResult {
+    /// Samples per classical register
+    pub data: HashMap<String, BitArray>,
+
+    /// Execution metadata
+    pub metadata: Value,
+}
+
+impl PubResult {
+    /// Samples of a register
+    pub fn register(&self, name: &str) -> Option<&BitArray> {
+        self.data.get(name)
+    }
+
+    /// Counts of binding `index` for a circuit with one register
+    pub fn counts(&self, index: usize) -> Result<Counts, SamplerError> {
+        match self.data.values().collect::<Vec<_>>().as_slice() {
+            [array] => Ok(array.counts(index)),
+            _ => Err(SamplerError::Decode(format!(
+                "expected one classical register, found {}",
+                self.data.len()
+            ))),
+        }
+    }
+}
+
+/// Sampler V2 job result (`GET /jobs/{id}/results`)
+/// Gantree: SamplerResult // 샘플러 결과
+#[derive(Debug, Clone)]
+pub struct SamplerResult {
+    /// Results in PUB order
+    pub pubs: Vec<PubResult>,
+
+    /// Job metadata
+    pub metadata: Value,
+}
+
+impl SamplerResult {
+    /// Decode a result payload
+    pub fn from_json(value: &Value) -> Result<Self, SamplerError> {
+        let results = value["results"]
+            .as_array()
+            .ok_or_else(|| SamplerError::Decode("missing results".to_string()))?;
+
+        let pubs = results
+            .iter()
+            .map(|result| {
+                let data = result["data"]
+                    .as_object()
+                    .ok_or_else(|| SamplerError::Decode("missing PUB data".to_string()))?
+                    .iter()
+                    .map(|(name, array)| Ok((name.clone(), BitArray::from_json(array)?)))
+                    .collect::<Result<_, SamplerError>>()?;
+                Ok(PubResult {
+                    data,
+                    metadata: result["metadata"].clone(),
+                })
+            })
+            .collect::<Result<_, SamplerError>>()?;
+
+        Ok(Self {
+            pubs,
+            metadata: value["metadata"].clone(),
+        })
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMETRIC: &str = "OPENQASM 3.0;\ninclude \"stdgates.inc\";\ninput float[64] delta;\nqubit[2] q;\nbit[2] c;\nrz(delta) q[0];\nc = measure q;";
+
+    fn fixture(name: &str) -> Value {
+        let text = match name {
+            "sweep" => include_str!("../fixtures/sampler_v2_sweep.json"),
+            "packed" => include_str!("../fixtures/sampler_v2_packed.json"),
+            _ => unreachable!(),
+        };
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_pub_serialization() {
+        let sweep = SamplerPub::sweep(PARAMETRIC, &[-0.1, 0.0, 0.1]).with_shots(1024);
+        assert_eq!(sweep.parameters(), ["delta"]);
+        assert_eq!(sweep.num_bindings(), 3);
+        assert!(sweep.validate().is_ok());
+
+        let json = serde_json::to_value(&sweep).unwrap();
+        assert_eq!(json[1], serde_json::json!([[-0.1], [0.0], [0.1]]));
+        assert_eq!(json[2], 1024);
+
+        let back: SamplerPub = serde_json::from_value(json).unwrap();
+        assert_eq!(back, sweep);
+
+        let plain: SamplerPub = serde_json::from_str(r#"["OPENQASM 3.0;"]"#).unwrap();
+        assert_eq!(plain.num_bindings(), 1);
+        assert_eq!(plain.shots, None);
+    }
+
+    #[test]
+    fn test_pub_validation() {
+        assert!(SamplerPub::new(PARAMETRIC).validate().is_err());
+        assert!(SamplerPub::new(PARAMETRIC)
+            .bind(vec![0.1, 0.2])
+            .validate()
+            .is_err());
+        assert!(SamplerPub::new("OPENQASM 3.0;\nqubit[1] q;")
+            .with_shots(0)
+            .validate()
+            .is_err());
+
+        let two = "OPENQASM 3.0;\ninput float[64] a;\ninput angle b;\nqubit[1] q;";
+        let pub_ = SamplerPub::new(two).bind(vec![0.1, 0.2]);
+        assert_eq!(pub_.parameters(), ["a", "b"]);
+        assert!(pub_.validate().is_ok());
+    }
+
+    #[test]
+    fn test_decode_hex_sweep_fixture() {
+        let result = SamplerResult::from_json(&fixture("sweep")).unwrap();
+        assert_eq!(result.pubs.len(), 1);
+
+        let c = result.pubs[0].register("c").unwrap();
+        assert_eq!(c.num_bits(), 2);
+        assert_eq!(c.shape(), [3]);
+        assert_eq!(c.num_shots(), 8);
+
+        assert_eq!(c.counts(0), Counts::from([("00".to_string(), 8)]));
+        let mid = c.counts(1);
+        assert_eq!(mid["00"] + mid["11"], 8);
+        assert_eq!(mid["11"], 4);
+        assert_eq!(c.bitstrings(2)[0], "11");
+        assert_eq!(
+            c.all_counts()
+                .iter()
+                .map(|counts| counts.values().sum::<u64>())
+                .collect::<Vec<_>>(),
+            [8, 8, 8]
+        );
+        assert_eq!(result.pubs[0].counts(1).unwrap(), mid);
+        assert_eq!(result.metadata["version"], 2);
+    }
+
+    #[test]
+    fn test_decode_packed_fixture() {
+        let result = SamplerResult::from_json(&fixture("packed")).unwrap();
+        let pub_ = &result.pubs[0];
+        assert!(pub_.counts(0).is_err());
+
+        // 10-bit register spans two bytes per shot
+        let meas = pub_.register("meas").unwrap();
+        assert_eq!(meas.num_bits(), 10);
+        assert!(meas.shape().is_empty());
+        assert_eq!(meas.size(), 1);
+        assert_eq!(
+            meas.bitstrings(0),
+            ["0000000000", "1000000001", "1111111111", "0000000101"]
+        );
+
+        let flag = pub_.register("flag").unwrap();
+        assert_eq!(
+            flag.counts(0),
+            Counts::from([("1".to_string(), 3), ("0".to_string(), 1)])
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let decode = |v: Value| BitArray::from_json(&v);
+        assert!(decode(serde_json::json!({"samples": ["0x1"]})).is_err());
+        assert!(decode(serde_json::json!({"samples": ["0x4"], "num_bits": 2})).is_err());
+        assert!(decode(serde_json::json!({"samples": ["0xg"], "num_bits": 2})).is_err());
+        assert!(
+            decode(serde_json::json!({"samples": [["0x1"], ["0x1", "0x0"]], "num_bits": 1}))
+                .is_err()
+        );
+        assert!(decode(serde_json::json!({"array": [[1, 2]], "num_bits": 2})).is_err());
+
+        // Hex wider than the register is fine while the extra digits are zero
+        let padded = decode(serde_json::json!({"samples": ["0x0003"], "num_bits": 2})).unwrap();
+        assert_eq!(padded.bitstrings(0), ["11"]);
+    }
+}
@@ -0,0 +1,464 @@
+//! Qiskit Runtime sessions and batches
+//!
+//! Gantree: L10_Qiskit → Session
+//!
+//! Jobs attached to an open session (`session_id`) skip the fair-share
+//! queue between steps, so an iterative optimization keeps device
+//! priority. A batch groups independent jobs the same way without
+//! reserving the device between them.
+//!
+//! ```rust,ignore
+//! let session = IbmSession::open(&manager, "ibm_brisbane", SessionMode::Dedicated,
+//!     DEFAULT_MAX_TTL).await?;
+//! let job = session.submit(submission).await?;
+//! session.close().await?;
+//! ```
+
+use crate::client::ClientError;
+use crate::job::{IbmJob, JobError, JobManager, JobSubmission};
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+use std::time::{Duration, Instant};
+
+/// Default session max TTL (8 hours, the Runtime default)
+pub const DEFAULT_MAX_TTL: Duration = Duration::from_secs(8 * 3600);
+
+/// Execution mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionMode {
+    /// Device reserved between jobs (iterative workloads)
+    Dedicated,
+
+    /// Independent jobs scheduled together
+    Batch,
+}
+
+/// Session state reported by the API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionState {
+    /// Open, no job running yet
+    Open,
+
+    /// A job of the session is running
+    Active,
+
+    /// Waiting for the next job (interactive TTL running)
+    Inactive,
+
+    /// Closed or expired
+    Closed,
+
+    /// Unknown state
+    #[serde(other)]
+    Unknown,
+}
+
+/// Session details (`GET /sessions/{id}`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionDetails {
+    /// Session ID
+    pub id: String,
+
+    /// Backend name
+    #[serde(default, alias = "backend")]
+    pub backend_name: Option<String>,
+
+    /// Execution mode
+    #[serde(default)]
+    pub mode: Option<SessionMode>,
+
+    /// Current state
+    #[serde(default)]
+    pub state: Option<SessionState>,
+
+    /// Whether new jobs are accepted
+    #[serde(default)]
+    pub accepting_jobs: Option<bool>,
+
+    /// Max TTL in seconds
+    #[serde(default, alias = "max_ttl")]
+    pub max_time: Option<u64>,
+
+    /// Why the session was closed
+    #[serde(default)]
+    pub closed_reason: Option<String>,
+}
+
+impl SessionDetails {
+    /// Check if the session no longer takes jobs
+    pub fn is_closed(&self) -> bool {
+        self.state == Some(SessionState::Closed) || self.accepting_jobs == Some(false)
+    }
+}
+
+/// Session creation request
+#[derive(Debug, Serialize)]
+struct SessionRequest<'a> {
+    backend: &'a str,
+    mode: SessionMode,
+    max_ttl: u64,
+}
+
+// ============================================================================
+// Session
+// ============================================================================
+
+/// Open Runtime session
+/// Gantree: IbmSession // 런타임 세션
+///
+/// Clones share the same server-side session.
+#[derive(Clone)]
+pub struct IbmSession {
+    id: String,
+    backend: String,
+    mode: SessionMode,
+    max_ttl: Duration,
+    opened_at: Instant,
+    manager: JobManager,
+}
+
+impl IbmSession {
+    /// Open a session on `backend`
+    /// Gantree: open(manager,backend,mode,max_ttl) -> IbmSession // 세션 열기
+    pub async fn open(
+        manager: &JobManager,
+        backend: impl Into<String>,
+        mode: SessionMode,
+        max_ttl: Duration,
+    ) -> Result<Self, JobError> {
+        let backend = backend.into();
+        let request = SessionRequest {
+            backend: &backend,
+            mode,
+            max_ttl: max_ttl.as_secs(),
+        };
+        let details: SessionDetails = manager.client().post("/sessions", &request).await?;
+        log::info!("Opened {:?} session {} on {}", mode, details.id, backend);
+
+        Ok(Self {
+            id: details.id,
+            backend,
+            mode,
+            max_ttl,
+            opened_at: Instant::now(),
+            manager: manager.clone(),
+        })
+    }
+
+    /// Open a new session with the same backend, mode and max TTL
+    pub async fn reopen(&self) -> Result<Self, JobError> {
+        Self::open(&self.manager, self.backend.clone(), self.mode, self.max_ttl).await
+    }
+
+    /// Session ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Backend name
+    pub fn backend(&self) -> &str {
+        &self.backend
+    }
+
+    /// Execution mode
+    pub fn mode(&self) -> SessionMode {
+        self.mode
+    }
+
+    /// Max TTL requested at open
+    pub fn max_ttl(&self) -> Duration {
+        self.max_ttl
+    }
+
+    /// Time left before the max TTL closes the session
+    pub fn remaining_ttl(&self) -> Duration {
+        self.max_ttl.saturating_sub(self.opened_at.elapsed())
+    }
+
+    /// Check if the max TTL has passed
+    pub fn is_expired(&self) -> bool {
+        self.remaining_ttl().is_zero()
+    }
+
+    /// Fetch the session details
+    pub async fn details(&self) -> Result<SessionDetails, JobError> {
+        Ok(self
+            .manager
+            .client()
+            .get(&format!("/sessions/{}", self.id))
+            .await?)
+    }
+
+    /// Submit a job into the session
+    /// Gantree: submit(submission) -> IbmJob // 세션 내 제출
+    ///
+    /// Fails with `JobError::SessionClosed` once the max TTL has passed or
+    /// the server closed the session.
+    pub async fn submit(&self, mut submission: JobSubmission) -> Result<IbmJob, JobError> {
+        if self.is_expired() {
+            return Err(JobError::SessionClosed(self.id.clone()));
+        }
+
+        submission.backend = self.backend.clone();
+        submission.session_id = Some(self.id.clone());
+
+        match self.manager.submit(submission).await {
+            Err(JobError::Client(ClientError::ApiError { code, message })) => {
+                // Tell a closed session apart from other rejections
+                match self.details().await {
+                    Ok(details) if details.is_closed() => {
+                        Err(JobError::SessionClosed(self.id.clone()))
+                    }
+                    _ => Err(JobError::Client(ClientError::ApiError { code, message })),
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Stop accepting jobs; queued jobs still run
+    pub async fn close(self) -> Result<(), JobError> {
+        let body = serde_json::json!({"accepting_jobs": false});
+        self.manager
+            .client()
+            .patch(&format!("/sessions/{}", self.id), &body)
+            .await?;
+        log::info!("Closed session {}", self.id);
+        Ok(())
+    }
+
+    /// Close the session and cancel its queued jobs
+    pub async fn cancel(self) -> Result<(), JobError> {
+        self.manager
+            .client()
+            .delete(&format!("/sessions/{}/close", self.id))
+            .await?;
+        log::info!("Cancelled session {}", self.id);
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for IbmSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IbmSession")
+            .field("id", &self.id)
+            .field("backend", &self.backend)
+            .field("mode", &self.mode)
+            .field("max_ttl", &self.max_ttl)
+            .finish()
+    }
+}
+
+// ============================================================================
+// Batch
+// ============================================================================
+
+/// Runtime batch
+/// Gantree: IbmBatch // 런타임 배치
+///
+/// A session in `SessionMode::Batch`; dereferences to `IbmSession`.
+#[derive(Debug, Clone)]
+pub struct IbmBatch {
+    session: IbmSession,
+}
+
+impl IbmBatch {
+    /// Open a batch on `backend`
+    pub async fn open(
+        manager: &JobManager,
+        backend: impl Into<String>,
+        max_ttl: Duration,
+    ) -> Result<Self, JobError> {
+        Ok(Self {
+            session: IbmSession::open(manager, backend, SessionMode::Batch, max_ttl).await?,
+        })
+    }
+
+    /// Submit all jobs before any is awaited
+    /// Gantree: submit_all(submissions) -> Vec<IbmJob> // 일괄 제출
+    pub async fn submit_all(
+        &self,
+        submissions: Vec<JobSubmission>,
+    ) -> Result<Vec<IbmJob>, JobError> {
+        let mut jobs = Vec::with_capacity(submissions.len());
+        for submission in submissions {
+            jobs.push(self.session.submit(submission).await?);
+        }
+        Ok(jobs)
+    }
+
+    /// Stop accepting jobs; submitted jobs still run
+    pub async fn close(self) -> Result<(), JobError> {
+        self.session.close().await
+    }
+
+    /// Get the underlying session
+    pub fn into_session(self) -> IbmSession {
+        self.session
+    }
+}
+
+impl Deref for IbmBatch {
+    type Target = IbmSession;
+
+    fn deref(&self) -> &IbmSession {
+        &self.session
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::IbmClient;
+    use crate::job::{JobParams, JobStatus};
+    use crate::mock::MockRuntime;
+
+    const QASM: &str = "OPENQASM 3.0;\nqubit[1] q;\nbit[1] c;\nc = measure q;";
+
+    fn manager(mock: &MockRuntime) -> JobManager {
+        JobManager::new(IbmClient::new(mock.credentials()).unwrap())
+            .with_poll_interval(Duration::from_millis(5))
+    }
+
+    fn submission() -> JobSubmission {
+        JobSubmission {
+            program_id: "sampler".to_string(),
+            backend: String::new(),
+            params: JobParams::new(vec![QASM.to_string()]).with_shots(100),
+            tags: Vec::new(),
+            session_id: None,
+        }
+    }
+
+    #[test]
+    fn test_session_details_deserialize() {
+        let json = r#"{
+            "id": "cn0abc",
+            "backend_name": "ibm_brisbane",
+            "mode": "dedicated",
+            "state": "inactive",
+            "accepting_jobs": true,
+            "max_time": 28800
+        }"#;
+
+        let details: SessionDetails = serde_json::from_str(json).unwrap();
+        assert_eq!(details.mode, Some(SessionMode::Dedicated));
+        assert_eq!(details.state, Some(SessionState::Inactive));
+        assert!(!details.is_closed());
+
+        let closed: SessionDetails =
+            serde_json::from_str(r#"{"id": "x", "state": "closed"}"#).unwrap();
+        assert!(closed.is_closed());
+    }
+
+    #[tokio::test]
+    async fn test_session_jobs_carry_session_id() {
+        let mock = MockRuntime::start();
+        let manager = manager(&mock);
+
+        let session = IbmSession::open(
+            &manager,
+            "ibm_mock",
+            SessionMode::Dedicated,
+            DEFAULT_MAX_TTL,
+        )
+        .await
+        .unwrap();
+        let mut job = session.submit(submission()).await.unwrap();
+        assert_eq!(job.backend(), "ibm_mock");
+        assert_eq!(mock.job_session(job.id()).as_deref(), Some(session.id()));
+        assert_eq!(
+            job.wait(Duration::from_secs(5)).await.unwrap(),
+            JobStatus::Completed
+        );
+
+        // Jobs outside the session are unaffected
+        let outside = manager
+            .submit(JobSubmission {
+                backend: "ibm_mock".to_string(),
+                ..submission()
+            })
+            .await
+            .unwrap();
+        assert_eq!(mock.job_session(outside.id()), None);
+
+        let id = session.id().to_string();
+        session.close().await.unwrap();
+        assert_eq!(
+            mock.request_count(&format!("PATCH /api/v1/sessions/{}", id)),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_closed_session_rejects_jobs() {
+        let mock = MockRuntime::start();
+        let manager = manager(&mock);
+
+        let session = IbmSession::open(
+            &manager,
+            "ibm_mock",
+            SessionMode::Dedicated,
+            DEFAULT_MAX_TTL,
+        )
+        .await
+        .unwrap();
+        mock.expire_session(session.id());
+
+        let details = session.details().await.unwrap();
+        assert!(details.is_closed());
+        assert_eq!(details.closed_reason.as_deref(), Some("max TTL exceeded"));
+        assert!(matches!(
+            session.submit(submission()).await,
+            Err(JobError::SessionClosed(_))
+        ));
+
+        // A session past its local max TTL is not even tried
+        let short = IbmSession::open(&manager, "ibm_mock", SessionMode::Dedicated, Duration::ZERO)
+            .await
+            .unwrap();
+        assert!(short.is_expired());
+        let submits = mock.request_count("POST /api/v1/jobs");
+        assert!(matches!(
+            short.submit(submission()).await,
+            Err(JobError::SessionClosed(_))
+        ));
+        assert_eq!(mock.request_count("POST /api/v1/jobs"), submits);
+
+        let reopened = short.reopen().await.unwrap();
+        assert_ne!(reopened.id(), short.id());
+        reopened.cancel().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_batch_submits_all() {
+        let mock = MockRuntime::start();
+        let manager = manager(&mock);
+
+        let batch = IbmBatch::open(&manager, "ibm_mock", DEFAULT_MAX_TTL)
+            .await
+            .unwrap();
+        assert_eq!(batch.mode(), SessionMode::Batch);
+
+        let jobs = batch
+            .submit_all(vec![submission(), submission(), submission()])
+            .await
+            .unwrap();
+        assert_eq!(jobs.len(), 3);
+        assert!(jobs
+            .iter()
+            .all(|j| mock.job_session(j.id()).as_deref() == Some(batch.id())));
+        assert_eq!(
+            batch.details().await.unwrap().mode,
+            Some(SessionMode::Batch)
+        );
+
+        batch.close().await.unwrap();
+    }
+}
@@ -0,0 +1,576 @@
+//! Durable job tracking
+//!
+//! Gantree: L10_Qiskit → JobStore
+//!
+//! Persists submitted jobs (backend, circuit hashes, parameters, tags,
+//! status) in a directory so long hardware campaigns survive process
+//! restarts:
+//!
+//! ```text
+//! <dir>/jobs.json              job index
+//! <dir>/results/<job_id>.json  downloaded results
+//! ```
+//!
+//! ```rust,ignore
+//! let mut store = JobStore::open("campaign")?;
+//! store.submit(&manager, submission).await?;
+//! // ... later, possibly in another process
+//! let report = store.sync(&manager).await?;
+//! for record in store.pending() { /* still queued or running */ }
+//! ```
+
+use crate::job::{JobError, JobManager, JobResult, JobStatus, JobSubmission};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Index file name inside the store directory
+const INDEX_FILE: &str = "jobs.json";
+
+/// Results subdirectory
+const RESULTS_DIR: &str = "results";
+
+/// Job store errors
+#[derive(Debug, Error)]
+pub enum StoreError {
+    /// File system error
+    #[error("Job store I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Corrupt index or result file
+    #[error("Job store serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    /// Job API error
+    #[error("Job error: {0}")]
+    Job(#[from] JobError),
+
+    /// Job ID unusable as a result file name
+    #[error("Invalid job ID for a result file: {0:?}")]
+    InvalidJobId(String),
+}
+
+/// Persisted job record
+/// Gantree: JobRecord // 작업 기록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRecord {
+    /// Job ID
+    pub job_id: String,
+
+    /// Backend name
+    pub backend: String,
+
+    /// Stable hash of each submitted circuit (see `circuit_hash`)
+    #[serde(default)]
+    pub circuit_hashes: Vec<String>,
+
+    /// Run parameters (shots, seed, ... and user values such as δ)
+    #[serde(default)]
+    pub parameters: serde_json::Map<String, serde_json::Value>,
+
+    /// Job tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Last known status
+    pub status: JobStatus,
+
+    /// Failure reason, if the job failed
+    #[serde(default)]
+    pub error: Option<String>,
+
+    /// Submission time
+    pub submitted_at: DateTime<Utc>,
+
+    /// Last status change or sync
+    pub updated_at: DateTime<Utc>,
+
+    /// Downloaded results file
+    #[serde(default)]
+    pub results_path: Option<PathBuf>,
+}
+
+impl JobRecord {
+    /// Create a queued record
+    pub fn new(job_id: impl Into<String>, backend: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            job_id: job_id.into(),
+            backend: backend.into(),
+            circuit_hashes: Vec::new(),
+            parameters: serde_json::Map::new(),
+            tags: Vec::new(),
+            status: JobStatus::Queued,
+            error: None,
+            submitted_at: now,
+            updated_at: now,
+            results_path: None,
+        }
+    }
+
+    /// Record for a submission; circuits are stored as hashes only
+    pub fn from_submission(job_id: impl Into<String>, submission: &JobSubmission) -> Self {
+        let mut params = submission.params.clone();
+        let mut circuits = std::mem::take(&mut params.circuits);
+
+        // PUBs keep their bindings; the circuit is replaced by its hash
+        for pub_ in &mut params.pubs {
+            let circuit = pub_.circuit_mut();
+            let hash = circuit_hash(circuit);
+            circuits.push(std::mem::replace(circuit, hash));
+        }
+
+        let mut record = Self::new(job_id, submission.backend.clone())
+            .with_circuits(&circuits)
+            .with_tags(submission.tags.clone());
+        if let Ok(serde_json::Value::Object(map)) = serde_json::to_value(&params) {
+            record.parameters = map;
+        }
+        record
+    }
+
+    /// Set circuit hashes from the submitted QASM
+    pub fn with_circuits(mut self, circuits: &[String]) -> Self {
+        self.circuit_hashes = circuits.iter().map(|c| circuit_hash(c)).collect();
+        self
+    }
+
+    /// Set tags
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Attach a run parameter
+    pub fn with_parameter(mut self, key: impl Into<String>, value: impl Serialize) -> Self {
+        if let Ok(value) = serde_json::to_value(value) {
+            self.parameters.insert(key.into(), value);
+        }
+        self
+    }
+
+    /// Check if the job is still queued or running
+    pub fn is_pending(&self) -> bool {
+        !self.status.is_terminal()
+    }
+
+    /// Check if the job completed and its results are stored locally
+    pub fn has_results(&self) -> bool {
+        self.results_path.is_some()
+    }
+
+    /// Whether `sync` still needs to contact the API for this job
+    fn needs_sync(&self) -> bool {
+        self.is_pending() || (self.status == JobStatus::Completed && !self.has_results())
+    }
+}
+
+/// Outcome of `JobStore::sync`
+/// Gantree: SyncReport // 동기화 결과
+#[derive(Debug, Clone, Default)]
+pub struct SyncReport {
+    /// Jobs queried
+    pub checked: usize,
+
+    /// Jobs whose results were downloaded
+    pub completed: Vec<String>,
+
+    /// Jobs found failed or cancelled
+    pub failed: Vec<String>,
+
+    /// Jobs that could not be queried (job ID, error)
+    pub errors: Vec<(String, String)>,
+}
+
+/// Directory-backed job store
+/// Gantree: JobStore // 영속 작업 저장소
+#[derive(Debug)]
+pub struct JobStore {
+    dir: PathBuf,
+    records: Vec<JobRecord>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoreIndex {
+    jobs: Vec<JobRecord>,
+}
+
+impl JobStore {
+    /// Open (or create) a store in `dir`
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self, StoreError> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(dir.join(RESULTS_DIR))?;
+
+        let index = dir.join(INDEX_FILE);
+        let records = if index.exists() {
+            serde_json::from_str::<StoreIndex>(&fs::read_to_string(&index)?)?.jobs
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self { dir, records })
+    }
+
+    /// Store directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Directory holding downloaded results
+    pub fn results_dir(&self) -> PathBuf {
+        self.dir.join(RESULTS_DIR)
+    }
+
+    // ========================================================================
+    // Records
+    // ========================================================================
+
+    /// Add or replace a record and persist the index
+    pub fn insert(&mut self, record: JobRecord) -> Result<(), StoreError> {
+        match self.records.iter_mut().find(|r| r.job_id == record.job_id) {
+            Some(existing) => *existing = record,
+            None => self.records.push(record),
+        }
+        self.save()
+    }
+
+    /// Remove a record (its results file is kept)
+    pub fn remove(&mut self, job_id: &str) -> Result<Option<JobRecord>, StoreError> {
+        let Some(index) = self.records.iter().position(|r| r.job_id == job_id) else {
+            return Ok(None);
+        };
+        let record = self.records.remove(index);
+        self.save()?;
+        Ok(Some(record))
+    }
+
+    /// Get a record by job ID
+    pub fn get(&self, job_id: &str) -> Option<&JobRecord> {
+        self.records.iter().find(|r| r.job_id == job_id)
+    }
+
+    /// All records, in submission order
+    pub fn records(&self) -> impl Iterator<Item = &JobRecord> {
+        self.records.iter()
+    }
+
+    /// Records still queued or running
+    pub fn pending(&self) -> impl Iterator<Item = &JobRecord> {
+        self.records.iter().filter(|r| r.is_pending())
+    }
+
+    /// Number of records
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Check if the store is empty
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Load the downloaded results of a job
+    pub fn load_result(&self, job_id: &str) -> Result<Option<JobResult>, StoreError> {
+        let Some(path) = self.get(job_id).and_then(|r| r.results_path.as_ref()) else {
+            return Ok(None);
+        };
+        Ok(Some(serde_json::from_str(&fs::read_to_string(path)?)?))
+    }
+
+    // ========================================================================
+    // API
+    // ========================================================================
+
+    /// Submit a job and record it
+    /// Gantree: submit(&mut,manager,submission) -> JobRecord // 제출 후 기록
+    pub async fn submit(
+        &mut self,
+        manager: &JobManager,
+        submission: JobSubmission,
+    ) -> Result<JobRecord, StoreError> {
+        let job = manager.submit(submission.clone()).await?;
+
+        let mut record = JobRecord::from_submission(job.id(), &submission);
+        record.status = job.status();
+        self.insert(record.clone())?;
+
+        Ok(record)
+    }
+
+    /// Refresh unfinished jobs and download results of completed ones
+    /// Gantree: sync(&mut,manager) -> SyncReport // 상태 동기화
+    ///
+    /// A job that cannot be queried is reported in `SyncReport::errors`
+    /// and retried on the next sync; the index is saved either way.
+    pub async fn sync(&mut self, manager: &JobManager) -> Result<SyncReport, StoreError> {
+        let mut report = SyncReport::default();
+        let results_dir = self.results_dir();
+
+        for record in self.records.iter_mut().filter(|r| r.needs_sync()) {
+            report.checked += 1;
+            match Self::sync_record(record, manager, &results_dir).await {
+                Ok(()) => match record.status {
+                    JobStatus::Completed => report.completed.push(record.job_id.clone()),
+                    JobStatus::Failed | JobStatus::Cancelled => {
+                        report.failed.push(record.job_id.clone())
+                    }
+                    _ => {}
+                },
+                Err(e) => report.errors.push((record.job_id.clone(), e.to_string())),
+            }
+        }
+
+        self.save()?;
+        Ok(report)
+    }
+
+    /// Refresh one record, downloading results when it completed
+    async fn sync_record(
+        record: &mut JobRecord,
+        manager: &JobManager,
+        results_dir: &Path,
+    ) -> Result<(), StoreError> {
+        let mut job = manager.get_job(&record.job_id).await?;
+        let status = match job.status() {
+            JobStatus::Failed => match job.refresh().await {
+                Err(JobError::ExecutionFailed(reason)) => {
+                    record.error = Some(reason);
+                    JobStatus::Failed
+                }
+                Err(e) => return Err(e.into()),
+                Ok(status) => status,
+            },
+            status => status,
+        };
+
+        if status == JobStatus::Completed {
+            let result = job.result().await?;
+            let path = result_path(results_dir, &record.job_id)?;
+            write_atomic(&path, &serde_json::to_string_pretty(&result)?)?;
+            record.results_path = Some(path);
+        }
+
+        record.status = status;
+        record.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Persist the index
+    fn save(&self) -> Result<(), StoreError> {
+        let index = StoreIndex {
+            jobs: self.records.clone(),
+        };
+        write_atomic(
+            &self.dir.join(INDEX_FILE),
+            &serde_json::to_string_pretty(&index)?,
+        )
+    }
+}
+
+/// Result file of a job; IDs that could escape `results_dir` are rejected
+fn result_path(results_dir: &Path, job_id: &str) -> Result<PathBuf, StoreError> {
+    let unsafe_id =
+        job_id.is_empty() || job_id.contains("..") || job_id.contains(['/', '\\', ':', '\0']);
+    if unsafe_id {
+        return Err(StoreError::InvalidJobId(job_id.to_string()));
+    }
+    Ok(results_dir.join(format!("{}.json", job_id)))
+}
+
+/// Write via a temporary file so a crash never leaves a truncated file
+fn write_atomic(path: &Path, contents: &str) -> Result<(), StoreError> {
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Stable hash of a circuit's QASM (64-bit FNV-1a, hex)
+/// Gantree: circuit_hash(qasm) -> String // 회로 해시
+///
+/// Independent of the Rust version, so hashes stay comparable across
+/// builds and restarts.
+pub fn circuit_hash(qasm: &str) -> String {
+    let hash = qasm.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::IbmClient;
+    use crate::job::JobParams;
+    use crate::mock::MockRuntime;
+    use crate::sampler::SamplerPub;
+    use std::time::Duration;
+
+    const QASM: &str = "OPENQASM 3.0;\nqubit[2] q;\nbit[2] c;\nc = measure q;";
+
+    fn temp_store(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("niso_qiskit_store_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn submission(shots: u64) -> JobSubmission {
+        JobSubmission {
+            program_id: "sampler".to_string(),
+            backend: "ibm_mock".to_string(),
+            params: JobParams::new(vec![QASM.to_string()]).with_shots(shots),
+            tags: vec!["niso".to_string(), "campaign-a".to_string()],
+            session_id: None,
+        }
+    }
+
+    fn manager(mock: &MockRuntime) -> JobManager {
+        JobManager::new(IbmClient::new(mock.credentials()).unwrap())
+            .with_poll_interval(Duration::from_millis(5))
+    }
+
+    #[test]
+    fn test_circuit_hash_is_stable() {
+        assert_eq!(circuit_hash(""), "cbf29ce484222325");
+        assert_eq!(circuit_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(circuit_hash(QASM), circuit_hash(&QASM.replace("2", "3")));
+    }
+
+    #[test]
+    fn test_result_path_rejects_traversal() {
+        let dir = Path::new("store/results");
+
+        assert_eq!(
+            result_path(dir, "d1abc2ef").unwrap(),
+            dir.join("d1abc2ef.json")
+        );
+        for id in [
+            "",
+            "..",
+            "../jobs",
+            "a/b",
+            "a\\b",
+            "/etc/passwd",
+            "C:x",
+            "x\0y",
+        ] {
+            assert!(
+                matches!(result_path(dir, id), Err(StoreError::InvalidJobId(_))),
+                "{:?} accepted",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_record_from_submission() {
+        let record =
+            JobRecord::from_submission("job-1", &submission(2048)).with_parameter("delta", 0.125);
+
+        assert_eq!(record.backend, "ibm_mock");
+        assert_eq!(record.circuit_hashes, [circuit_hash(QASM)]);
+        assert_eq!(record.parameters["shots"], 2048);
+        assert_eq!(record.parameters["delta"], 0.125);
+        assert!(!record.parameters.contains_key("circuits"));
+        assert!(record.is_pending());
+    }
+
+    #[test]
+    fn test_record_from_pub_submission() {
+        let submission = JobSubmission {
+            params: JobParams::from_pubs(vec![SamplerPub::sweep(QASM, &[0.1, 0.2])]),
+            ..submission(1024)
+        };
+        let record = JobRecord::from_submission("job-2", &submission);
+
+        assert_eq!(record.circuit_hashes, [circuit_hash(QASM)]);
+        assert_eq!(
+            record.parameters["pubs"],
+            serde_json::json!([[circuit_hash(QASM), [[0.1], [0.2]]]])
+        );
+        assert_eq!(record.parameters["version"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_store_survives_restart_and_syncs() {
+        let dir = temp_store("sync");
+        let mock = MockRuntime::start();
+        mock.set_queue_polls(1);
+        let manager = manager(&mock);
+
+        let id = {
+            let mut store = JobStore::open(&dir).unwrap();
+            store
+                .submit(&manager, submission(512))
+                .await
+                .unwrap()
+                .job_id
+        };
+
+        // A fresh process sees the submission
+        let mut store = JobStore::open(&dir).unwrap();
+        assert_eq!(store.pending().count(), 1);
+        assert_eq!(store.get(&id).unwrap().tags, ["niso", "campaign-a"]);
+
+        let report = store.sync(&manager).await.unwrap();
+        assert_eq!(report.checked, 1);
+        assert!(report.completed.is_empty());
+        assert_eq!(store.get(&id).unwrap().status, JobStatus::Running);
+
+        let report = store.sync(&manager).await.unwrap();
+        assert_eq!(report.completed, vec![id.clone()]);
+        assert_eq!(store.pending().count(), 0);
+
+        let result = JobStore::open(&dir)
+            .unwrap()
+            .load_result(&id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.results[0].counts.as_ref().unwrap()["00"], 512);
+
+        // Nothing left to query
+        assert_eq!(store.sync(&manager).await.unwrap().checked, 0);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_sync_records_failures_and_errors() {
+        let dir = temp_store("failures");
+        let mock = MockRuntime::start();
+        let manager = manager(&mock);
+        let mut store = JobStore::open(&dir).unwrap();
+
+        mock.fail_next_job("qubit 3 unavailable");
+        let failed = store
+            .submit(&manager, submission(100))
+            .await
+            .unwrap()
+            .job_id;
+        store
+            .insert(JobRecord::new("job_unknown", "ibm_mock"))
+            .unwrap();
+
+        let report = store.sync(&manager).await.unwrap();
+        assert_eq!(report.failed, vec![failed.clone()]);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].0, "job_unknown");
+
+        let record = store.get(&failed).unwrap();
+        assert_eq!(record.status, JobStatus::Failed);
+        assert_eq!(record.error.as_deref(), Some("qubit 3 unavailable"));
+        assert!(!record.has_results());
+
+        // The unreachable job stays pending and is retried
+        assert_eq!(store.pending().count(), 1);
+        assert!(store.remove("job_unknown").unwrap().is_some());
+        assert_eq!(JobStore::open(&dir).unwrap().len(), 1);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -5,9 +5,9 @@
 //! Provides complete circuit timing information including
 //! idle time analysis and decoherence estimation.
 
-use crate::scheduled_gate::ScheduledGate;
+use crate::scheduled_gate::{ScheduledGate, TimeSlot};
 use niso_core::QubitId;
-use niso_noise::NoiseVector;
+use niso_noise::{CrosstalkMap, NoiseVector};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -29,6 +29,11 @@ pub struct CircuitSchedule {
 
     /// Per-qubit end times
     qubit_end_times: Vec<f64>,
+
+    /// Explicit delays padding idle qubits (hardware-aligned schedules)
+    /// Gantree: delays: Vec<TimeSlot> // 지연 패딩
+    #[serde(default)]
+    delays: Vec<TimeSlot>,
 }
 
 impl CircuitSchedule {
@@ -48,9 +53,16 @@ impl CircuitSchedule {
             total_duration_ns,
             num_qubits,
             qubit_end_times,
+            delays: Vec::new(),
         }
     }
 
+    /// Attach explicit delay padding
+    pub fn with_delays(mut self, delays: Vec<TimeSlot>) -> Self {
+        self.delays = delays;
+        self
+    }
+
     /// Create empty schedule
     pub fn empty(num_qubits: usize) -> Self {
         Self {
@@ -58,6 +70,7 @@ impl CircuitSchedule {
             total_duration_ns: 0.0,
             num_qubits,
             qubit_end_times: vec![0.0; num_qubits],
+            delays: Vec::new(),
         }
     }
 
@@ -95,6 +108,16 @@ impl CircuitSchedule {
         &self.qubit_end_times
     }
 
+    /// Build an exportable timeline (JSON / SVG)
+    pub fn to_timeline(&self, noise_vectors: &[NoiseVector]) -> crate::export::ScheduleTimeline {
+        crate::export::ScheduleTimeline::from_schedule(self, noise_vectors)
+    }
+
+    /// Get explicit delay padding (empty unless hardware-aligned)
+    pub fn delays(&self) -> &[TimeSlot] {
+        &self.delays
+    }
+
     // ========================================================================
     // Critical Path Analysis
     // ========================================================================
@@ -154,6 +177,46 @@ impl CircuitSchedule {
             .collect()
     }
 
+    /// Per-qubit idle intervals (gaps before, between and after gates)
+    /// Gantree: idle_intervals(&self) -> Vec<TimeSlot> // idle 구간
+    ///
+    /// Global operations (e.g. `MeasureAll`) occupy every qubit. A qubit that
+    /// finishes early is idle until the end of the schedule.
+    pub fn idle_intervals(&self) -> Vec<TimeSlot> {
+        let mut busy: Vec<Vec<(f64, f64)>> = vec![Vec::new(); self.num_qubits];
+
+        for gate in &self.gates {
+            let qubits = gate.qubits();
+            if qubits.is_empty() {
+                for slots in busy.iter_mut() {
+                    slots.push((gate.start_time_ns, gate.end_time_ns));
+                }
+            } else {
+                for q in qubits.into_iter().filter(|&q| q < self.num_qubits) {
+                    busy[q].push((gate.start_time_ns, gate.end_time_ns));
+                }
+            }
+        }
+
+        let mut idle = Vec::new();
+        for (q, slots) in busy.iter_mut().enumerate() {
+            slots.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+            let mut cursor = 0.0;
+            for &(start, end) in slots.iter() {
+                if start > cursor {
+                    idle.push(TimeSlot::new(q, cursor, start));
+                }
+                cursor = f64::max(cursor, end);
+            }
+            if self.total_duration_ns > cursor {
+                idle.push(TimeSlot::new(q, cursor, self.total_duration_ns));
+            }
+        }
+
+        idle
+    }
+
     /// Calculate total idle time across all qubits
     /// Gantree: total_idle_time(&self) -> f64 // 총 idle
     pub fn total_idle_time(&self) -> f64 {
@@ -270,6 +333,47 @@ impl CircuitSchedule {
         }
     }
 
+    // ========================================================================
+    // Crosstalk Analysis
+    // ========================================================================
+
+    /// Find overlapping gates on conflicting edges
+    /// Gantree: crosstalk_overlaps(&self,CrosstalkMap) -> Vec // 크로스톡 중첩
+    ///
+    /// Returns `(gate_idx_a, gate_idx_b, error)` for every pair of gates
+    /// that run at the same time on edges listed in the crosstalk map.
+    pub fn crosstalk_overlaps(&self, crosstalk: &CrosstalkMap) -> Vec<(usize, usize, f64)> {
+        let mut overlaps = Vec::new();
+        if crosstalk.is_empty() {
+            return overlaps;
+        }
+
+        let two_qubit: Vec<&ScheduledGate> =
+            self.gates.iter().filter(|g| g.is_two_qubit()).collect();
+
+        for (i, a) in two_qubit.iter().enumerate() {
+            for b in &two_qubit[i + 1..] {
+                if !a.overlaps(b.start_time_ns, b.end_time_ns) {
+                    continue;
+                }
+                if let Some(error) = crosstalk.conflict_between(&a.qubits(), &b.qubits()) {
+                    overlaps.push((a.gate_idx, b.gate_idx, error));
+                }
+            }
+        }
+
+        overlaps
+    }
+
+    /// Estimate total crosstalk error from overlapping conflicting gates
+    pub fn estimate_crosstalk_error(&self, crosstalk: &CrosstalkMap) -> f64 {
+        1.0 - self
+            .crosstalk_overlaps(crosstalk)
+            .iter()
+            .map(|&(_, _, error)| 1.0 - error)
+            .product::<f64>()
+    }
+
     // ========================================================================
     // Gate Statistics
     // ========================================================================
@@ -348,6 +452,36 @@ mod tests {
         assert_eq!(schedule.total_duration_ns(), 5335.0);
     }
 
+    #[test]
+    fn test_idle_intervals() {
+        let gates = vec![
+            ScheduledGate::new(0, Gate::H(0), 0.0, 35.0),
+            ScheduledGate::new(1, Gate::H(0), 35.0, 70.0),
+            ScheduledGate::new(2, Gate::Cnot(0, 1), 70.0, 370.0),
+            ScheduledGate::new(3, Gate::MeasureAll, 370.0, 5370.0),
+        ];
+
+        let schedule = CircuitSchedule::new(gates, 5370.0, 2, vec![5370.0, 5370.0]);
+        let idle = schedule.idle_intervals();
+
+        assert_eq!(idle, vec![TimeSlot::new(1, 0.0, 70.0)]);
+    }
+
+    #[test]
+    fn test_idle_intervals_trailing() {
+        // q1 finishes at 35 ns while q0 runs until 370 ns
+        let gates = vec![
+            ScheduledGate::new(0, Gate::H(1), 0.0, 35.0),
+            ScheduledGate::new(1, Gate::H(0), 0.0, 35.0),
+            ScheduledGate::new(2, Gate::Rz(0, 0.5), 35.0, 370.0),
+        ];
+
+        let schedule = CircuitSchedule::new(gates, 370.0, 2, vec![370.0, 35.0]);
+        let idle = schedule.idle_intervals();
+
+        assert_eq!(idle, vec![TimeSlot::new(1, 35.0, 370.0)]);
+    }
+
     #[test]
     fn test_idle_times() {
         // Create schedule where q1 waits for q0
@@ -414,6 +548,22 @@ mod tests {
         assert_eq!(schedule.concurrent_gates_at(100.0), 1);
     }
 
+    #[test]
+    fn test_crosstalk_overlaps() {
+        let gates = vec![
+            ScheduledGate::new(0, Gate::Cnot(0, 1), 0.0, 300.0),
+            ScheduledGate::new(1, Gate::Cnot(2, 3), 100.0, 400.0),
+            ScheduledGate::new(2, Gate::Cnot(2, 3), 400.0, 700.0),
+        ];
+        let schedule = CircuitSchedule::new(gates, 700.0, 4, vec![300.0, 300.0, 700.0, 700.0]);
+        let crosstalk = CrosstalkMap::new().with_pair((0, 1), (2, 3), 0.1);
+
+        let overlaps = schedule.crosstalk_overlaps(&crosstalk);
+        assert_eq!(overlaps, vec![(0, 1, 0.1)]);
+        assert!((schedule.estimate_crosstalk_error(&crosstalk) - 0.1).abs() < 1e-12);
+        assert_eq!(schedule.estimate_crosstalk_error(&CrosstalkMap::new()), 0.0);
+    }
+
     #[test]
     fn test_decoherence_estimation() {
         let schedule = make_test_schedule();
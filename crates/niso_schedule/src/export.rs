@@ -0,0 +1,363 @@
+//! Schedule timeline export for NISO
+//!
+//! Gantree: L4_Scheduling → ScheduleExporter
+//!
+//! Converts a `CircuitSchedule` into a per-qubit timeline that can be
+//! written as JSON or rendered as a standalone SVG Gantt chart. Idle
+//! regions carry the estimated T2 decay from the attached noise vectors,
+//! which makes long waits easy to spot in optimization reports.
+
+use crate::circuit_schedule::CircuitSchedule;
+use crate::scheduled_gate::ScheduledGate;
+use niso_core::{NisoResult, QubitId};
+use niso_noise::NoiseVector;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Gate category used for coloring timeline blocks
+/// Gantree: GateKind // 게이트 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GateKind {
+    /// Single-qubit gate
+    SingleQubit,
+    /// Two-qubit gate
+    TwoQubit,
+    /// Measurement
+    Measure,
+    /// Barrier
+    Barrier,
+    /// Anything else (reset, ...)
+    Other,
+}
+
+impl GateKind {
+    /// SVG fill color for this gate kind
+    pub fn color(&self) -> &'static str {
+        match self {
+            GateKind::SingleQubit => "#4e79a7",
+            GateKind::TwoQubit => "#e15759",
+            GateKind::Measure => "#59a14f",
+            GateKind::Barrier => "#9c9c9c",
+            GateKind::Other => "#f28e2b",
+        }
+    }
+}
+
+/// A gate block on a qubit lane
+/// Gantree: GateInterval // 게이트 구간
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateInterval {
+    /// Index in the original circuit
+    pub gate_idx: usize,
+
+    /// Gate label (e.g. "h", "cx")
+    pub label: String,
+
+    /// Gate category
+    pub kind: GateKind,
+
+    /// Start time in nanoseconds
+    pub start_ns: f64,
+
+    /// End time in nanoseconds
+    pub end_ns: f64,
+
+    /// All qubits the gate acts on
+    pub qubits: Vec<QubitId>,
+}
+
+/// An idle gap on a qubit lane
+/// Gantree: IdleInterval // idle 구간
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdleInterval {
+    /// Start time in nanoseconds
+    pub start_ns: f64,
+
+    /// End time in nanoseconds
+    pub end_ns: f64,
+
+    /// Estimated T2 decay over the gap (0 without noise data)
+    pub t2_decay: f64,
+}
+
+/// Timeline of a single qubit
+/// Gantree: QubitLane // 큐비트 레인
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QubitLane {
+    /// Qubit index
+    pub qubit: QubitId,
+
+    /// Gate blocks in time order
+    pub gates: Vec<GateInterval>,
+
+    /// Idle gaps in time order
+    pub idle: Vec<IdleInterval>,
+}
+
+/// Exportable schedule timeline
+/// Gantree: ScheduleTimeline // 타임라인
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleTimeline {
+    /// Total duration in nanoseconds
+    pub total_duration_ns: f64,
+
+    /// One lane per qubit
+    pub lanes: Vec<QubitLane>,
+}
+
+impl ScheduleTimeline {
+    // ========================================================================
+    // Constructors
+    // ========================================================================
+
+    /// Build a timeline from a schedule
+    /// Gantree: from_schedule(schedule,nvs) -> Self // 타임라인 생성
+    ///
+    /// `noise_vectors` may be empty; idle decay is then reported as 0.
+    pub fn from_schedule(schedule: &CircuitSchedule, noise_vectors: &[NoiseVector]) -> Self {
+        let num_qubits = schedule.num_qubits();
+        let mut lanes: Vec<QubitLane> = (0..num_qubits)
+            .map(|qubit| QubitLane {
+                qubit,
+                gates: Vec::new(),
+                idle: Vec::new(),
+            })
+            .collect();
+
+        for sg in schedule.gates() {
+            let mut qubits = sg.qubits();
+            qubits.retain(|&q| q < num_qubits);
+            if sg.gate.qubits().is_empty() {
+                qubits = (0..num_qubits).collect();
+            }
+
+            let interval = GateInterval {
+                gate_idx: sg.gate_idx,
+                label: sg.gate.name().to_string(),
+                kind: Self::classify(sg),
+                start_ns: sg.start_time_ns,
+                end_ns: sg.end_time_ns,
+                qubits: qubits.clone(),
+            };
+
+            for &q in &qubits {
+                lanes[q].gates.push(interval.clone());
+            }
+        }
+
+        for slot in schedule.idle_intervals() {
+            let t2_decay = noise_vectors
+                .iter()
+                .find(|nv| nv.qubit_id == slot.qubit)
+                .map(|nv| nv.estimate_decoherence(slot.duration() / 1000.0))
+                .unwrap_or(0.0);
+
+            lanes[slot.qubit].idle.push(IdleInterval {
+                start_ns: slot.start_ns,
+                end_ns: slot.end_ns,
+                t2_decay,
+            });
+        }
+
+        Self {
+            total_duration_ns: schedule.total_duration_ns(),
+            lanes,
+        }
+    }
+
+    fn classify(sg: &ScheduledGate) -> GateKind {
+        if sg.gate.is_barrier() {
+            GateKind::Barrier
+        } else if sg.gate.is_measurement() {
+            GateKind::Measure
+        } else if sg.gate.is_two_qubit() {
+            GateKind::TwoQubit
+        } else if sg.gate.is_single_qubit() {
+            GateKind::SingleQubit
+        } else {
+            GateKind::Other
+        }
+    }
+
+    // ========================================================================
+    // JSON Export
+    // ========================================================================
+
+    /// Serialize to pretty JSON
+    pub fn to_json(&self) -> NisoResult<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Write JSON to a file
+    pub fn write_json<P: AsRef<Path>>(&self, path: P) -> NisoResult<()> {
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    // ========================================================================
+    // SVG Export
+    // ========================================================================
+
+    /// Render a standalone SVG Gantt chart
+    /// Gantree: to_svg(&self) -> String // SVG 렌더링
+    ///
+    /// One lane per qubit; gate blocks are colored by `GateKind` and idle
+    /// regions are shaded with opacity proportional to their T2 decay.
+    pub fn to_svg(&self) -> String {
+        const LABEL_W: f64 = 48.0;
+        const PLOT_W: f64 = 960.0;
+        const LANE_H: f64 = 32.0;
+        const BLOCK_H: f64 = 22.0;
+        const AXIS_H: f64 = 28.0;
+
+        let height = self.lanes.len() as f64 * LANE_H + AXIS_H;
+        let width = LABEL_W + PLOT_W + 16.0;
+        let scale = if self.total_duration_ns > 0.0 {
+            PLOT_W / self.total_duration_ns
+        } else {
+            0.0
+        };
+        let x = |t: f64| LABEL_W + t * scale;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width:.0}" height="{height:.0}" viewBox="0 0 {width:.0} {height:.0}" font-family="monospace" font-size="11">"#
+        );
+        let _ = writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#);
+
+        for (row, lane) in self.lanes.iter().enumerate() {
+            let top = row as f64 * LANE_H;
+            let y = top + (LANE_H - BLOCK_H) / 2.0;
+            let mid = top + LANE_H / 2.0;
+
+            let _ = writeln!(
+                svg,
+                r#"<text x="4" y="{:.1}" dominant-baseline="middle">q{}</text>"#,
+                mid, lane.qubit
+            );
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{:.1}" y1="{mid:.1}" x2="{:.1}" y2="{mid:.1}" stroke="#cccccc"/>"##,
+                LABEL_W,
+                LABEL_W + PLOT_W
+            );
+
+            for idle in &lane.idle {
+                let opacity = 0.1 + 0.8 * idle.t2_decay.clamp(0.0, 1.0);
+                let _ = writeln!(
+                    svg,
+                    r##"<rect class="idle" x="{:.2}" y="{y:.1}" width="{:.2}" height="{BLOCK_H:.1}" fill="#7f3c8d" fill-opacity="{opacity:.3}"><title>idle {:.1} ns, T2 decay {:.4}</title></rect>"##,
+                    x(idle.start_ns),
+                    (idle.end_ns - idle.start_ns) * scale,
+                    idle.end_ns - idle.start_ns,
+                    idle.t2_decay
+                );
+            }
+
+            for gate in &lane.gates {
+                let w = ((gate.end_ns - gate.start_ns) * scale).max(1.0);
+                let _ = writeln!(
+                    svg,
+                    r#"<rect class="gate" x="{:.2}" y="{y:.1}" width="{w:.2}" height="{BLOCK_H:.1}" fill="{}"><title>#{} {} {:.1}-{:.1} ns</title></rect>"#,
+                    x(gate.start_ns),
+                    gate.kind.color(),
+                    gate.gate_idx,
+                    gate.label,
+                    gate.start_ns,
+                    gate.end_ns
+                );
+            }
+        }
+
+        let axis_y = self.lanes.len() as f64 * LANE_H + AXIS_H / 2.0;
+        let _ = writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{axis_y:.1}">0 ns</text>"#,
+            LABEL_W
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{axis_y:.1}" text-anchor="end">{:.1} ns</text>"#,
+            LABEL_W + PLOT_W,
+            self.total_duration_ns
+        );
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+
+    /// Write SVG to a file
+    pub fn write_svg<P: AsRef<Path>>(&self, path: P) -> NisoResult<()> {
+        std::fs::write(path, self.to_svg())?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheduler::Scheduler;
+    use niso_core::CircuitBuilder;
+    use niso_noise::GateTimes;
+
+    fn make_timeline(noise_vectors: &[NoiseVector]) -> ScheduleTimeline {
+        // q1 waits for two H gates on q0
+        let circuit = CircuitBuilder::new(2)
+            .h(0)
+            .h(0)
+            .cnot(0, 1)
+            .measure_all()
+            .build();
+        let schedule = Scheduler::compute_asap(&circuit, &GateTimes::default());
+        ScheduleTimeline::from_schedule(&schedule, noise_vectors)
+    }
+
+    #[test]
+    fn test_timeline_lanes() {
+        let nvs = vec![
+            NoiseVector::new(0, 100.0, 60.0, 0.001, 0.01, 0.01),
+            NoiseVector::new(1, 100.0, 60.0, 0.001, 0.01, 0.01),
+        ];
+        let timeline = make_timeline(&nvs);
+
+        assert_eq!(timeline.lanes.len(), 2);
+        assert_eq!(timeline.lanes[0].gates.len(), 4);
+        assert_eq!(timeline.lanes[1].gates.len(), 2);
+        assert_eq!(timeline.lanes[1].gates[0].kind, GateKind::TwoQubit);
+        assert_eq!(timeline.lanes[1].gates[1].kind, GateKind::Measure);
+
+        assert!(timeline.lanes[0].idle.is_empty());
+        assert_eq!(timeline.lanes[1].idle.len(), 1);
+        assert!(timeline.lanes[1].idle[0].t2_decay > 0.0);
+    }
+
+    #[test]
+    fn test_timeline_json_roundtrip() {
+        let timeline = make_timeline(&[]);
+        let json = timeline.to_json().unwrap();
+
+        assert!(json.contains("\"two_qubit\""));
+        let back: ScheduleTimeline = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, timeline);
+        assert_eq!(back.lanes[1].idle[0].t2_decay, 0.0);
+    }
+
+    #[test]
+    fn test_timeline_svg() {
+        let timeline = make_timeline(&[]);
+        let svg = timeline.to_svg();
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("class=\"gate\"").count(), 6);
+        assert_eq!(svg.matches("class=\"idle\"").count(), 1);
+        assert!(svg.contains(GateKind::TwoQubit.color()));
+    }
+}
@@ -66,13 +66,21 @@ pub mod circuit_schedule;
 /// Scheduler algorithms (Gantree: L4_Scheduling ??Scheduler)
 pub mod scheduler;
 
+/// Hardware timing constraints (Gantree: L4_Scheduling ??TimingConstraints)
+pub mod timing;
+
+/// Timeline export (Gantree: L4_Scheduling ??ScheduleExporter)
+pub mod export;
+
 // ============================================================================
 // Re-exports
 // ============================================================================
 
 pub use circuit_schedule::CircuitSchedule;
+pub use export::{GateInterval, GateKind, IdleInterval, QubitLane, ScheduleTimeline};
 pub use scheduled_gate::{ScheduledGate, TimeSlot};
 pub use scheduler::Scheduler;
+pub use timing::TimingConstraints;
 
 // ============================================================================
 // Prelude
@@ -87,8 +95,10 @@ pub mod prelude {
     //! ```
 
     pub use crate::circuit_schedule::CircuitSchedule;
+    pub use crate::export::ScheduleTimeline;
     pub use crate::scheduled_gate::{ScheduledGate, TimeSlot};
     pub use crate::scheduler::Scheduler;
+    pub use crate::timing::TimingConstraints;
 }
 
 // ============================================================================
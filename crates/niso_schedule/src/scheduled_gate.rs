@@ -101,7 +101,7 @@ impl fmt::Display for ScheduledGate {
 // ============================================================================
 
 /// Time slot for a qubit
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct TimeSlot {
     /// Qubit ID
     pub qubit: QubitId,
@@ -6,9 +6,10 @@
 //! enabling timing analysis and decoherence estimation.
 
 use crate::circuit_schedule::CircuitSchedule;
-use crate::scheduled_gate::ScheduledGate;
+use crate::scheduled_gate::{ScheduledGate, TimeSlot};
+use crate::timing::TimingConstraints;
 use niso_core::{Circuit, Gate, QubitId};
-use niso_noise::{GateTimes, NoiseVector};
+use niso_noise::{CrosstalkMap, GateTimes, NoiseVector};
 
 /// Circuit scheduler
 /// Gantree: Scheduler // 스케줄러
@@ -55,6 +56,199 @@ impl Scheduler {
         CircuitSchedule::new(scheduled_gates, total_duration, num_qubits, qubit_available)
     }
 
+    // ========================================================================
+    // Hardware-Aligned Scheduling
+    // ========================================================================
+
+    /// Compute an ASAP schedule that satisfies hardware timing constraints
+    /// Gantree: compute_aligned(Circuit,GateTimes,TimingConstraints) -> CircuitSchedule // 정렬 스케줄
+    ///
+    /// Gate lengths are rounded up to the device granularity and minimum
+    /// length, start times are aligned to the pulse (or acquire) alignment,
+    /// and every idle gap before a gate is recorded as an explicit delay.
+    /// All times are exact multiples of `dt`.
+    pub fn compute_aligned(
+        circuit: &Circuit,
+        gate_times: &GateTimes,
+        constraints: &TimingConstraints,
+    ) -> CircuitSchedule {
+        let num_qubits = circuit.num_qubits();
+
+        if circuit.is_empty() {
+            return CircuitSchedule::empty(num_qubits);
+        }
+
+        // Gantree: init_qubit_available() // 가용 샘플 초기화
+        let mut qubit_available = vec![0u64; num_qubits];
+        let mut scheduled_gates = Vec::with_capacity(circuit.gate_count());
+        let mut delays = Vec::new();
+
+        for (gate_idx, gate) in circuit.gates().iter().enumerate() {
+            let qubits: Vec<QubitId> = if gate.qubits().is_empty() {
+                (0..num_qubits).collect()
+            } else {
+                gate.qubits()
+                    .into_iter()
+                    .filter(|&q| q < num_qubits)
+                    .collect()
+            };
+
+            let duration = constraints.duration_samples(gate_times.gate_duration(gate));
+            let earliest = qubits
+                .iter()
+                .map(|&q| qubit_available[q])
+                .max()
+                .unwrap_or(0);
+
+            // Gantree: align_start(gate) // 시작 정렬
+            let start = if duration == 0 {
+                earliest // Virtual gates and barriers take no time
+            } else if gate.is_measurement() {
+                constraints.align(earliest, constraints.acquire_alignment)
+            } else {
+                constraints.align(earliest, constraints.pulse_alignment)
+            };
+            let end = start + duration;
+
+            // Gantree: pad_delays(gate) // 지연 패딩
+            for &q in &qubits {
+                if duration > 0 && start > qubit_available[q] {
+                    delays.push(TimeSlot::new(
+                        q,
+                        constraints.to_ns(qubit_available[q]),
+                        constraints.to_ns(start),
+                    ));
+                }
+                qubit_available[q] = end;
+            }
+
+            scheduled_gates.push(ScheduledGate::new(
+                gate_idx,
+                gate.clone(),
+                constraints.to_ns(start),
+                constraints.to_ns(end),
+            ));
+        }
+
+        let total = qubit_available.iter().copied().max().unwrap_or(0);
+        let qubit_end_times = qubit_available
+            .iter()
+            .map(|&s| constraints.to_ns(s))
+            .collect();
+
+        CircuitSchedule::new(
+            scheduled_gates,
+            constraints.to_ns(total),
+            num_qubits,
+            qubit_end_times,
+        )
+        .with_delays(delays)
+    }
+
+    // ========================================================================
+    // Crosstalk-Aware Scheduling
+    // ========================================================================
+
+    /// Compute a crosstalk-aware schedule
+    /// Gantree: compute_crosstalk_aware(Circuit,GateTimes,NoiseVector,CrosstalkMap) -> CircuitSchedule // 크로스톡 회피
+    ///
+    /// Gates are placed ASAP, except that a two-qubit gate which would
+    /// overlap a conflicting two-qubit gate is delayed until the conflict
+    /// ends. A delay is only taken when the decoherence it adds to the
+    /// gate's qubits is smaller than the crosstalk error it avoids.
+    pub fn compute_crosstalk_aware(
+        circuit: &Circuit,
+        gate_times: &GateTimes,
+        noise_vectors: &[NoiseVector],
+        crosstalk: &CrosstalkMap,
+    ) -> CircuitSchedule {
+        let num_qubits = circuit.num_qubits();
+
+        if circuit.is_empty() {
+            return CircuitSchedule::empty(num_qubits);
+        }
+
+        let mut qubit_available = vec![0.0; num_qubits];
+        let mut scheduled_gates: Vec<ScheduledGate> = Vec::with_capacity(circuit.gate_count());
+
+        for (gate_idx, gate) in circuit.gates().iter().enumerate() {
+            let (mut start_time, mut end_time) =
+                Self::schedule_gate(gate, &qubit_available, gate_times);
+
+            if gate.is_two_qubit() && !crosstalk.is_empty() {
+                start_time = Self::resolve_crosstalk(
+                    gate,
+                    start_time,
+                    end_time - start_time,
+                    &scheduled_gates,
+                    noise_vectors,
+                    crosstalk,
+                );
+                end_time = start_time + gate_times.gate_duration(gate);
+            }
+
+            scheduled_gates.push(ScheduledGate::new(
+                gate_idx,
+                gate.clone(),
+                start_time,
+                end_time,
+            ));
+
+            Self::update_availability(gate, end_time, &mut qubit_available, num_qubits);
+        }
+
+        let total_duration = qubit_available.iter().cloned().fold(0.0, f64::max);
+
+        CircuitSchedule::new(scheduled_gates, total_duration, num_qubits, qubit_available)
+    }
+
+    /// Find the start time for a two-qubit gate that balances idle time
+    /// against crosstalk with already scheduled gates
+    fn resolve_crosstalk(
+        gate: &Gate,
+        earliest_start: f64,
+        duration: f64,
+        scheduled: &[ScheduledGate],
+        noise_vectors: &[NoiseVector],
+        crosstalk: &CrosstalkMap,
+    ) -> f64 {
+        let qubits = gate.qubits();
+        let mut start_time = earliest_start;
+
+        loop {
+            // Gantree: find_conflicts(start) // 충돌 탐색
+            let conflicts: Vec<(f64, f64)> = scheduled
+                .iter()
+                .filter(|sg| sg.overlaps(start_time, start_time + duration))
+                .filter_map(|sg| {
+                    crosstalk
+                        .conflict_between(&sg.qubits(), &qubits)
+                        .map(|error| (error, sg.end_time_ns))
+                })
+                .collect();
+
+            if conflicts.is_empty() {
+                return start_time;
+            }
+
+            // Gantree: compare_costs(crosstalk,idle) // 비용 비교
+            let crosstalk_error = 1.0 - conflicts.iter().map(|(e, _)| 1.0 - e).product::<f64>();
+            let delayed_start = conflicts.iter().map(|&(_, end)| end).fold(0.0, f64::max);
+            let delay_us = (delayed_start - start_time) / 1000.0;
+            let idle_error: f64 = qubits
+                .iter()
+                .filter_map(|&q| noise_vectors.get(q))
+                .map(|nv| nv.estimate_decoherence(delay_us))
+                .sum();
+
+            if idle_error >= crosstalk_error {
+                return start_time;
+            }
+
+            start_time = delayed_start;
+        }
+    }
+
     /// Schedule a single gate
     fn schedule_gate(gate: &Gate, qubit_available: &[f64], gate_times: &GateTimes) -> (f64, f64) {
         let qubits = gate.qubits();
@@ -111,6 +305,12 @@ impl Scheduler {
         schedule.estimate_decoherence(noise_vectors)
     }
 
+    /// Estimate crosstalk error from schedule
+    /// Gantree: estimate_crosstalk(schedule,CrosstalkMap) -> f64 // 크로스톡 에러
+    pub fn estimate_crosstalk(schedule: &CircuitSchedule, crosstalk: &CrosstalkMap) -> f64 {
+        schedule.estimate_crosstalk_error(crosstalk)
+    }
+
     /// Compute idle error for a single qubit
     /// Gantree: compute_idle_error(idle,t2) // idle 에러
     pub fn compute_idle_error(idle_ns: f64, t2_us: f64) -> f64 {
@@ -135,7 +335,25 @@ impl Scheduler {
         noise_vectors: &[NoiseVector],
         gate_times: &GateTimes,
     ) -> f64 {
-        let schedule = Self::compute_asap(circuit, gate_times);
+        Self::score_circuit_with_crosstalk(circuit, noise_vectors, gate_times, &CrosstalkMap::new())
+    }
+
+    /// Score a circuit including a crosstalk penalty term
+    /// Gantree: score_circuit_with_crosstalk(Circuit,NoiseVector,GateTimes,CrosstalkMap) -> f64 // 크로스톡 점수
+    ///
+    /// The circuit is scheduled crosstalk-aware, and any remaining
+    /// overlaps of conflicting gates reduce the score.
+    pub fn score_circuit_with_crosstalk(
+        circuit: &Circuit,
+        noise_vectors: &[NoiseVector],
+        gate_times: &GateTimes,
+        crosstalk: &CrosstalkMap,
+    ) -> f64 {
+        let schedule = if crosstalk.is_empty() {
+            Self::compute_asap(circuit, gate_times)
+        } else {
+            Self::compute_crosstalk_aware(circuit, gate_times, noise_vectors, crosstalk)
+        };
 
         // Gate fidelity component
         let mut gate_fidelity = 1.0;
@@ -173,8 +391,11 @@ impl Scheduler {
             }
         }
 
+        // Crosstalk component
+        let crosstalk_fidelity = 1.0 - schedule.estimate_crosstalk_error(crosstalk);
+
         // Combined score
-        gate_fidelity * coherence_fidelity * readout_fidelity
+        gate_fidelity * coherence_fidelity * readout_fidelity * crosstalk_fidelity
     }
 
     // ========================================================================
@@ -333,6 +554,105 @@ mod tests {
         assert!(eff_par > eff_seq);
     }
 
+    #[test]
+    fn test_crosstalk_aware_serializes_conflicts() {
+        // CX(0,1) and CX(2,3) run in parallel under plain ASAP
+        let circuit = CircuitBuilder::new(4).cnot(0, 1).cnot(2, 3).build();
+        let times = GateTimes::default();
+        let crosstalk = CrosstalkMap::new().with_pair((0, 1), (2, 3), 0.05);
+        let noise_vectors: Vec<_> = (0..4)
+            .map(|q| NoiseVector::new(q, 100.0, 60.0, 0.001, 0.01, 0.01))
+            .collect();
+
+        let asap = Scheduler::compute_asap(&circuit, &times);
+        assert_eq!(asap.gates()[1].start_time_ns, 0.0);
+
+        let aware =
+            Scheduler::compute_crosstalk_aware(&circuit, &times, &noise_vectors, &crosstalk);
+        let gates = aware.gates();
+        assert!(gates[1].start_time_ns >= gates[0].end_time_ns);
+        assert_eq!(aware.estimate_crosstalk_error(&crosstalk), 0.0);
+    }
+
+    #[test]
+    fn test_crosstalk_aware_keeps_overlap_when_idle_costs_more() {
+        let circuit = CircuitBuilder::new(4).cnot(0, 1).cnot(2, 3).build();
+        let times = GateTimes::default();
+        // Negligible crosstalk, very short T2: delaying is not worth it
+        let crosstalk = CrosstalkMap::new().with_pair((0, 1), (2, 3), 1e-6);
+        let noise_vectors: Vec<_> = (0..4)
+            .map(|q| NoiseVector::new(q, 2.0, 1.0, 0.001, 0.01, 0.01))
+            .collect();
+
+        let aware =
+            Scheduler::compute_crosstalk_aware(&circuit, &times, &noise_vectors, &crosstalk);
+        assert_eq!(aware.gates()[1].start_time_ns, 0.0);
+    }
+
+    #[test]
+    fn test_score_crosstalk_penalty() {
+        let circuit = CircuitBuilder::new(4).cnot(0, 1).cnot(2, 3).build();
+        let times = GateTimes::default();
+        let crosstalk = CrosstalkMap::new().with_pair((0, 1), (2, 3), 1e-6);
+        let noise_vectors: Vec<_> = (0..4)
+            .map(|q| NoiseVector::new(q, 2.0, 1.0, 0.001, 0.01, 0.01))
+            .collect();
+
+        let plain = Scheduler::score_circuit(&circuit, &noise_vectors, &times);
+        let penalized =
+            Scheduler::score_circuit_with_crosstalk(&circuit, &noise_vectors, &times, &crosstalk);
+
+        // Overlap is kept, so the crosstalk term lowers the score
+        assert!(penalized < plain);
+    }
+
+    #[test]
+    fn test_aligned_schedule_on_dt_grid() {
+        let circuit = CircuitBuilder::new(2)
+            .h(0)
+            .rz(0, 0.3)
+            .cnot(0, 1)
+            .measure_all()
+            .build();
+        let times = GateTimes::default();
+        let tc = TimingConstraints::ibm();
+
+        let schedule = Scheduler::compute_aligned(&circuit, &times, &tc);
+
+        for sg in schedule.gates() {
+            let start = sg.start_time_ns / tc.dt_ns;
+            let end = sg.end_time_ns / tc.dt_ns;
+            assert!((start - start.round()).abs() < 1e-6);
+            assert_eq!(start.round() as u64 % tc.pulse_alignment, 0);
+            if sg.duration() > 0.0 {
+                assert_eq!((end - start).round() as u64 % tc.granularity, 0);
+            }
+        }
+
+        // Lengths never shrink relative to the nominal ASAP schedule
+        let asap = Scheduler::compute_asap(&circuit, &times);
+        assert!(schedule.total_duration_ns() >= asap.total_duration_ns());
+    }
+
+    #[test]
+    fn test_aligned_schedule_pads_delays() {
+        // 10-sample H gates become 16 samples; the CX must wait on q1
+        let circuit = CircuitBuilder::new(2).h(0).h(0).cnot(0, 1).build();
+        let times = GateTimes::new(10.0, 20.0, 100.0);
+        let tc = TimingConstraints::new(1.0, 16, 16, 16, 16).unwrap();
+
+        let schedule = Scheduler::compute_aligned(&circuit, &times, &tc);
+        let gates = schedule.gates();
+
+        assert_eq!(gates[0].end_time_ns, 16.0);
+        assert_eq!(gates[2].start_time_ns, 32.0);
+        assert_eq!(gates[2].end_time_ns, 64.0);
+
+        let delays = schedule.delays();
+        assert_eq!(delays.len(), 1);
+        assert_eq!(delays[0], TimeSlot::new(1, 0.0, 32.0));
+    }
+
     #[test]
     fn test_tqqc_circuit_schedule() {
         // TQQC 7-qubit parity circuit
@@ -0,0 +1,204 @@
+//! Hardware timing constraints for NISO
+//!
+//! Gantree: L4_Scheduling → TimingConstraints
+//!
+//! Describes the sample clock of a device (`dt`) and the rules that
+//! instruction lengths and start times must satisfy, so that a schedule
+//! matches what the hardware actually executes.
+
+use niso_core::{NisoError, NisoResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Device timing constraints
+/// Gantree: TimingConstraints // 하드웨어 타이밍 제약
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TimingConstraints {
+    /// Sample time in nanoseconds
+    /// Gantree: dt_ns: f64 // 샘플 시간
+    pub dt_ns: f64,
+
+    /// Instruction lengths must be multiples of this many samples
+    /// Gantree: granularity: u64 // 길이 단위
+    pub granularity: u64,
+
+    /// Minimum length of a non-virtual instruction in samples
+    /// Gantree: min_length: u64 // 최소 길이
+    pub min_length: u64,
+
+    /// Gate start times must be multiples of this many samples
+    /// Gantree: pulse_alignment: u64 // 펄스 정렬
+    pub pulse_alignment: u64,
+
+    /// Measurement start times must be multiples of this many samples
+    /// Gantree: acquire_alignment: u64 // 측정 정렬
+    pub acquire_alignment: u64,
+}
+
+impl TimingConstraints {
+    // ========================================================================
+    // Constructors
+    // ========================================================================
+
+    /// Create timing constraints with validation
+    pub fn new(
+        dt_ns: f64,
+        granularity: u64,
+        min_length: u64,
+        pulse_alignment: u64,
+        acquire_alignment: u64,
+    ) -> NisoResult<Self> {
+        let constraints = Self {
+            dt_ns,
+            granularity,
+            min_length,
+            pulse_alignment,
+            acquire_alignment,
+        };
+        constraints.validate()?;
+        Ok(constraints)
+    }
+
+    /// IBM Quantum Eagle/Heron constraints
+    /// Gantree: ibm() -> Self // IBM 기본값
+    ///
+    /// dt = 2/9 ns, 16-sample granularity and alignment, 64-sample minimum.
+    pub fn ibm() -> Self {
+        Self {
+            dt_ns: 2.0 / 9.0,
+            granularity: 16,
+            min_length: 64,
+            pulse_alignment: 16,
+            acquire_alignment: 16,
+        }
+    }
+
+    /// Unconstrained timing on a given sample clock
+    pub fn unconstrained(dt_ns: f64) -> Self {
+        Self {
+            dt_ns,
+            granularity: 1,
+            min_length: 1,
+            pulse_alignment: 1,
+            acquire_alignment: 1,
+        }
+    }
+
+    /// Validate constraints
+    pub fn validate(&self) -> NisoResult<()> {
+        if !(self.dt_ns > 0.0 && self.dt_ns.is_finite()) {
+            return Err(NisoError::CalibrationError(format!(
+                "dt must be positive: {}",
+                self.dt_ns
+            )));
+        }
+
+        if self.granularity == 0 || self.pulse_alignment == 0 || self.acquire_alignment == 0 {
+            return Err(NisoError::CalibrationError(
+                "granularity and alignments must be >= 1 sample".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Conversions
+    // ========================================================================
+
+    /// Convert samples to nanoseconds
+    pub fn to_ns(&self, samples: u64) -> f64 {
+        samples as f64 * self.dt_ns
+    }
+
+    /// Convert nanoseconds to samples (rounding up)
+    pub fn to_samples(&self, ns: f64) -> u64 {
+        if ns <= 0.0 {
+            return 0;
+        }
+        // Tolerate float noise so exact multiples of dt are not rounded up
+        (ns / self.dt_ns - 1e-9).ceil().max(0.0) as u64
+    }
+
+    /// Instruction length in samples for a nominal duration
+    /// Gantree: duration_samples(ns) -> u64 // 길이 정렬
+    ///
+    /// Zero-length (virtual) instructions stay zero; others are rounded up
+    /// to the granularity and at least `min_length`.
+    pub fn duration_samples(&self, ns: f64) -> u64 {
+        let samples = self.to_samples(ns);
+        if samples == 0 {
+            return 0;
+        }
+        Self::round_up(samples.max(self.min_length), self.granularity)
+    }
+
+    /// Align a start time (in samples) to the next multiple of `alignment`
+    pub fn align(&self, samples: u64, alignment: u64) -> u64 {
+        Self::round_up(samples, alignment)
+    }
+
+    fn round_up(value: u64, multiple: u64) -> u64 {
+        value.div_ceil(multiple) * multiple
+    }
+}
+
+impl Default for TimingConstraints {
+    fn default() -> Self {
+        Self::ibm()
+    }
+}
+
+impl fmt::Display for TimingConstraints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "TimingConstraints(dt={:.4}ns, granularity={}, min_length={}, pulse_align={}, acquire_align={})",
+            self.dt_ns, self.granularity, self.min_length, self.pulse_alignment, self.acquire_alignment
+        )
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_samples() {
+        let tc = TimingConstraints::new(1.0, 16, 64, 16, 16).unwrap();
+
+        assert_eq!(tc.duration_samples(0.0), 0); // virtual
+        assert_eq!(tc.duration_samples(10.0), 64); // min length
+        assert_eq!(tc.duration_samples(65.0), 80); // granularity
+        assert_eq!(tc.duration_samples(96.0), 96); // already aligned
+    }
+
+    #[test]
+    fn test_ibm_dt_exact_multiples() {
+        let tc = TimingConstraints::ibm();
+
+        // 160 samples * 2/9 ns
+        let ns = tc.to_ns(160);
+        assert_eq!(tc.to_samples(ns), 160);
+        assert_eq!(tc.duration_samples(ns), 160);
+    }
+
+    #[test]
+    fn test_align() {
+        let tc = TimingConstraints::ibm();
+
+        assert_eq!(tc.align(0, 16), 0);
+        assert_eq!(tc.align(1, 16), 16);
+        assert_eq!(tc.align(32, 16), 32);
+    }
+
+    #[test]
+    fn test_invalid_constraints() {
+        assert!(TimingConstraints::new(0.0, 16, 64, 16, 16).is_err());
+        assert!(TimingConstraints::new(0.22, 0, 64, 16, 16).is_err());
+    }
+}
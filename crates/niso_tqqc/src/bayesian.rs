@@ -0,0 +1,424 @@
+//! Bayesian optimization of delta
+//!
+//! Gantree: L5_TQQC → BayesianOptimizer
+//!
+//! Fits a Gaussian process with a periodic (sinusoidal) kernel to the
+//! measured (δ, parity) points, using the binomial shot-noise variance of
+//! each parity estimate as per-point observation noise, and picks the
+//! next δ by expected improvement. Parity is 2π-periodic in δ, so the
+//! periodic kernel extrapolates across the wrap-around.
+
+use crate::config::TqqcConfig;
+use crate::optimizer::Optimizer;
+use niso_core::stats::normal_cdf;
+use niso_core::ParamVec;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::f64::consts::PI;
+
+/// Minimum observation variance (numerical jitter)
+const MIN_VARIANCE: f64 = 1e-6;
+
+/// Number of random EI candidates in more than one dimension
+const RANDOM_CANDIDATES: usize = 512;
+
+/// Grid resolution for one-dimensional EI maximization
+const GRID_POINTS: usize = 256;
+
+/// Gaussian process posterior over observed points
+/// Gantree: GaussianProcess // GP 대리모델
+#[derive(Debug, Clone)]
+pub struct GaussianProcess {
+    /// Kernel length scale
+    length_scale: f64,
+
+    /// Kernel signal variance
+    signal_variance: f64,
+
+    /// Observed inputs
+    xs: Vec<ParamVec>,
+
+    /// Observed values
+    ys: Vec<f64>,
+
+    /// Per-point observation noise variance
+    noise: Vec<f64>,
+
+    /// Prior mean (mean of observations)
+    prior_mean: f64,
+
+    /// Cholesky factor of K + diag(noise)
+    chol: Vec<Vec<f64>>,
+
+    /// (K + diag(noise))^-1 (y - m)
+    alpha: Vec<f64>,
+}
+
+impl GaussianProcess {
+    /// Create an empty GP with a periodic kernel
+    pub fn new(length_scale: f64, signal_variance: f64) -> Self {
+        Self {
+            length_scale,
+            signal_variance,
+            xs: Vec::new(),
+            ys: Vec::new(),
+            noise: Vec::new(),
+            prior_mean: 0.0,
+            chol: Vec::new(),
+            alpha: Vec::new(),
+        }
+    }
+
+    /// Periodic kernel: σ² exp(-2 Σ sin²((x_i - x'_i)/2) / ℓ²)
+    /// Gantree: kernel(a,b) -> f64 // 주기 커널
+    pub fn kernel(&self, a: &[f64], b: &[f64]) -> f64 {
+        let s: f64 = a
+            .iter()
+            .zip(b)
+            .map(|(x, y)| ((x - y) / 2.0).sin().powi(2))
+            .sum();
+        self.signal_variance * (-2.0 * s / self.length_scale.powi(2)).exp()
+    }
+
+    /// Add an observation and refit
+    pub fn observe(&mut self, x: ParamVec, y: f64, variance: f64) {
+        self.xs.push(x);
+        self.ys.push(y);
+        self.noise.push(variance.max(MIN_VARIANCE));
+        self.fit();
+    }
+
+    /// Number of observations
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Check if no observations were made
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Observed inputs
+    pub fn inputs(&self) -> &[ParamVec] {
+        &self.xs
+    }
+
+    fn fit(&mut self) {
+        let n = self.xs.len();
+        self.prior_mean = self.ys.iter().sum::<f64>() / n as f64;
+
+        let k: Vec<Vec<f64>> = self
+            .xs
+            .iter()
+            .enumerate()
+            .map(|(i, xi)| {
+                self.xs
+                    .iter()
+                    .enumerate()
+                    .map(|(j, xj)| self.kernel(xi, xj) + if i == j { self.noise[i] } else { 0.0 })
+                    .collect()
+            })
+            .collect();
+
+        self.chol = cholesky(&k);
+        let centered: Vec<f64> = self.ys.iter().map(|y| y - self.prior_mean).collect();
+        let z = solve_lower(&self.chol, &centered);
+        self.alpha = solve_upper(&self.chol, &z);
+    }
+
+    /// Posterior mean and variance at a point
+    /// Gantree: predict(x) -> (mean,var) // 사후 예측
+    pub fn predict(&self, x: &[f64]) -> (f64, f64) {
+        if self.xs.is_empty() {
+            return (0.0, self.signal_variance);
+        }
+
+        let k_star: Vec<f64> = self.xs.iter().map(|xi| self.kernel(xi, x)).collect();
+        let mean = self.prior_mean
+            + k_star
+                .iter()
+                .zip(&self.alpha)
+                .map(|(k, a)| k * a)
+                .sum::<f64>();
+
+        let v = solve_lower(&self.chol, &k_star);
+        let variance = self.kernel(x, x) - v.iter().map(|vi| vi * vi).sum::<f64>();
+
+        (mean, variance.max(0.0))
+    }
+
+    /// Expected improvement over `best` (maximization)
+    /// Gantree: expected_improvement(x,best) -> f64 // EI
+    pub fn expected_improvement(&self, x: &[f64], best: f64, xi: f64) -> f64 {
+        let (mean, variance) = self.predict(x);
+        let sigma = variance.sqrt();
+        if sigma < 1e-12 {
+            return 0.0;
+        }
+
+        let gain = mean - best - xi;
+        let z = gain / sigma;
+        gain * normal_cdf(z) + sigma * normal_pdf(z)
+    }
+}
+
+/// Bayesian optimizer with expected-improvement acquisition
+/// Gantree: BayesianOptimizer // 베이지안 최적화
+///
+/// Proposes one point per round: first the starting point and
+/// `initial_points - 1` random points, then the EI maximizer.
+pub struct BayesianOptimizer {
+    gp: GaussianProcess,
+    num_params: usize,
+    initial: ParamVec,
+    initial_points: usize,
+    bounds: (f64, f64),
+    shots: u64,
+    xi: f64,
+    pending: Option<ParamVec>,
+    rng: StdRng,
+}
+
+impl BayesianOptimizer {
+    /// Create an optimizer over `num_params` deltas measured with `shots`
+    pub fn new(num_params: usize, shots: u64) -> Self {
+        Self {
+            gp: GaussianProcess::new(1.0, 1.0),
+            num_params,
+            initial: vec![0.0; num_params],
+            initial_points: 3,
+            bounds: (-PI, PI),
+            shots,
+            xi: 0.01,
+            pending: None,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Create from a TQQC configuration
+    pub fn from_config(config: &TqqcConfig) -> Self {
+        let mut opt = Self::new(config.num_params(), config.shots);
+        opt.initial[0] = config.delta_init;
+        if let Some(seed) = config.seed {
+            opt = opt.with_seed(seed);
+        }
+        opt
+    }
+
+    /// Set starting parameters
+    pub fn with_initial(mut self, params: ParamVec) -> Self {
+        self.num_params = params.len();
+        self.initial = params;
+        self
+    }
+
+    /// Set kernel hyperparameters
+    pub fn with_kernel(mut self, length_scale: f64, signal_variance: f64) -> Self {
+        self.gp = GaussianProcess::new(length_scale, signal_variance);
+        self
+    }
+
+    /// Set number of initial design points (including the starting point)
+    pub fn with_initial_points(mut self, n: usize) -> Self {
+        self.initial_points = n.max(1);
+        self
+    }
+
+    /// Set search bounds for every parameter
+    pub fn with_bounds(mut self, lo: f64, hi: f64) -> Self {
+        self.bounds = (lo, hi);
+        self
+    }
+
+    /// Set EI exploration margin
+    pub fn with_xi(mut self, xi: f64) -> Self {
+        self.xi = xi;
+        self
+    }
+
+    /// Set seed
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Access the surrogate model
+    pub fn gp(&self) -> &GaussianProcess {
+        &self.gp
+    }
+
+    /// Binomial shot-noise variance of a parity estimate
+    pub fn shot_variance(parity: f64, shots: u64) -> f64 {
+        (1.0 - parity * parity).max(0.0) / shots.max(1) as f64
+    }
+
+    fn random_point(&mut self) -> ParamVec {
+        let (lo, hi) = self.bounds;
+        (0..self.num_params)
+            .map(|_| self.rng.gen_range(lo..hi))
+            .collect()
+    }
+
+    /// Highest posterior mean among observed points
+    fn best_observed(&self) -> Option<(ParamVec, f64)> {
+        self.gp
+            .inputs()
+            .iter()
+            .map(|x| (x.clone(), self.gp.predict(x).0))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    fn maximize_ei(&mut self) -> ParamVec {
+        let best = self.best_observed().map(|(_, m)| m).unwrap_or(0.0);
+        let (lo, hi) = self.bounds;
+
+        let candidates: Vec<ParamVec> = if self.num_params == 1 {
+            (0..GRID_POINTS)
+                .map(|i| vec![lo + (hi - lo) * (i as f64 + 0.5) / GRID_POINTS as f64])
+                .collect()
+        } else {
+            (0..RANDOM_CANDIDATES)
+                .map(|_| self.random_point())
+                .collect()
+        };
+
+        candidates
+            .into_iter()
+            .map(|x| {
+                let ei = self.gp.expected_improvement(&x, best, self.xi);
+                (x, ei)
+            })
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(x, _)| x)
+            .unwrap_or_else(|| self.initial.clone())
+    }
+}
+
+impl Optimizer for BayesianOptimizer {
+    fn name(&self) -> &str {
+        "bayesian"
+    }
+
+    fn propose(&mut self) -> Vec<ParamVec> {
+        let x = if self.gp.is_empty() {
+            self.initial.clone()
+        } else if self.gp.len() < self.initial_points {
+            self.random_point()
+        } else {
+            self.maximize_ei()
+        };
+
+        self.pending = Some(x.clone());
+        vec![x]
+    }
+
+    fn update(&mut self, values: &[f64]) {
+        if let (Some(x), Some(&y)) = (self.pending.take(), values.first()) {
+            let variance = Self::shot_variance(y, self.shots);
+            self.gp.observe(x, y, variance);
+        }
+    }
+
+    fn current(&self) -> ParamVec {
+        self.best_observed()
+            .map(|(x, _)| x)
+            .unwrap_or_else(|| self.initial.clone())
+    }
+}
+
+// ============================================================================
+// Numerical Helpers
+// ============================================================================
+
+/// Standard normal PDF
+fn normal_pdf(z: f64) -> f64 {
+    (-0.5 * z * z).exp() / (2.0 * PI).sqrt()
+}
+
+/// Cholesky factorization of a symmetric positive-definite matrix
+fn cholesky(a: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = a.len();
+    let mut l = vec![vec![0.0; n]; n];
+
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+            if i == j {
+                l[i][j] = (a[i][i] - sum).max(MIN_VARIANCE).sqrt();
+            } else {
+                l[i][j] = (a[i][j] - sum) / l[j][j];
+            }
+        }
+    }
+
+    l
+}
+
+/// Solve L x = b (forward substitution)
+fn solve_lower(l: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n = b.len();
+    let mut x = vec![0.0; n];
+    for i in 0..n {
+        let sum: f64 = (0..i).map(|k| l[i][k] * x[k]).sum();
+        x[i] = (b[i] - sum) / l[i][i];
+    }
+    x
+}
+
+/// Solve Lᵀ x = b (backward substitution)
+fn solve_upper(l: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let n = b.len();
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let sum: f64 = (i + 1..n).map(|k| l[k][i] * x[k]).sum();
+        x[i] = (b[i] - sum) / l[i][i];
+    }
+    x
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_cdf() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((normal_cdf(1.96) - 0.975).abs() < 1e-4);
+        assert!((normal_cdf(-1.96) - 0.025).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_gp_interpolates_and_is_periodic() {
+        let mut gp = GaussianProcess::new(1.0, 1.0);
+        gp.observe(vec![0.0], 1.0, 1e-6);
+        gp.observe(vec![1.0], 0.5, 1e-6);
+
+        let (m0, v0) = gp.predict(&[0.0]);
+        assert!((m0 - 1.0).abs() < 1e-3);
+        assert!(v0 < 1e-3);
+
+        // δ and δ + 2π are the same point
+        let (m_wrap, _) = gp.predict(&[2.0 * PI]);
+        assert!((m_wrap - m0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_bayesian_finds_cosine_peak() {
+        // Parity ≈ 0.9 cos(δ + 0.8) with small observation noise
+        let mut opt = BayesianOptimizer::new(1, 8192).with_seed(11);
+        let mut rng = StdRng::seed_from_u64(3);
+
+        for _ in 0..12 {
+            let x = opt.propose();
+            let y = 0.9 * (x[0][0] + 0.8).cos() + rng.gen_range(-0.01..0.01);
+            opt.update(&[y]);
+        }
+
+        let best = opt.current();
+        assert!((best[0] + 0.8).abs() < 0.15, "best = {:?}", best);
+    }
+}
@@ -4,6 +4,7 @@
 //!
 //! Configuration for the TQQC optimization engine.
 
+use crate::stat_test::{self, StatisticalTest};
 use niso_core::{tqqc, BasisString, EntanglerType};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -20,6 +21,32 @@ pub enum SigMode {
     /// Adaptive threshold based on noise/shots
     /// Gantree: Adaptive // 적응형
     Adaptive,
+
+    /// Wald's sequential probability ratio test on the ± parity difference
+    /// Gantree: Sequential // SPRT
+    Sequential,
+
+    /// Beta-posterior probability that one direction beats the other
+    /// Gantree: Bayesian // 베이지안
+    Bayesian,
+}
+
+/// Multiple-comparison correction across one optimization run
+/// Gantree: Correction // 다중 비교 보정
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Correction {
+    /// Every comparison at the nominal level
+    /// Gantree: None // 없음
+    #[default]
+    None,
+
+    /// α / m for each of the m = points × inner_max comparisons
+    /// Gantree: Bonferroni // 본페로니
+    Bonferroni,
+
+    /// α / (m - r) after r significant moves (online step-down Holm)
+    /// Gantree: Holm // 홈
+    Holm,
 }
 
 /// Delta accumulation mode
@@ -36,6 +63,84 @@ pub enum DeltaMode {
     Reset,
 }
 
+/// Delta parameterization
+/// Gantree: ParamMode // 파라미터 모드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ParamMode {
+    /// Single delta on Rz(θ+δ) of qubit 0
+    /// Gantree: Scalar // 단일 델타
+    #[default]
+    Scalar,
+
+    /// One delta per qubit (Rz(δ_q) on every qubit)
+    /// Gantree: PerQubit // 큐비트별 델타
+    PerQubit,
+}
+
+/// Probing strategy for multi-parameter search
+/// Gantree: SearchMode // 탐색 모드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SearchMode {
+    /// Probe one parameter per outer iteration (round robin)
+    /// Gantree: Coordinate // 좌표별
+    #[default]
+    Coordinate,
+
+    /// Probe all parameters at once along a random ±1 direction
+    /// Gantree: Simultaneous // 동시
+    Simultaneous,
+}
+
+/// Adaptive shot allocation for the ±δ pair
+/// Gantree: AdaptiveShots // 적응형 샷 할당
+///
+/// Each pair starts at `initial` shots per side; while the z-score lies
+/// within `band` of `z_critical` another `increment` shots are added to
+/// both sides, up to `max` per side. Decisive pairs stop early.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveShots {
+    /// Starting shots per evaluation
+    pub initial: u64,
+
+    /// Shots added per evaluation while the test is undecided
+    pub increment: u64,
+
+    /// Per-evaluation shot cap
+    pub max: u64,
+
+    /// Half-width of the undecided z band around `z_critical`
+    pub band: f64,
+}
+
+impl AdaptiveShots {
+    /// Create with explicit shot counts and the default band (1.0)
+    pub fn new(initial: u64, increment: u64, max: u64) -> Self {
+        Self {
+            initial,
+            increment,
+            max,
+            band: 1.0,
+        }
+    }
+
+    /// Scale down from a fixed shot count (start at 1/8, cap at `shots`)
+    pub fn from_shots(shots: u64) -> Self {
+        let initial = (shots / 8).max(1);
+        Self::new(initial, initial, shots)
+    }
+
+    /// Set the undecided band half-width
+    pub fn with_band(mut self, band: f64) -> Self {
+        self.band = band;
+        self
+    }
+
+    /// Whether a z-score is too close to `z_critical` to decide
+    pub fn is_undecided(&self, z_score: f64, z_critical: f64) -> bool {
+        (z_score - z_critical).abs() < self.band
+    }
+}
+
 /// TQQC configuration
 /// Gantree: TqqcConfig // 설정
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -80,6 +185,21 @@ pub struct TqqcConfig {
     /// Gantree: sig_level: f64 // 유의 수준
     pub sig_level: f64,
 
+    /// Type-II error for `SigMode::Sequential`
+    /// Gantree: sig_type_ii: f64 // 제2종 오류 (0.2)
+    #[serde(default = "default_sig_type_ii")]
+    pub sig_type_ii: f64,
+
+    /// Minimum parity difference worth detecting (`SigMode::Sequential`)
+    /// Gantree: sig_effect: f64 // 효과 크기 (0.05)
+    #[serde(default = "default_sig_effect")]
+    pub sig_effect: f64,
+
+    /// Multiple-comparison correction over points × inner_max tests
+    /// Gantree: correction: Correction // none/bonferroni/holm
+    #[serde(default)]
+    pub correction: Correction,
+
     /// Delta accumulation mode
     /// Gantree: delta_mode: DeltaMode // track/reset
     pub delta_mode: DeltaMode,
@@ -101,6 +221,26 @@ pub struct TqqcConfig {
     /// Random seed
     /// Gantree: seed: Option<u64> // 시드
     pub seed: Option<u64>,
+
+    /// Delta parameterization
+    /// Gantree: param_mode: ParamMode // scalar/per-qubit
+    #[serde(default)]
+    pub param_mode: ParamMode,
+
+    /// Multi-parameter probing strategy
+    /// Gantree: search_mode: SearchMode // coordinate/simultaneous
+    #[serde(default)]
+    pub search_mode: SearchMode,
+
+    /// Adaptive shot allocation for ±δ pairs (`None` = fixed `shots`)
+    /// Gantree: adaptive_shots: Option<AdaptiveShots> // 적응형 샷
+    #[serde(default)]
+    pub adaptive_shots: Option<AdaptiveShots>,
+
+    /// Backend jobs an inner-loop batch may keep in flight at once
+    /// Gantree: max_in_flight: usize // 동시 작업 수
+    #[serde(default = "default_max_in_flight")]
+    pub max_in_flight: usize,
 }
 
 impl TqqcConfig {
@@ -122,12 +262,19 @@ impl TqqcConfig {
             use_statistical_test: false,
             sig_mode: SigMode::Fixed,
             sig_level: 0.95,
+            sig_type_ii: default_sig_type_ii(),
+            sig_effect: default_sig_effect(),
+            correction: Correction::None,
             delta_mode: DeltaMode::Track,
             basis: BasisString::all_x(7),
             entangler: EntanglerType::Cx,
             theta_init: 0.0,
             delta_init: 0.0,
             seed: None,
+            param_mode: ParamMode::Scalar,
+            search_mode: SearchMode::Coordinate,
+            adaptive_shots: None,
+            max_in_flight: default_max_in_flight(),
         }
     }
 
@@ -214,6 +361,19 @@ impl TqqcConfig {
         self
     }
 
+    /// Set sequential-test type-II error and minimum effect size
+    pub fn with_sequential_params(mut self, type_ii: f64, effect: f64) -> Self {
+        self.sig_type_ii = type_ii;
+        self.sig_effect = effect;
+        self
+    }
+
+    /// Set multiple-comparison correction
+    pub fn with_correction(mut self, correction: Correction) -> Self {
+        self.correction = correction;
+        self
+    }
+
     /// Set delta mode
     pub fn with_delta_mode(mut self, mode: DeltaMode) -> Self {
         self.delta_mode = mode;
@@ -250,10 +410,53 @@ impl TqqcConfig {
         self
     }
 
+    /// Set delta parameterization
+    pub fn with_param_mode(mut self, mode: ParamMode) -> Self {
+        self.param_mode = mode;
+        self
+    }
+
+    /// Set multi-parameter probing strategy
+    pub fn with_search_mode(mut self, mode: SearchMode) -> Self {
+        self.search_mode = mode;
+        self
+    }
+
+    /// Enable adaptive shot allocation
+    pub fn with_adaptive_shots(mut self, adaptive: AdaptiveShots) -> Self {
+        self.adaptive_shots = Some(adaptive);
+        self
+    }
+
+    /// Split inner-loop batches into up to `jobs` concurrent backend jobs
+    ///
+    /// Overlaps queue waits on hardware (see `Backend::execute_concurrent`);
+    /// ±δ pairs always stay in the same job.
+    pub fn with_max_in_flight(mut self, jobs: usize) -> Self {
+        self.max_in_flight = jobs;
+        self
+    }
+
     // ========================================================================
     // Derived Values
     // ========================================================================
 
+    /// Number of delta parameters being optimized
+    pub fn num_params(&self) -> usize {
+        match self.param_mode {
+            ParamMode::Scalar => 1,
+            ParamMode::PerQubit => self.qubits,
+        }
+    }
+
+    /// Statistical test described by the significance settings
+    pub fn stat_test(&self) -> StatisticalTest {
+        StatisticalTest::new(self.sig_mode, self.sig_level)
+            .with_type_ii(self.sig_type_ii)
+            .with_effect(self.sig_effect)
+            .with_correction(self.correction, self.points * self.inner_max)
+    }
+
     /// Get convergence threshold for this configuration
     pub fn threshold(&self) -> f64 {
         tqqc::threshold_for_qubits(self.qubits)
@@ -317,6 +520,30 @@ impl TqqcConfig {
             ));
         }
 
+        if self.sig_type_ii <= 0.0 || self.sig_type_ii >= 0.5 {
+            return Err(format!(
+                "sig_type_ii must be in (0, 0.5), got {}",
+                self.sig_type_ii
+            ));
+        }
+
+        if self.sig_effect <= 0.0 {
+            return Err("sig_effect must be > 0".to_string());
+        }
+
+        if let Some(adaptive) = &self.adaptive_shots {
+            if adaptive.initial == 0 || adaptive.initial > adaptive.max {
+                return Err(format!(
+                    "adaptive shots must satisfy 0 < initial <= max, got {} / {}",
+                    adaptive.initial, adaptive.max
+                ));
+            }
+        }
+
+        if self.max_in_flight == 0 {
+            return Err("max_in_flight must be > 0".to_string());
+        }
+
         if self.basis.len() != self.qubits {
             return Err(format!(
                 "basis length {} doesn't match qubits {}",
@@ -329,6 +556,18 @@ impl TqqcConfig {
     }
 }
 
+fn default_sig_type_ii() -> f64 {
+    stat_test::DEFAULT_TYPE_II
+}
+
+fn default_sig_effect() -> f64 {
+    stat_test::DEFAULT_EFFECT
+}
+
+fn default_max_in_flight() -> usize {
+    1
+}
+
 impl Default for TqqcConfig {
     fn default() -> Self {
         Self::default_7q()
@@ -381,6 +620,40 @@ mod tests {
         assert_eq!(config.seed, Some(42));
     }
 
+    #[test]
+    fn test_num_params() {
+        let config = TqqcConfig::default_5q();
+        assert_eq!(config.num_params(), 1);
+
+        let config = config.with_param_mode(ParamMode::PerQubit);
+        assert_eq!(config.num_params(), 5);
+        assert_eq!(config.search_mode, SearchMode::Coordinate);
+    }
+
+    #[test]
+    fn test_adaptive_shots() {
+        let adaptive = AdaptiveShots::from_shots(8192);
+        assert_eq!(adaptive.initial, 1024);
+        assert_eq!(adaptive.max, 8192);
+        assert!(adaptive.is_undecided(1.5, 1.96));
+        assert!(!adaptive.is_undecided(4.0, 1.96));
+
+        let config = TqqcConfig::default_5q().with_adaptive_shots(adaptive);
+        assert!(config.validate().is_ok());
+
+        let config = TqqcConfig::default_5q().with_adaptive_shots(AdaptiveShots::new(0, 1, 10));
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_max_in_flight() {
+        let config = TqqcConfig::default_5q();
+        assert_eq!(config.max_in_flight, 1);
+
+        assert!(config.clone().with_max_in_flight(4).validate().is_ok());
+        assert!(config.with_max_in_flight(0).validate().is_err());
+    }
+
     #[test]
     fn test_threshold() {
         let config_7q = TqqcConfig::default_7q();
@@ -5,23 +5,33 @@
 //! Main TQQC optimization engine implementing delta search
 //! with dynamic inner loop and statistical testing.
 
-use crate::config::{DeltaMode, TqqcConfig};
+use crate::bayesian::BayesianOptimizer;
+use crate::config::{DeltaMode, SearchMode, TqqcConfig};
 use crate::convergence::{Convergence, DynamicInner};
-use crate::parity::Parity;
+use crate::objective::{Objective, ParityObjective};
+use crate::observer::{EvaluationEvent, OptimizationObserver};
+use crate::optimizer::Optimizer;
 use crate::stat_test::{Direction, StatisticalTest, TestResult};
+use crate::state::{RngState, TqqcState};
 use niso_backend::Backend;
-use niso_core::NisoResult;
+use niso_core::{Counts, NisoError, NisoResult, ParamVec};
 use rand::prelude::*;
-use rand::rngs::StdRng;
+use rand_chacha::ChaCha12Rng;
 use serde::{Deserialize, Serialize};
+use std::ops::ControlFlow;
+use std::path::Path;
 
 /// TQQC optimization result
 /// Gantree: TqqcResult // 최적화 결과
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TqqcResult {
-    /// Optimized delta value
+    /// Optimized delta value (first parameter in multi-parameter mode)
     pub delta_opt: f64,
 
+    /// Optimized delta vector (one entry per parameter)
+    #[serde(default)]
+    pub deltas_opt: ParamVec,
+
     /// Baseline parity (delta=0)
     pub parity_baseline: f64,
 
@@ -48,6 +58,14 @@ pub struct TqqcResult {
 
     /// Iteration history
     pub history: Vec<IterationRecord>,
+
+    /// Total shots executed (baseline and all ±δ evaluations)
+    #[serde(default)]
+    pub total_shots: u64,
+
+    /// Whether an observer aborted the run
+    #[serde(default)]
+    pub aborted: bool,
 }
 
 impl TqqcResult {
@@ -64,6 +82,17 @@ impl TqqcResult {
         self.improvement > 0.0
     }
 
+    /// Measured shot saving relative to a reference shot count
+    ///
+    /// `reference_shots` is what a fixed-shot run would have spent, e.g.
+    /// the `total_shots` of the same run without adaptive allocation.
+    pub fn shot_saving(&self, reference_shots: u64) -> f64 {
+        if reference_shots == 0 {
+            return 0.0;
+        }
+        1.0 - self.total_shots as f64 / reference_shots as f64
+    }
+
     /// Calculate k_estimated (early stop efficiency)
     pub fn k_estimated(&self, max_points: usize) -> f64 {
         if !self.early_stopped || self.iterations >= max_points {
@@ -88,9 +117,17 @@ pub struct IterationRecord {
     /// Iteration number
     pub iteration: usize,
 
-    /// Delta value at this iteration
+    /// Delta value at this iteration (of the moved parameter, if any)
     pub delta: f64,
 
+    /// Parameter probed at this iteration (`None` = all simultaneously)
+    #[serde(default)]
+    pub param_index: Option<usize>,
+
+    /// Full delta vector after this iteration
+    #[serde(default)]
+    pub deltas: ParamVec,
+
     /// Parity at +delta
     pub parity_plus: f64,
 
@@ -111,6 +148,10 @@ pub struct IterationRecord {
 
     /// Was significant
     pub is_significant: bool,
+
+    /// Shots executed during this iteration
+    #[serde(default)]
+    pub shots: u64,
 }
 
 /// TQQC optimization engine
@@ -132,7 +173,16 @@ pub struct TqqcEngine<B: Backend> {
     stat_test: StatisticalTest,
 
     /// Random generator
-    rng: StdRng,
+    rng: ChaCha12Rng,
+
+    /// Objective being maximized (parity ansatz by default)
+    objective: Box<dyn Objective>,
+
+    /// Shots executed since the current run started
+    shots_used: u64,
+
+    /// Progress observers
+    observers: Vec<Box<dyn OptimizationObserver>>,
 }
 
 impl<B: Backend> TqqcEngine<B> {
@@ -144,13 +194,15 @@ impl<B: Backend> TqqcEngine<B> {
     pub fn new(config: TqqcConfig, backend: B) -> Self {
         let convergence = Convergence::from_noise(config.qubits, config.noise);
         let dynamic_inner = DynamicInner::new(config.inner_max, 0.9);
-        let stat_test = StatisticalTest::new(config.sig_mode, config.sig_level);
+        let stat_test = config.stat_test();
 
         let rng = match config.seed {
-            Some(seed) => StdRng::seed_from_u64(seed),
-            None => StdRng::from_entropy(),
+            Some(seed) => ChaCha12Rng::seed_from_u64(seed),
+            None => ChaCha12Rng::from_entropy(),
         };
 
+        let objective = Box::new(ParityObjective::new(config.clone()));
+
         Self {
             config,
             backend,
@@ -158,196 +210,648 @@ impl<B: Backend> TqqcEngine<B> {
             dynamic_inner,
             stat_test,
             rng,
+            objective,
+            shots_used: 0,
+            observers: Vec::new(),
         }
     }
 
+    /// Replace the objective (e.g. a user-supplied parameterized circuit)
+    /// Gantree: with_objective(self,obj) -> Self // 목적 함수 설정
+    pub fn with_objective(mut self, objective: impl Objective + 'static) -> Self {
+        self.objective = Box::new(objective);
+        self
+    }
+
+    /// Attach a progress observer
+    /// Gantree: with_observer(self,obs) -> Self // 관찰자 등록
+    pub fn with_observer(mut self, observer: impl OptimizationObserver + 'static) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    /// Attach a boxed progress observer
+    pub fn add_observer(&mut self, observer: Box<dyn OptimizationObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// Detach and return all observers
+    pub fn take_observers(&mut self) -> Vec<Box<dyn OptimizationObserver>> {
+        std::mem::take(&mut self.observers)
+    }
+
     // ========================================================================
     // Main Optimization
     // ========================================================================
 
     /// Run TQQC optimization
+    ///
+    /// In `ParamMode::PerQubit` the search runs over a delta vector, probing
+    /// one coordinate per outer iteration or all coordinates at once
+    /// depending on `SearchMode`.
     pub fn optimize(&mut self) -> NisoResult<TqqcResult> {
-        let theta = self.config.theta_init;
-        let mut delta = self.config.delta_init;
-        let mut last_improve = 0.0;
-        let mut total_inner = 0;
+        let state = self.start()?;
+        self.resume(state)
+    }
+
+    /// Run TQQC optimization, saving a checkpoint after every iteration
+    /// Gantree: optimize_with_checkpoint(&mut,path) -> TqqcResult // 체크포인트 실행
+    pub fn optimize_with_checkpoint(&mut self, path: impl AsRef<Path>) -> NisoResult<TqqcResult> {
+        let path = path.as_ref();
+        let mut state = self.start()?;
+        state.save(path)?;
+
+        while !state.finished {
+            self.step(&mut state)?;
+            state.save(path)?;
+        }
+
+        Ok(self.finish(&state))
+    }
+
+    /// Continue an optimization from a saved state
+    /// Gantree: resume(&mut,state) -> TqqcResult // 재개
+    ///
+    /// With the same configuration, seed and backend the result equals
+    /// that of an uninterrupted run.
+    pub fn resume(&mut self, mut state: TqqcState) -> NisoResult<TqqcResult> {
+        self.restore(&state);
+        while !state.finished {
+            self.step(&mut state)?;
+        }
+        Ok(self.finish(&state))
+    }
+
+    /// Measure the baseline and create the initial state
+    /// Gantree: start(&mut) -> TqqcState // 시작
+    pub fn start(&mut self) -> NisoResult<TqqcState> {
+        self.shots_used = 0;
 
         // Baseline measurement (delta=0)
-        let parity_baseline = self.measure_parity(theta, 0.0)?;
-        let mut parity_current = parity_baseline;
+        let parity_baseline = self.measure_params(&self.objective.baseline_params())?;
+        let aborted = self.notify(|o| o.on_baseline(parity_baseline)).is_break();
 
-        // Tracking
-        let mut early_stopped = false;
-        let mut ties_count = 0;
-        let mut significant_moves = 0;
-        let mut history = Vec::with_capacity(self.config.points);
+        Ok(TqqcState {
+            iteration: 0,
+            deltas: self.objective.initial_params(),
+            parity_baseline,
+            parity_current: parity_baseline,
+            last_improve: 0.0,
+            total_inner: 0,
+            ties_count: 0,
+            significant_moves: 0,
+            early_stopped: false,
+            finished: aborted || self.config.points == 0,
+            aborted,
+            shots_used: self.shots_used,
+            history: Vec::with_capacity(self.config.points),
+            convergence: self.convergence.clone(),
+            rng: RngState::capture(&self.rng),
+        })
+    }
 
-        // Main optimization loop
-        for iteration in 0..self.config.points {
-            // Dynamic inner count
-            let inner_count = if self.config.dynamic_inner {
-                self.dynamic_inner
-                    .compute_count(last_improve, self.convergence.threshold())
-            } else {
-                1
-            };
+    /// Run one outer iteration and update `state`
+    /// Gantree: step(&mut,state) -> Result // 외부 반복 1회
+    ///
+    /// `state` must come from `start` or a previous `step` on this engine,
+    /// or have been passed to `resume`.
+    pub fn step(&mut self, state: &mut TqqcState) -> NisoResult<()> {
+        if state.finished {
+            return Ok(());
+        }
+
+        let num_params = self.objective.num_params();
+        let iteration = state.iteration;
 
-            let mut best_delta = delta;
-            let mut best_parity = parity_current;
-            let mut record_parity_plus = 0.0;
-            let mut record_parity_minus = 0.0;
-            let mut record_direction = None;
-            let mut record_significant = false;
+        // Dynamic inner count
+        let inner_count = if self.config.dynamic_inner {
+            self.dynamic_inner
+                .compute_count(state.last_improve, self.convergence.threshold())
+        } else {
+            1
+        };
 
-            // Inner loop
-            for j in 0..inner_count {
-                let step_j = self.dynamic_inner.compute_step(j, self.config.step_amp);
+        // Probe direction for this iteration
+        let (param_index, probe) = self.probe_direction(iteration, num_params);
+        let shots_before = self.shots_used;
+
+        let mut best_deltas = state.deltas.clone();
+        let mut best_parity = state.parity_current;
+        let mut record_parity_plus = 0.0;
+        let mut record_parity_minus = 0.0;
+        let mut record_direction = None;
+        let mut record_significant = false;
+        let mut inner_run = 0;
+
+        // Inner candidates all offset the same deltas, so with fixed shots
+        // every ±δ pair of the iteration goes out as one batch
+        let steps: Vec<f64> = (0..inner_count)
+            .map(|j| self.dynamic_inner.compute_step(j, self.config.step_amp))
+            .collect();
+        let prefetched = if self.config.adaptive_shots.is_none() {
+            Some(self.measure_inner_pairs(&state.deltas, &probe, &steps)?)
+        } else {
+            None
+        };
 
-                // Evaluate +delta and -delta
-                let parity_plus = self.measure_parity(theta, delta + step_j)?;
-                let parity_minus = self.measure_parity(theta, delta - step_j)?;
+        // Inner loop
+        for (j, &step_j) in steps.iter().enumerate() {
+            // Evaluate +delta and -delta
+            let deltas_plus = Self::offset(&state.deltas, &probe, step_j);
+            let deltas_minus = Self::offset(&state.deltas, &probe, -step_j);
+            let test = self.stat_test.for_comparison(state.significant_moves);
+            let (parity_plus, parity_minus, pair_shots) = match &prefetched {
+                Some(values) => (values[j].0, values[j].1, self.config.shots),
+                None => self.measure_pair(&test, &deltas_plus, &deltas_minus)?,
+            };
 
-                if j == 0 {
-                    record_parity_plus = parity_plus;
-                    record_parity_minus = parity_minus;
-                }
+            if j == 0 {
+                record_parity_plus = parity_plus;
+                record_parity_minus = parity_minus;
+            }
 
-                // Statistical test
-                let (candidate_delta, candidate_parity, direction, _is_significant) =
-                    if self.config.use_statistical_test {
-                        let test_result = self.stat_test.test(
-                            parity_plus,
-                            parity_minus,
-                            self.config.shots,
-                            self.config.noise,
-                        );
-
-                        if test_result.is_significant {
-                            significant_moves += 1;
-                            record_significant = true;
-                        }
-
-                        self.select_direction(
-                            delta,
-                            step_j,
-                            parity_plus,
-                            parity_minus,
-                            parity_current,
-                            &test_result,
-                        )
+            inner_run += 1;
+            let bound = self.objective.value_bound();
+            let z_score =
+                test.compute_z_from_parity(parity_plus / bound, parity_minus / bound, pair_shots);
+
+            // Statistical test
+            let (candidate_deltas, candidate_parity, direction, is_significant) =
+                if self.config.use_statistical_test {
+                    let test_result = test.test(
+                        parity_plus / bound,
+                        parity_minus / bound,
+                        pair_shots,
+                        self.config.noise,
+                    );
+
+                    if test_result.is_significant {
+                        state.significant_moves += 1;
+                        record_significant = true;
+                    }
+
+                    self.select_direction(
+                        &state.deltas,
+                        deltas_plus,
+                        deltas_minus,
+                        parity_plus,
+                        parity_minus,
+                        state.parity_current,
+                        &test_result,
+                    )
+                } else {
+                    // No statistical test: always select better
+                    if parity_plus > parity_minus {
+                        (deltas_plus, parity_plus, Some(Direction::Plus), true)
                     } else {
-                        // No statistical test: always select better
-                        if parity_plus > parity_minus {
-                            (delta + step_j, parity_plus, Some(Direction::Plus), true)
-                        } else {
-                            (delta - step_j, parity_minus, Some(Direction::Minus), true)
-                        }
-                    };
-
-                if j == 0 {
-                    record_direction = direction;
-                }
+                        (deltas_minus, parity_minus, Some(Direction::Minus), true)
+                    }
+                };
 
-                // Update best
-                if candidate_parity > best_parity {
-                    best_delta = candidate_delta;
-                    best_parity = candidate_parity;
-                }
+            if j == 0 {
+                record_direction = direction;
             }
 
-            // Apply best from inner loop
-            let improvement = best_parity - parity_current;
+            // Update best
+            if candidate_parity > best_parity {
+                best_deltas = candidate_deltas;
+                best_parity = candidate_parity;
+            }
 
-            // Update delta based on mode
-            delta = match self.config.delta_mode {
-                DeltaMode::Track => best_delta,
-                DeltaMode::Reset => best_delta - delta, // Relative change
+            let event = EvaluationEvent {
+                iteration,
+                inner: j,
+                step: step_j,
+                parity_plus,
+                parity_minus,
+                shots: pair_shots,
+                z_score,
+                is_significant,
             };
+            if self.notify(|o| o.on_evaluation(&event)).is_break() {
+                state.aborted = true;
+                break;
+            }
+        }
 
-            parity_current = best_parity;
-            last_improve = improvement;
-            total_inner += inner_count;
+        // Apply best from inner loop
+        let improvement = best_parity - state.parity_current;
+
+        // Update deltas based on mode
+        state.deltas = match self.config.delta_mode {
+            DeltaMode::Track => best_deltas,
+            DeltaMode::Reset => best_deltas
+                .iter()
+                .zip(&state.deltas)
+                .map(|(best, current)| best - current) // Relative change
+                .collect(),
+        };
+
+        state.parity_current = best_parity;
+        state.last_improve = improvement;
+        state.total_inner += inner_run;
+
+        // Check for ties
+        if (record_parity_plus - record_parity_minus).abs() < 1e-9 {
+            state.ties_count += 1;
+        }
 
-            // Check for ties
-            if (record_parity_plus - record_parity_minus).abs() < 1e-9 {
-                ties_count += 1;
+        // Record iteration
+        state.history.push(IterationRecord {
+            iteration,
+            delta: state.deltas[param_index.unwrap_or(0)],
+            param_index,
+            deltas: state.deltas.clone(),
+            parity_plus: record_parity_plus,
+            parity_minus: record_parity_minus,
+            parity_selected: state.parity_current,
+            improvement,
+            inner_count: inner_run,
+            direction: record_direction,
+            is_significant: record_significant,
+            shots: self.shots_used - shots_before,
+        });
+        if let Some(record) = state.history.last() {
+            if self.notify(|o| o.on_iteration(record)).is_break() {
+                state.aborted = true;
             }
+        }
+
+        // Convergence check
+        self.convergence.push(improvement);
+        state.iteration += 1;
+        if state.aborted {
+            state.finished = true;
+        } else if self.config.dynamic_inner && self.convergence.check() {
+            state.early_stopped = true;
+            state.finished = true;
+            for observer in &mut self.observers {
+                observer.on_converged(iteration);
+            }
+        } else if state.iteration >= self.config.points {
+            state.finished = true;
+        }
 
-            // Record iteration
+        state.shots_used = self.shots_used;
+        state.convergence = self.convergence.clone();
+        state.rng = RngState::capture(&self.rng);
+        Ok(())
+    }
+
+    /// Call every observer; `Break` if any of them asked to stop
+    fn notify(
+        &mut self,
+        mut f: impl FnMut(&mut dyn OptimizationObserver) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        let mut flow = ControlFlow::Continue(());
+        for observer in &mut self.observers {
+            if f(observer.as_mut()).is_break() {
+                flow = ControlFlow::Break(());
+            }
+        }
+        flow
+    }
+
+    /// Build the result and report it to the observers
+    fn finish(&mut self, state: &TqqcState) -> TqqcResult {
+        let result = state.to_result();
+        for observer in &mut self.observers {
+            observer.on_finished(&result);
+        }
+        result
+    }
+
+    /// Load engine-side state (convergence, RNG, shot counter)
+    fn restore(&mut self, state: &TqqcState) {
+        self.convergence = state.convergence.clone();
+        self.rng = state.rng.restore();
+        self.shots_used = state.shots_used;
+    }
+
+    /// Run TQQC with a pluggable optimizer strategy
+    /// Gantree: optimize_with(&mut,opt) -> TqqcResult // 전략 최적화
+    ///
+    /// Each outer iteration is one propose/update round of `optimizer`;
+    /// the round's proposals run as one backend batch.
+    /// Records keep the `TqqcResult` layout: `parity_plus`/`parity_minus`
+    /// hold the first two evaluations of the round, `parity_selected` the
+    /// best parity seen so far. The final parity is a fresh evaluation of
+    /// the recommended parameters.
+    pub fn optimize_with(&mut self, optimizer: &mut dyn Optimizer) -> NisoResult<TqqcResult> {
+        self.shots_used = 0;
+        let parity_baseline = self.measure_params(&self.objective.baseline_params())?;
+        let mut parity_best = parity_baseline;
+
+        let mut aborted = self.notify(|o| o.on_baseline(parity_baseline)).is_break();
+        let mut early_stopped = false;
+        let mut total_inner = 0;
+        let mut history = Vec::with_capacity(self.config.points);
+
+        for iteration in 0..self.config.points {
+            if aborted {
+                break;
+            }
+            if optimizer.is_done() {
+                early_stopped = true;
+                break;
+            }
+
+            let proposals = optimizer.propose();
+            if proposals.is_empty() {
+                break;
+            }
+
+            let shots_before = self.shots_used;
+            let values: Vec<f64> = self
+                .measure_batch(&proposals, self.config.shots)?
+                .iter()
+                .map(|counts| self.objective.evaluate(counts))
+                .collect();
+            optimizer.update(&values);
+
+            let round_best = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let improvement = (round_best - parity_best).max(0.0);
+            parity_best = parity_best.max(round_best);
+            total_inner += proposals.len();
+
+            let deltas = optimizer.current();
             history.push(IterationRecord {
                 iteration,
-                delta,
-                parity_plus: record_parity_plus,
-                parity_minus: record_parity_minus,
-                parity_selected: parity_current,
+                delta: deltas.first().copied().unwrap_or(0.0),
+                param_index: None,
+                deltas,
+                parity_plus: values[0],
+                parity_minus: values.get(1).copied().unwrap_or(values[0]),
+                parity_selected: parity_best,
                 improvement,
-                inner_count,
-                direction: record_direction,
-                is_significant: record_significant,
+                inner_count: proposals.len(),
+                direction: None,
+                is_significant: false,
+                shots: self.shots_used - shots_before,
             });
+            if let Some(record) = history.last() {
+                aborted = self.notify(|o| o.on_iteration(record)).is_break();
+            }
 
-            // Convergence check
             self.convergence.push(improvement);
-            if self.config.dynamic_inner && self.convergence.check() {
+            if !aborted && self.config.dynamic_inner && self.convergence.check() {
                 early_stopped = true;
+                for observer in &mut self.observers {
+                    observer.on_converged(iteration);
+                }
                 break;
             }
         }
 
-        Ok(TqqcResult {
-            delta_opt: delta,
+        let deltas_opt = optimizer.current();
+        let parity_final = self.measure_params(&deltas_opt)?;
+
+        let result = TqqcResult {
+            delta_opt: deltas_opt.first().copied().unwrap_or(0.0),
+            deltas_opt,
             parity_baseline,
-            parity_final: parity_current,
-            improvement: parity_current - parity_baseline,
+            parity_final,
+            improvement: parity_final - parity_baseline,
             iterations: history.len(),
             early_stopped,
-            ties_count,
-            significant_moves,
+            ties_count: 0,
+            significant_moves: 0,
             total_inner_iterations: total_inner,
             history,
-        })
+            total_shots: self.shots_used,
+            aborted,
+        };
+        for observer in &mut self.observers {
+            observer.on_finished(&result);
+        }
+        Ok(result)
+    }
+
+    /// Run TQQC with Bayesian optimization (GP surrogate + expected improvement)
+    pub fn optimize_bayesian(&mut self) -> NisoResult<TqqcResult> {
+        let mut optimizer = BayesianOptimizer::new(self.objective.num_params(), self.config.shots)
+            .with_initial(self.objective.initial_params());
+        if let Some(seed) = self.config.seed {
+            optimizer = optimizer.with_seed(seed);
+        }
+        self.optimize_with(&mut optimizer)
     }
 
     // ========================================================================
     // Helper Methods
     // ========================================================================
 
-    /// Measure parity at given theta+delta
-    fn measure_parity(&self, theta: f64, delta: f64) -> NisoResult<f64> {
-        let circuit = Parity::build_circuit(&self.config, theta, delta);
-        let result = self.backend.execute(&circuit, self.config.shots)?;
-        Ok(Parity::expectation(&result.counts))
+    /// Measure the objective at a parameter vector
+    fn measure_params(&mut self, params: &[f64]) -> NisoResult<f64> {
+        let counts = self.measure_counts(params, self.config.shots)?;
+        Ok(self.objective.evaluate(&counts))
+    }
+
+    /// Execute the objective circuit and count the shots spent
+    fn measure_counts(&mut self, params: &[f64], shots: u64) -> NisoResult<Counts> {
+        let circuit = self.objective.build_circuit(params)?;
+        let result = self.backend.execute(&circuit, shots)?;
+        self.shots_used += shots;
+        Ok(result.counts)
+    }
+
+    /// Execute several parameter sets as one backend batch
+    /// Gantree: measure_batch(&mut,params,shots) -> Vec<Counts> // 일괄 측정
+    ///
+    /// On hardware backends the batch is a single multi-circuit job, so
+    /// the ±δ circuits share one queue wait. With `max_in_flight > 1` it is
+    /// split into that many jobs (whole pairs each) run concurrently.
+    fn measure_batch(&mut self, params: &[ParamVec], shots: u64) -> NisoResult<Vec<Counts>> {
+        let circuits = params
+            .iter()
+            .map(|p| self.objective.build_circuit(p))
+            .collect::<NisoResult<Vec<_>>>()?;
+        let results = if self.config.max_in_flight > 1 && circuits.len() > 2 {
+            let per_job = circuits.len().div_ceil(self.config.max_in_flight);
+            let jobs: Vec<Vec<_>> = circuits
+                .chunks(per_job + per_job % 2)
+                .map(<[_]>::to_vec)
+                .collect();
+            self.backend
+                .execute_concurrent(&jobs, shots)?
+                .into_iter()
+                .flatten()
+                .collect()
+        } else {
+            self.backend.execute_batch(&circuits, shots)?
+        };
+        if results.len() != circuits.len() {
+            return Err(NisoError::BackendError(format!(
+                "batch returned {} results for {} circuits",
+                results.len(),
+                circuits.len()
+            )));
+        }
+        self.shots_used += shots * circuits.len() as u64;
+        Ok(results.into_iter().map(|r| r.counts).collect())
+    }
+
+    /// Measure every inner-step ±δ pair of an iteration in one batch
+    ///
+    /// Returns (value_plus, value_minus) per step, in step order.
+    fn measure_inner_pairs(
+        &mut self,
+        deltas: &[f64],
+        probe: &[f64],
+        steps: &[f64],
+    ) -> NisoResult<Vec<(f64, f64)>> {
+        let params: Vec<ParamVec> = steps
+            .iter()
+            .flat_map(|&step| {
+                [
+                    Self::offset(deltas, probe, step),
+                    Self::offset(deltas, probe, -step),
+                ]
+            })
+            .collect();
+        let counts = self.measure_batch(&params, self.config.shots)?;
+        Ok(counts
+            .chunks(2)
+            .map(|pair| {
+                (
+                    self.objective.evaluate(&pair[0]),
+                    self.objective.evaluate(&pair[1]),
+                )
+            })
+            .collect())
+    }
+
+    /// Measure a ±δ pair, returning (value_plus, value_minus, shots per side)
+    /// Gantree: measure_pair(&mut,test,plus,minus) -> (f64,f64,u64) // 적응형 쌍 측정
+    ///
+    /// With `adaptive_shots` the pair starts small and grows while the
+    /// z-test is undecided (see `AdaptiveShots`), or while a sequential
+    /// test reports `pending`; otherwise both sides use the fixed `shots`.
+    fn measure_pair(
+        &mut self,
+        stat_test: &StatisticalTest,
+        plus: &[f64],
+        minus: &[f64],
+    ) -> NisoResult<(f64, f64, u64)> {
+        let pair = [plus.to_vec(), minus.to_vec()];
+        let Some(adaptive) = self.config.adaptive_shots else {
+            let counts = self.measure_batch(&pair, self.config.shots)?;
+            let value_plus = self.objective.evaluate(&counts[0]);
+            let value_minus = self.objective.evaluate(&counts[1]);
+            return Ok((value_plus, value_minus, self.config.shots));
+        };
+
+        let bound = self.objective.value_bound();
+        let mut shots = adaptive.initial;
+        let mut counts = self.measure_batch(&pair, shots)?.into_iter();
+        let mut counts_plus = counts.next().unwrap_or_default();
+        let mut counts_minus = counts.next().unwrap_or_default();
+
+        loop {
+            let value_plus = self.objective.evaluate(&counts_plus);
+            let value_minus = self.objective.evaluate(&counts_minus);
+
+            let test = stat_test.test(
+                value_plus / bound,
+                value_minus / bound,
+                shots,
+                self.config.noise,
+            );
+            let undecided = if stat_test.is_sequential() {
+                test.pending
+            } else {
+                adaptive.is_undecided(test.z_score, test.z_critical)
+            };
+            let step = adaptive.increment.min(adaptive.max.saturating_sub(shots));
+            if step == 0 || !undecided {
+                return Ok((value_plus, value_minus, shots));
+            }
+
+            let mut more = self.measure_batch(&pair, step)?.into_iter();
+            Self::merge_counts(&mut counts_plus, more.next().unwrap_or_default());
+            Self::merge_counts(&mut counts_minus, more.next().unwrap_or_default());
+            shots += step;
+        }
+    }
+
+    /// Accumulate counts from an additional execution
+    fn merge_counts(into: &mut Counts, from: Counts) {
+        for (bits, n) in from {
+            *into.entry(bits).or_insert(0) += n;
+        }
+    }
+
+    /// Choose the probe direction for an outer iteration
+    /// Gantree: probe_direction(iter,n) -> (Option<usize>,ParamVec) // 탐색 방향
+    ///
+    /// Coordinate mode cycles through unit vectors; simultaneous mode draws
+    /// a random ±1 vector over all parameters.
+    fn probe_direction(
+        &mut self,
+        iteration: usize,
+        num_params: usize,
+    ) -> (Option<usize>, ParamVec) {
+        match self.config.search_mode {
+            SearchMode::Coordinate => {
+                let k = iteration % num_params;
+                let mut probe = vec![0.0; num_params];
+                probe[k] = 1.0;
+                (Some(k), probe)
+            }
+            SearchMode::Simultaneous => {
+                let probe = (0..num_params)
+                    .map(|_| if self.rng.gen::<bool>() { 1.0 } else { -1.0 })
+                    .collect();
+                (None, probe)
+            }
+        }
+    }
+
+    /// deltas + step * probe
+    fn offset(deltas: &[f64], probe: &[f64], step: f64) -> ParamVec {
+        deltas
+            .iter()
+            .zip(probe)
+            .map(|(d, p)| d + step * p)
+            .collect()
     }
 
     /// Select direction based on statistical test
+    #[allow(clippy::too_many_arguments)]
     fn select_direction(
         &mut self,
-        delta: f64,
-        step: f64,
+        deltas: &[f64],
+        deltas_plus: ParamVec,
+        deltas_minus: ParamVec,
         parity_plus: f64,
         parity_minus: f64,
         parity_current: f64,
         test_result: &TestResult,
-    ) -> (f64, f64, Option<Direction>, bool) {
+    ) -> (ParamVec, f64, Option<Direction>, bool) {
         if test_result.is_tie {
             // Tie: random selection
             if self.rng.gen::<f64>() > 0.5 {
-                (delta + step, parity_plus, Some(Direction::Plus), false)
+                (deltas_plus, parity_plus, Some(Direction::Plus), false)
             } else {
-                (delta - step, parity_minus, Some(Direction::Minus), false)
+                (deltas_minus, parity_minus, Some(Direction::Minus), false)
             }
         } else if test_result.is_significant {
             // Significant: follow direction
             match test_result.direction {
-                Some(Direction::Plus) => (delta + step, parity_plus, Some(Direction::Plus), true),
+                Some(Direction::Plus) => (deltas_plus, parity_plus, Some(Direction::Plus), true),
                 Some(Direction::Minus) => {
-                    (delta - step, parity_minus, Some(Direction::Minus), true)
+                    (deltas_minus, parity_minus, Some(Direction::Minus), true)
                 }
-                _ => (delta, parity_current, Some(Direction::Stay), true),
+                _ => (deltas.to_vec(), parity_current, Some(Direction::Stay), true),
             }
         } else {
             // Not significant: conservative (stay)
-            (delta, parity_current, Some(Direction::Stay), false)
+            (
+                deltas.to_vec(),
+                parity_current,
+                Some(Direction::Stay),
+                false,
+            )
         }
     }
 
@@ -364,6 +868,11 @@ impl<B: Backend> TqqcEngine<B> {
     pub fn backend(&self) -> &B {
         &self.backend
     }
+
+    /// Get objective
+    pub fn objective(&self) -> &dyn Objective {
+        self.objective.as_ref()
+    }
 }
 
 // ============================================================================
@@ -373,7 +882,10 @@ impl<B: Backend> TqqcEngine<B> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{AdaptiveShots, ParamMode, SearchMode, SigMode};
+    use crate::observer::JsonlObserver;
     use niso_backend::SimulatorBackend;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     fn make_test_engine() -> TqqcEngine<SimulatorBackend> {
         let config = TqqcConfig::default_5q()
@@ -394,11 +906,11 @@ mod tests {
 
     #[test]
     fn test_measure_parity() {
-        let engine = make_test_engine();
-        let parity = engine.measure_parity(0.0, 0.0).unwrap();
+        let mut engine = make_test_engine();
+        let parity = engine.measure_params(&[0.0]).unwrap();
 
         // Parity should be in [-1, 1]
-        assert!(parity >= -1.0 && parity <= 1.0);
+        assert!((-1.0..=1.0).contains(&parity));
     }
 
     #[test]
@@ -448,10 +960,318 @@ mod tests {
         assert!(!result.history.is_empty());
     }
 
+    #[test]
+    fn test_adaptive_shots_saves_shots() {
+        let config = TqqcConfig::default_5q()
+            .with_noise(0.01)
+            .with_points(8)
+            .with_theta(0.6)
+            .with_statistical_test(true)
+            .with_seed(42);
+        let backend = SimulatorBackend::from_depol(5, 0.01).unwrap().with_seed(42);
+
+        let mut fixed = TqqcEngine::new(config.clone(), backend);
+        let fixed_result = fixed.optimize().unwrap();
+        let fixed_shots: u64 = fixed_result.history.iter().map(|r| r.shots).sum();
+        assert_eq!(
+            fixed_result.total_shots,
+            fixed_shots + config.shots // baseline
+        );
+
+        let adaptive = AdaptiveShots::from_shots(config.shots);
+        let backend = SimulatorBackend::from_depol(5, 0.01).unwrap().with_seed(42);
+        let mut engine = TqqcEngine::new(config.with_adaptive_shots(adaptive), backend);
+        let result = engine.optimize().unwrap();
+
+        assert!(result.improved());
+        assert!(
+            result.total_shots < fixed_result.total_shots,
+            "adaptive {} vs fixed {}",
+            result.total_shots,
+            fixed_result.total_shots
+        );
+        assert!(result.shot_saving(fixed_result.total_shots) > 0.0);
+        for record in &result.history {
+            // Every ±δ side runs between initial and max shots
+            assert!(record.shots >= 2 * adaptive.initial);
+            assert!(record.shots <= 2 * adaptive.max * record.inner_count as u64);
+        }
+    }
+
+    #[test]
+    fn test_sequential_mode_with_adaptive_shots() {
+        for mode in [SigMode::Sequential, SigMode::Bayesian] {
+            let config = TqqcConfig::default_5q()
+                .with_noise(0.01)
+                .with_points(8)
+                .with_theta(0.6)
+                .with_step_amp(0.3)
+                .with_statistical_test(true)
+                .with_sig_mode(mode)
+                .with_adaptive_shots(AdaptiveShots::new(512, 512, 4096))
+                .with_seed(42);
+            let backend = SimulatorBackend::from_depol(5, 0.01).unwrap().with_seed(42);
+
+            let mut engine = TqqcEngine::new(config, backend);
+            let result = engine.optimize().unwrap();
+
+            assert!(result.improved(), "{:?} did not improve", mode);
+            assert!(result.significant_moves > 0);
+        }
+    }
+
+    #[test]
+    fn test_resume_matches_uninterrupted_run() {
+        let config = TqqcConfig::default_5q()
+            .with_noise(0.01)
+            .with_points(8)
+            .with_theta(0.6)
+            .with_statistical_test(true)
+            .with_param_mode(ParamMode::PerQubit)
+            .with_search_mode(SearchMode::Simultaneous)
+            .with_seed(11);
+        let make_backend = || SimulatorBackend::from_depol(5, 0.01).unwrap().with_seed(11);
+
+        let mut full = TqqcEngine::new(config.clone(), make_backend());
+        let expected = full.optimize().unwrap();
+
+        // Interrupt after three iterations and resume in a fresh engine
+        let mut first = TqqcEngine::new(config.clone(), make_backend());
+        let mut state = first.start().unwrap();
+        for _ in 0..3 {
+            first.step(&mut state).unwrap();
+        }
+        let json = state.to_json().unwrap();
+
+        let mut second = TqqcEngine::new(config, make_backend());
+        let resumed = second.resume(TqqcState::from_json(&json).unwrap()).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&resumed).unwrap(),
+            serde_json::to_string(&expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_optimize_with_checkpoint() {
+        let path =
+            std::env::temp_dir().join(format!("niso_tqqc_checkpoint_{}.json", std::process::id()));
+        let mut engine = make_test_engine();
+
+        let result = engine.optimize_with_checkpoint(&path).unwrap();
+        let state = TqqcState::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(state.finished);
+        assert_eq!(state.history.len(), result.iterations);
+        assert_eq!(state.shots_used, result.total_shots);
+    }
+
+    /// Records event names and stops after `stop_after` iterations
+    struct Recorder {
+        events: std::sync::Arc<std::sync::Mutex<Vec<&'static str>>>,
+        stop_after: usize,
+    }
+
+    impl OptimizationObserver for Recorder {
+        fn on_baseline(&mut self, _parity: f64) -> ControlFlow<()> {
+            self.events.lock().unwrap().push("baseline");
+            ControlFlow::Continue(())
+        }
+
+        fn on_evaluation(&mut self, event: &EvaluationEvent) -> ControlFlow<()> {
+            assert!(event.z_score.is_finite());
+            self.events.lock().unwrap().push("evaluation");
+            ControlFlow::Continue(())
+        }
+
+        fn on_iteration(&mut self, record: &IterationRecord) -> ControlFlow<()> {
+            self.events.lock().unwrap().push("iteration");
+            if record.iteration + 1 >= self.stop_after {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+
+        fn on_finished(&mut self, _result: &TqqcResult) {
+            self.events.lock().unwrap().push("finished");
+        }
+    }
+
+    #[test]
+    fn test_observer_events_and_abort() {
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorder = Recorder {
+            events: events.clone(),
+            stop_after: 2,
+        };
+        let mut engine = make_test_engine().with_observer(recorder);
+
+        let result = engine.optimize().unwrap();
+        let events = events.lock().unwrap();
+
+        assert!(result.aborted);
+        assert_eq!(result.iterations, 2);
+        assert_eq!(events.first(), Some(&"baseline"));
+        assert_eq!(events.last(), Some(&"finished"));
+        assert_eq!(events.iter().filter(|e| **e == "iteration").count(), 2);
+        assert_eq!(
+            events.iter().filter(|e| **e == "evaluation").count(),
+            result.total_inner_iterations
+        );
+    }
+
+    #[test]
+    fn test_jsonl_observer_on_engine() {
+        let path =
+            std::env::temp_dir().join(format!("niso_tqqc_events_{}.jsonl", std::process::id()));
+        let file = std::fs::File::create(&path).unwrap();
+        let mut engine = make_test_engine().with_observer(JsonlObserver::new(file));
+
+        let result = engine.optimize().unwrap();
+        let log = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(!result.aborted);
+        // baseline + evaluations + iterations + converged + finished
+        let expected = 2
+            + result.total_inner_iterations
+            + result.iterations
+            + usize::from(result.early_stopped);
+        assert_eq!(log.lines().count(), expected);
+        assert!(log
+            .lines()
+            .last()
+            .unwrap()
+            .contains(r#""event":"finished""#));
+    }
+
+    /// Simulator wrapper counting single and batched executions
+    struct CountingBackend {
+        inner: SimulatorBackend,
+        singles: AtomicUsize,
+        batches: AtomicUsize,
+        jobs_in_flight: AtomicUsize,
+    }
+
+    impl Backend for CountingBackend {
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        fn num_qubits(&self) -> usize {
+            self.inner.num_qubits()
+        }
+
+        fn execute(
+            &self,
+            circuit: &niso_core::Circuit,
+            shots: u64,
+        ) -> NisoResult<niso_backend::ExecutionResult> {
+            self.singles.fetch_add(1, Ordering::SeqCst);
+            self.inner.execute(circuit, shots)
+        }
+
+        fn execute_batch(
+            &self,
+            circuits: &[niso_core::Circuit],
+            shots: u64,
+        ) -> NisoResult<Vec<niso_backend::ExecutionResult>> {
+            self.batches.fetch_add(1, Ordering::SeqCst);
+            self.inner.execute_batch(circuits, shots)
+        }
+
+        fn execute_concurrent(
+            &self,
+            batches: &[Vec<niso_core::Circuit>],
+            shots: u64,
+        ) -> NisoResult<Vec<Vec<niso_backend::ExecutionResult>>> {
+            self.jobs_in_flight
+                .fetch_max(batches.len(), Ordering::SeqCst);
+            batches
+                .iter()
+                .map(|batch| {
+                    assert_eq!(batch.len() % 2, 0, "±δ pair split across jobs");
+                    self.execute_batch(batch, shots)
+                })
+                .collect()
+        }
+    }
+
+    fn counting_backend() -> CountingBackend {
+        CountingBackend {
+            inner: SimulatorBackend::from_depol(5, 0.01).unwrap().with_seed(42),
+            singles: AtomicUsize::new(0),
+            batches: AtomicUsize::new(0),
+            jobs_in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    #[test]
+    fn test_inner_loop_uses_one_batch_per_iteration() {
+        let config = TqqcConfig::default_5q()
+            .with_noise(0.01)
+            .with_points(6)
+            .with_dynamic_inner(true)
+            .with_inner_max(5)
+            .with_seed(42);
+
+        let mut engine = TqqcEngine::new(config.clone(), counting_backend());
+        let result = engine.optimize().unwrap();
+
+        // Baseline is the only single execution; each iteration is one batch
+        assert_eq!(engine.backend.singles.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            engine.backend.batches.load(Ordering::SeqCst),
+            result.iterations
+        );
+        assert_eq!(
+            result.total_shots,
+            (2 * result.total_inner_iterations as u64 + 1) * config.shots
+        );
+    }
+
+    #[test]
+    fn test_concurrent_jobs_match_single_batch() {
+        let config = TqqcConfig::default_5q().with_seed(42);
+        // Five ±δ pairs
+        let params: Vec<ParamVec> = (1..=10).map(|i| vec![0.05 * i as f64]).collect();
+
+        let mut single = TqqcEngine::new(config.clone(), counting_backend());
+        let expected = single.measure_batch(&params, 1000).unwrap();
+
+        let mut engine = TqqcEngine::new(config.with_max_in_flight(3), counting_backend());
+        let counts = engine.measure_batch(&params, 1000).unwrap();
+
+        // Jobs of 2 + 2 + 1 pairs; the simulator reseeds per execution
+        assert_eq!(engine.backend.jobs_in_flight.load(Ordering::SeqCst), 3);
+        assert_eq!(engine.backend.batches.load(Ordering::SeqCst), 3);
+        assert_eq!(counts, expected);
+        assert_eq!(engine.shots_used, 10_000);
+    }
+
+    #[test]
+    fn test_adaptive_pairs_are_batched() {
+        let config = TqqcConfig::default_5q()
+            .with_noise(0.01)
+            .with_points(4)
+            .with_statistical_test(true)
+            .with_adaptive_shots(AdaptiveShots::new(512, 512, 2048))
+            .with_seed(42);
+
+        let mut engine = TqqcEngine::new(config, counting_backend());
+        let result = engine.optimize().unwrap();
+
+        assert_eq!(engine.backend.singles.load(Ordering::SeqCst), 1);
+        assert!(engine.backend.batches.load(Ordering::SeqCst) >= result.total_inner_iterations);
+    }
+
     #[test]
     fn test_result_improvement_percent() {
         let result = TqqcResult {
             delta_opt: 0.1,
+            deltas_opt: vec![0.1],
             parity_baseline: 0.5,
             parity_final: 0.6,
             improvement: 0.1,
@@ -461,6 +1281,8 @@ mod tests {
             significant_moves: 5,
             total_inner_iterations: 10,
             history: vec![],
+            total_shots: 0,
+            aborted: false,
         };
 
         // 0.1 / 0.5 * 100 = 20%
@@ -471,6 +1293,7 @@ mod tests {
     fn test_result_k_estimated() {
         let result = TqqcResult {
             delta_opt: 0.1,
+            deltas_opt: vec![0.1],
             parity_baseline: 0.5,
             parity_final: 0.6,
             improvement: 0.1,
@@ -480,12 +1303,124 @@ mod tests {
             significant_moves: 5,
             total_inner_iterations: 10,
             history: vec![],
+            total_shots: 0,
+            aborted: false,
         };
 
         let k = result.k_estimated(20);
         assert!(k > 0.0 && k <= 1.0);
     }
 
+    #[test]
+    fn test_per_qubit_coordinate_search() {
+        let config = TqqcConfig::default_5q()
+            .with_noise(0.01)
+            .with_points(6)
+            .with_dynamic_inner(false)
+            .with_param_mode(ParamMode::PerQubit)
+            .with_seed(42);
+
+        let backend = SimulatorBackend::from_depol(5, 0.01).unwrap().with_seed(42);
+
+        let mut engine = TqqcEngine::new(config, backend);
+        let result = engine.optimize().unwrap();
+
+        assert_eq!(result.deltas_opt.len(), 5);
+        assert_eq!(result.delta_opt, result.deltas_opt[0]);
+
+        // Round robin over parameters; only the probed entry may change
+        for (i, record) in result.history.iter().enumerate() {
+            assert_eq!(record.param_index, Some(i % 5));
+            assert_eq!(record.deltas.len(), 5);
+            if i > 0 {
+                let prev = &result.history[i - 1].deltas;
+                for (k, (a, b)) in prev.iter().zip(&record.deltas).enumerate() {
+                    if k != i % 5 {
+                        assert_eq!(a, b);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_per_qubit_simultaneous_search() {
+        let config = TqqcConfig::default_5q()
+            .with_noise(0.01)
+            .with_points(4)
+            .with_param_mode(ParamMode::PerQubit)
+            .with_search_mode(SearchMode::Simultaneous)
+            .with_statistical_test(true)
+            .with_seed(7);
+
+        let backend = SimulatorBackend::from_depol(5, 0.01).unwrap().with_seed(7);
+
+        let mut engine = TqqcEngine::new(config, backend);
+        let result = engine.optimize().unwrap();
+
+        assert!(result.iterations > 0);
+        assert!(result.history.iter().all(|r| r.param_index.is_none()));
+        assert!((-1.0..=1.0).contains(&result.parity_final));
+    }
+
+    #[test]
+    fn test_optimize_bayesian() {
+        // Phase error of 0.8 rad: baseline parity ~cos(0.8)
+        let config = TqqcConfig::for_qubits(3)
+            .with_noise(0.005)
+            .with_points(10)
+            .with_shots(2048)
+            .with_theta(0.8)
+            .with_dynamic_inner(false)
+            .with_seed(42);
+
+        let backend = SimulatorBackend::from_depol(3, 0.005)
+            .unwrap()
+            .with_seed(42);
+
+        let mut engine = TqqcEngine::new(config, backend);
+        let result = engine.optimize_bayesian().unwrap();
+
+        assert_eq!(result.iterations, 10);
+        assert!(result.history.iter().all(|r| r.inner_count == 1));
+        assert!(result.improvement > 0.1, "{:?}", result);
+        assert!((result.delta_opt + 0.8).abs() < 0.4);
+    }
+
+    #[test]
+    fn test_optimize_custom_circuit_objective() {
+        use crate::objective::CircuitObjective;
+        use niso_core::CircuitBuilder;
+
+        // Maximize <Z0 Z1> - <Z0> on Ry(a) Ry(b) | 00>: optimum a = π, b = π
+        let template = CircuitBuilder::new(2)
+            .ry(0, 2.0)
+            .ry(1, 2.0)
+            .measure_all()
+            .build();
+        let objective =
+            CircuitObjective::z_terms(template, vec![(1.0, vec![0, 1]), (-1.0, vec![0])]);
+
+        let config = TqqcConfig::for_qubits(2)
+            .with_points(12)
+            .with_shots(2048)
+            .with_noise(0.005)
+            .with_step_amp(0.3)
+            .with_dynamic_inner(false)
+            .with_statistical_test(true)
+            .with_seed(42);
+        let backend = SimulatorBackend::from_depol(2, 0.005)
+            .unwrap()
+            .with_seed(42);
+
+        let mut engine = TqqcEngine::new(config, backend).with_objective(objective);
+        let result = engine.optimize().unwrap();
+
+        assert_eq!(result.deltas_opt.len(), 2);
+        assert!(result.improvement > 0.0, "{:?}", result);
+        assert!(result.history.iter().any(|r| r.param_index == Some(1)));
+    }
+
     #[test]
     fn test_7q_optimization() {
         let config = TqqcConfig::default_7q()
@@ -60,15 +60,41 @@ pub mod parity;
 /// TQQC engine (Gantree: L5_TQQC ??TqqcEngine)
 pub mod engine;
 
+/// Optimizer strategy trait and runner (Gantree: L5_TQQC ??Optimizer)
+pub mod optimizer;
+
+/// Optimizer strategy implementations (Gantree: L5_TQQC ??Strategies)
+pub mod strategies;
+
+/// Bayesian optimization (Gantree: L5_TQQC ??BayesianOptimizer)
+pub mod bayesian;
+
+/// Optimization objectives (Gantree: L5_TQQC ??Objective)
+pub mod objective;
+
+/// Optimization observers (Gantree: L5_TQQC ??Observer)
+pub mod observer;
+
+/// Checkpointable optimization state (Gantree: L5_TQQC ??TqqcState)
+pub mod state;
+
 // ============================================================================
 // Re-exports
 // ============================================================================
 
-pub use config::{DeltaMode, SigMode, TqqcConfig};
+pub use bayesian::{BayesianOptimizer, GaussianProcess};
+pub use config::{
+    AdaptiveShots, Correction, DeltaMode, ParamMode, SearchMode, SigMode, TqqcConfig,
+};
 pub use convergence::{Convergence, DynamicInner};
 pub use engine::{IterationRecord, TqqcEngine, TqqcResult};
+pub use objective::{CircuitObjective, Objective, ParityObjective};
+pub use observer::{EvaluationEvent, JsonlObserver, ObserverEvent, OptimizationObserver};
+pub use optimizer::{DirectRun, Optimizer, StrategyResult, StrategyRunner};
 pub use parity::Parity;
 pub use stat_test::{Direction, StatisticalTest, TestResult};
+pub use state::{RngState, TqqcState};
+pub use strategies::{NelderMead, ParameterShift, Spsa, TqqcWalk};
 
 // ============================================================================
 // Prelude
@@ -82,11 +108,19 @@ pub mod prelude {
     //! use niso_tqqc::prelude::*;
     //! ```
 
-    pub use crate::config::{DeltaMode, SigMode, TqqcConfig};
+    pub use crate::bayesian::BayesianOptimizer;
+    pub use crate::config::{
+        AdaptiveShots, Correction, DeltaMode, ParamMode, SearchMode, SigMode, TqqcConfig,
+    };
     pub use crate::convergence::{Convergence, DynamicInner};
     pub use crate::engine::{IterationRecord, TqqcEngine, TqqcResult};
+    pub use crate::objective::{CircuitObjective, Objective, ParityObjective};
+    pub use crate::observer::{JsonlObserver, OptimizationObserver};
+    pub use crate::optimizer::{DirectRun, Optimizer, StrategyResult, StrategyRunner};
     pub use crate::parity::Parity;
     pub use crate::stat_test::{Direction, StatisticalTest, TestResult};
+    pub use crate::state::TqqcState;
+    pub use crate::strategies::{NelderMead, ParameterShift, Spsa, TqqcWalk};
 }
 
 // ============================================================================
@@ -0,0 +1,286 @@
+//! Optimization objectives for TQQC
+//!
+//! Gantree: L5_TQQC → Objective
+//!
+//! An objective maps a parameter vector to a circuit and the measured
+//! counts of that circuit to a scalar to maximize. The TQQC parity ansatz
+//! is one objective; arbitrary parameterized circuits (VQE/QAOA ansätze)
+//! with a counts-based cost are another.
+
+use crate::config::TqqcConfig;
+use crate::parity::Parity;
+use niso_core::{Circuit, Counts, NisoResult, ParamVec};
+use std::sync::Arc;
+
+/// Objective to maximize over a parameter vector
+/// Gantree: Objective // 목적 함수 trait
+pub trait Objective: Send + Sync {
+    /// Number of parameters
+    fn num_params(&self) -> usize;
+
+    /// Build the circuit for a parameter vector
+    /// Gantree: build_circuit(&self,params) -> Circuit // 회로 생성
+    fn build_circuit(&self, params: &[f64]) -> NisoResult<Circuit>;
+
+    /// Compute the objective value from measured counts
+    /// Gantree: evaluate(&self,counts) -> f64 // 값 계산
+    fn evaluate(&self, counts: &Counts) -> f64;
+
+    /// Starting parameters
+    fn initial_params(&self) -> ParamVec {
+        vec![0.0; self.num_params()]
+    }
+
+    /// Parameters the improvement is measured against
+    fn baseline_params(&self) -> ParamVec {
+        self.initial_params()
+    }
+
+    /// Upper bound on |value|
+    ///
+    /// Values are divided by this bound before the z-test, whose variance
+    /// model assumes a ±1-valued observable.
+    fn value_bound(&self) -> f64 {
+        1.0
+    }
+}
+
+// Shared objectives (e.g. `Arc<dyn Objective>`) are objectives too
+impl<O: Objective + ?Sized> Objective for Arc<O> {
+    fn num_params(&self) -> usize {
+        (**self).num_params()
+    }
+
+    fn build_circuit(&self, params: &[f64]) -> NisoResult<Circuit> {
+        (**self).build_circuit(params)
+    }
+
+    fn evaluate(&self, counts: &Counts) -> f64 {
+        (**self).evaluate(counts)
+    }
+
+    fn initial_params(&self) -> ParamVec {
+        (**self).initial_params()
+    }
+
+    fn baseline_params(&self) -> ParamVec {
+        (**self).baseline_params()
+    }
+
+    fn value_bound(&self) -> f64 {
+        (**self).value_bound()
+    }
+}
+
+// ============================================================================
+// Parity Objective
+// ============================================================================
+
+/// TQQC parity expectation of the `Parity::build_circuit` ansatz
+/// Gantree: ParityObjective // 패리티 목적 함수
+#[derive(Debug, Clone)]
+pub struct ParityObjective {
+    config: TqqcConfig,
+}
+
+impl ParityObjective {
+    /// Create from a TQQC configuration
+    pub fn new(config: TqqcConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Objective for ParityObjective {
+    fn num_params(&self) -> usize {
+        self.config.num_params()
+    }
+
+    fn build_circuit(&self, params: &[f64]) -> NisoResult<Circuit> {
+        Ok(Parity::build_circuit_params(
+            &self.config,
+            self.config.theta_init,
+            params,
+        ))
+    }
+
+    fn evaluate(&self, counts: &Counts) -> f64 {
+        Parity::expectation(counts)
+    }
+
+    fn initial_params(&self) -> ParamVec {
+        let mut params = vec![0.0; self.num_params()];
+        params[0] = self.config.delta_init;
+        params
+    }
+
+    /// Baseline is delta = 0
+    fn baseline_params(&self) -> ParamVec {
+        vec![0.0; self.num_params()]
+    }
+}
+
+// ============================================================================
+// Circuit Objective
+// ============================================================================
+
+/// Counts-to-value function
+pub type CountsFn = Box<dyn Fn(&Counts) -> f64 + Send + Sync>;
+
+/// User-supplied parameterized circuit with a counts-based objective
+/// Gantree: CircuitObjective // 회로 목적 함수
+///
+/// Every rotation angle of the template is a parameter (in gate order,
+/// `U` counts three); the template's own angles are the starting point.
+/// The template must end in measurements.
+pub struct CircuitObjective {
+    template: Circuit,
+    objective: CountsFn,
+    value_bound: f64,
+}
+
+impl CircuitObjective {
+    /// Create from a template circuit and a counts function
+    pub fn new<F>(template: Circuit, objective: F) -> Self
+    where
+        F: Fn(&Counts) -> f64 + Send + Sync + 'static,
+    {
+        Self {
+            template,
+            objective: Box::new(objective),
+            value_bound: 1.0,
+        }
+    }
+
+    /// Maximize a weighted sum of Z-string expectations
+    ///
+    /// Each term is (coefficient, qubits); its expectation is the parity
+    /// of the listed bits. Minimizing an energy E is maximizing -E, so pass
+    /// negated coefficients for VQE/QAOA costs.
+    pub fn z_terms(template: Circuit, terms: Vec<(f64, Vec<usize>)>) -> Self {
+        let bound = terms.iter().map(|(c, _)| c.abs()).sum::<f64>().max(1e-12);
+        let num_qubits = template.num_qubits();
+
+        Self::new(template, move |counts: &Counts| {
+            let total: u64 = counts.values().sum();
+            if total == 0 {
+                return 0.0;
+            }
+
+            terms
+                .iter()
+                .map(|(coeff, qubits)| {
+                    let signed: i64 = counts
+                        .iter()
+                        .map(|(bits, &n)| {
+                            // Bitstrings are big-endian: qubit 0 is the last char
+                            let bytes = bits.as_bytes();
+                            let ones = qubits
+                                .iter()
+                                .filter(|&&q| {
+                                    q < num_qubits
+                                        && bytes.len() > q
+                                        && bytes[bytes.len() - 1 - q] == b'1'
+                                })
+                                .count();
+                            if ones % 2 == 0 {
+                                n as i64
+                            } else {
+                                -(n as i64)
+                            }
+                        })
+                        .sum();
+                    coeff * signed as f64 / total as f64
+                })
+                .sum()
+        })
+        .with_value_bound(bound)
+    }
+
+    /// Set the bound on |value| used to normalize the z-test
+    pub fn with_value_bound(mut self, bound: f64) -> Self {
+        self.value_bound = bound;
+        self
+    }
+
+    /// Template circuit
+    pub fn template(&self) -> &Circuit {
+        &self.template
+    }
+}
+
+impl Objective for CircuitObjective {
+    fn num_params(&self) -> usize {
+        self.template.num_parameters()
+    }
+
+    fn build_circuit(&self, params: &[f64]) -> NisoResult<Circuit> {
+        self.template.bind_parameters(params)
+    }
+
+    fn evaluate(&self, counts: &Counts) -> f64 {
+        (self.objective)(counts)
+    }
+
+    fn initial_params(&self) -> ParamVec {
+        self.template.parameters()
+    }
+
+    fn value_bound(&self) -> f64 {
+        self.value_bound
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use niso_core::CircuitBuilder;
+
+    #[test]
+    fn test_parity_objective_matches_parity() {
+        let config = TqqcConfig::default_5q().with_delta(0.2);
+        let objective = ParityObjective::new(config.clone());
+
+        assert_eq!(objective.num_params(), 1);
+        assert_eq!(objective.initial_params(), vec![0.2]);
+        assert_eq!(objective.baseline_params(), vec![0.0]);
+        assert_eq!(
+            objective.build_circuit(&[0.3]).unwrap(),
+            Parity::build_circuit(&config, config.theta_init, 0.3)
+        );
+    }
+
+    #[test]
+    fn test_circuit_objective_binds_template() {
+        let template = CircuitBuilder::new(2)
+            .ry(0, 0.5)
+            .ry(1, 0.7)
+            .cnot(0, 1)
+            .measure_all()
+            .build();
+        let objective = CircuitObjective::new(template, |_| 0.0);
+
+        assert_eq!(objective.num_params(), 2);
+        assert_eq!(objective.initial_params(), vec![0.5, 0.7]);
+        assert!(objective.build_circuit(&[0.0]).is_err());
+        assert!(objective.build_circuit(&[0.1, 0.2]).is_ok());
+    }
+
+    #[test]
+    fn test_z_terms() {
+        let template = CircuitBuilder::new(2).measure_all().build();
+        // <Z0> - 2 <Z0 Z1>
+        let objective =
+            CircuitObjective::z_terms(template, vec![(1.0, vec![0]), (-2.0, vec![0, 1])]);
+
+        let mut counts = Counts::new();
+        counts.insert("01".to_string(), 100); // q0 = 1, q1 = 0
+
+        // Z0 = -1, Z0Z1 = -1  =>  -1 + 2 = 1
+        assert!((objective.evaluate(&counts) - 1.0).abs() < 1e-12);
+        assert_eq!(objective.value_bound(), 3.0);
+    }
+}
@@ -0,0 +1,192 @@
+//! Optimization progress observers
+//!
+//! Gantree: L5_TQQC → Observer
+//!
+//! Callbacks invoked by `TqqcEngine` while it runs: baseline, every ±δ
+//! evaluation, every outer iteration, convergence and completion. Any
+//! callback returning `ControlFlow::Break` aborts the run after the current
+//! iteration has been recorded.
+
+use crate::engine::{IterationRecord, TqqcResult};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::ops::ControlFlow;
+
+/// One ±δ evaluation inside an outer iteration
+/// Gantree: EvaluationEvent // ±δ 평가 이벤트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluationEvent {
+    /// Outer iteration
+    pub iteration: usize,
+
+    /// Inner step within the iteration
+    pub inner: usize,
+
+    /// Step size used for ±δ
+    pub step: f64,
+
+    /// Objective at +δ
+    pub parity_plus: f64,
+
+    /// Objective at -δ
+    pub parity_minus: f64,
+
+    /// Shots per side
+    pub shots: u64,
+
+    /// z-score of the difference
+    pub z_score: f64,
+
+    /// Whether the statistical test found the difference significant
+    pub is_significant: bool,
+}
+
+/// Observer of a running optimization
+/// Gantree: OptimizationObserver // 진행 관찰자 trait
+///
+/// All callbacks default to doing nothing.
+pub trait OptimizationObserver: Send {
+    /// Baseline objective measured
+    fn on_baseline(&mut self, _parity: f64) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// A ±δ pair was evaluated
+    fn on_evaluation(&mut self, _event: &EvaluationEvent) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// An outer iteration completed
+    fn on_iteration(&mut self, _record: &IterationRecord) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Convergence triggered early stop after `iteration`
+    fn on_converged(&mut self, _iteration: usize) {}
+
+    /// The run finished (normally, converged or aborted)
+    fn on_finished(&mut self, _result: &TqqcResult) {}
+}
+
+// ============================================================================
+// JSONL Observer
+// ============================================================================
+
+/// Event written by `JsonlObserver`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ObserverEvent {
+    /// Baseline measured
+    Baseline {
+        /// Baseline objective
+        parity: f64,
+    },
+
+    /// ±δ evaluation
+    Evaluation(EvaluationEvent),
+
+    /// Outer iteration
+    Iteration(IterationRecord),
+
+    /// Convergence triggered
+    Converged {
+        /// Last iteration
+        iteration: usize,
+    },
+
+    /// Run finished
+    Finished {
+        /// Final objective
+        parity_final: f64,
+        /// Improvement over baseline
+        improvement: f64,
+        /// Outer iterations run
+        iterations: usize,
+        /// Total shots executed
+        total_shots: u64,
+        /// Whether an observer aborted the run
+        aborted: bool,
+    },
+}
+
+/// Writes every event as one JSON line
+/// Gantree: JsonlObserver // JSONL 로그
+pub struct JsonlObserver<W: Write + Send> {
+    writer: W,
+}
+
+impl<W: Write + Send> JsonlObserver<W> {
+    /// Create from a writer (file, socket, `Vec<u8>`, ...)
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Consume and return the writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Write one event; I/O errors are ignored so logging never aborts a run
+    fn write(&mut self, event: &ObserverEvent) {
+        if let Ok(line) = serde_json::to_string(event) {
+            let _ = writeln!(self.writer, "{}", line);
+            let _ = self.writer.flush();
+        }
+    }
+}
+
+impl<W: Write + Send> OptimizationObserver for JsonlObserver<W> {
+    fn on_baseline(&mut self, parity: f64) -> ControlFlow<()> {
+        self.write(&ObserverEvent::Baseline { parity });
+        ControlFlow::Continue(())
+    }
+
+    fn on_evaluation(&mut self, event: &EvaluationEvent) -> ControlFlow<()> {
+        self.write(&ObserverEvent::Evaluation(event.clone()));
+        ControlFlow::Continue(())
+    }
+
+    fn on_iteration(&mut self, record: &IterationRecord) -> ControlFlow<()> {
+        self.write(&ObserverEvent::Iteration(record.clone()));
+        ControlFlow::Continue(())
+    }
+
+    fn on_converged(&mut self, iteration: usize) {
+        self.write(&ObserverEvent::Converged { iteration });
+    }
+
+    fn on_finished(&mut self, result: &TqqcResult) {
+        self.write(&ObserverEvent::Finished {
+            parity_final: result.parity_final,
+            improvement: result.improvement,
+            iterations: result.iterations,
+            total_shots: result.total_shots,
+            aborted: result.aborted,
+        });
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jsonl_observer_writes_lines() {
+        let mut observer = JsonlObserver::new(Vec::new());
+
+        let _ = observer.on_baseline(0.5);
+        observer.on_converged(3);
+
+        let output = String::from_utf8(observer.into_inner()).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], r#"{"event":"baseline","parity":0.5}"#);
+        let event: ObserverEvent = serde_json::from_str(lines[1]).unwrap();
+        assert!(matches!(event, ObserverEvent::Converged { iteration: 3 }));
+    }
+}
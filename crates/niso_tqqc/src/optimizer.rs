@@ -0,0 +1,300 @@
+//! Pluggable optimizer strategies
+//!
+//! Gantree: L5_TQQC → Optimizer
+//!
+//! Ask/tell interface shared by all search algorithms: an optimizer
+//! proposes parameter vectors, the runner evaluates them on a backend,
+//! and the optimizer updates from the noisy objective values. Running
+//! every strategy through the same runner makes comparisons under an
+//! identical noise model and shot budget possible.
+
+use crate::config::TqqcConfig;
+use crate::objective::{Objective, ParityObjective};
+use niso_backend::Backend;
+use niso_core::{NisoError, NisoResult, ParamVec};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Search strategy over a parameter vector (maximizing the objective)
+/// Gantree: Optimizer // 최적화 전략 trait
+pub trait Optimizer {
+    /// Strategy name
+    fn name(&self) -> &str;
+
+    /// Propose the next batch of parameter vectors to evaluate
+    /// Gantree: propose(&mut) -> Vec<ParamVec> // 후보 제안
+    fn propose(&mut self) -> Vec<ParamVec>;
+
+    /// Receive noisy objective values for the last proposal (same order)
+    /// Gantree: update(&mut,values) // 결과 반영
+    fn update(&mut self, values: &[f64]);
+
+    /// Currently recommended parameters
+    fn current(&self) -> ParamVec;
+
+    /// Whether the strategy has terminated on its own
+    fn is_done(&self) -> bool {
+        false
+    }
+
+    /// Drive the search on a backend directly instead of through propose/update
+    /// Gantree: run_direct(&mut,backend,objective,budget) -> Option<DirectRun> // 직접 실행
+    ///
+    /// Strategies that own their evaluation loop (the TQQC engine) return
+    /// `Some` and the runner skips the ask/tell loop.
+    fn run_direct(
+        &mut self,
+        _backend: &dyn Backend,
+        _objective: Arc<dyn Objective>,
+        _shot_budget: u64,
+    ) -> Option<NisoResult<DirectRun>> {
+        None
+    }
+}
+
+/// Outcome of a strategy that drives the backend itself
+/// Gantree: DirectRun // 직접 실행 결과
+#[derive(Debug, Clone)]
+pub struct DirectRun {
+    /// Recommended parameters at the end of the run
+    pub params_opt: ParamVec,
+
+    /// Number of circuit evaluations spent
+    pub evaluations: usize,
+
+    /// Shots spent (the strategy's own baseline excluded)
+    pub shots_used: u64,
+
+    /// Best objective value after each round
+    pub history: Vec<f64>,
+}
+
+/// Result of running a strategy under a shot budget
+/// Gantree: StrategyResult // 전략 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyResult {
+    /// Strategy name
+    pub name: String,
+
+    /// Recommended parameters at the end of the run
+    pub params_opt: ParamVec,
+
+    /// Objective at the baseline parameters
+    pub value_baseline: f64,
+
+    /// Objective at the recommended parameters (fresh evaluation)
+    pub value_final: f64,
+
+    /// Number of circuit evaluations spent by the strategy
+    pub evaluations: usize,
+
+    /// Shots spent by the strategy (baseline/final excluded)
+    pub shots_used: u64,
+
+    /// Best objective value seen after each round
+    pub history: Vec<f64>,
+}
+
+impl StrategyResult {
+    /// Improvement over baseline
+    pub fn improvement(&self) -> f64 {
+        self.value_final - self.value_baseline
+    }
+}
+
+/// Evaluates strategies on an objective (TQQC parity by default)
+/// Gantree: StrategyRunner // 전략 실행기
+pub struct StrategyRunner<B: Backend> {
+    /// Configuration (shots, parameterization)
+    config: TqqcConfig,
+
+    /// Backend for circuit execution
+    backend: B,
+
+    /// Objective being maximized
+    objective: Arc<dyn Objective>,
+}
+
+impl<B: Backend> StrategyRunner<B> {
+    /// Create a new runner
+    pub fn new(config: TqqcConfig, backend: B) -> Self {
+        let objective = Arc::new(ParityObjective::new(config.clone()));
+        Self {
+            config,
+            backend,
+            objective,
+        }
+    }
+
+    /// Replace the objective
+    pub fn with_objective(mut self, objective: impl Objective + 'static) -> Self {
+        self.objective = Arc::new(objective);
+        self
+    }
+
+    /// Evaluate the objective at a parameter vector
+    pub fn evaluate(&self, params: &[f64]) -> NisoResult<f64> {
+        let circuit = self.objective.build_circuit(params)?;
+        let result = self.backend.execute(&circuit, self.config.shots)?;
+        Ok(self.objective.evaluate(&result.counts))
+    }
+
+    /// Run a strategy until its shot budget is exhausted
+    /// Gantree: run(&self,opt,budget) -> StrategyResult // 전략 실행
+    ///
+    /// A round is only executed if all of its proposals fit in the
+    /// remaining budget.
+    pub fn run(
+        &self,
+        optimizer: &mut dyn Optimizer,
+        shot_budget: u64,
+    ) -> NisoResult<StrategyResult> {
+        let num_params = self.objective.num_params();
+        let value_baseline = self.evaluate(&self.objective.baseline_params())?;
+
+        if let Some(direct) =
+            optimizer.run_direct(&self.backend, Arc::clone(&self.objective), shot_budget)
+        {
+            let direct = direct?;
+            let value_final = self.evaluate(&direct.params_opt)?;
+            return Ok(StrategyResult {
+                name: optimizer.name().to_string(),
+                params_opt: direct.params_opt,
+                value_baseline,
+                value_final,
+                evaluations: direct.evaluations,
+                shots_used: direct.shots_used,
+                history: direct.history,
+            });
+        }
+
+        let mut shots_used = 0;
+        let mut evaluations = 0;
+        let mut best = f64::NEG_INFINITY;
+        let mut history = Vec::new();
+
+        while !optimizer.is_done() {
+            let proposals = optimizer.propose();
+            if proposals.is_empty() {
+                break;
+            }
+
+            let cost = proposals.len() as u64 * self.config.shots;
+            if shots_used + cost > shot_budget {
+                break;
+            }
+
+            let mut values = Vec::with_capacity(proposals.len());
+            for params in &proposals {
+                if params.len() != num_params {
+                    return Err(NisoError::TqqcConfigError(format!(
+                        "{} proposed {} parameters, expected {}",
+                        optimizer.name(),
+                        params.len(),
+                        num_params
+                    )));
+                }
+                values.push(self.evaluate(params)?);
+            }
+
+            shots_used += cost;
+            evaluations += proposals.len();
+            best = values.iter().copied().fold(best, f64::max);
+            history.push(best);
+
+            optimizer.update(&values);
+        }
+
+        let params_opt = optimizer.current();
+        let value_final = self.evaluate(&params_opt)?;
+
+        Ok(StrategyResult {
+            name: optimizer.name().to_string(),
+            params_opt,
+            value_baseline,
+            value_final,
+            evaluations,
+            shots_used,
+            history,
+        })
+    }
+
+    /// Run several strategies under the same shot budget
+    pub fn compare(
+        &self,
+        optimizers: &mut [Box<dyn Optimizer>],
+        shot_budget: u64,
+    ) -> NisoResult<Vec<StrategyResult>> {
+        optimizers
+            .iter_mut()
+            .map(|opt| self.run(opt.as_mut(), shot_budget))
+            .collect()
+    }
+
+    /// Get configuration
+    pub fn config(&self) -> &TqqcConfig {
+        &self.config
+    }
+
+    /// Get backend
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategies::{NelderMead, ParameterShift, Spsa, TqqcWalk};
+    use niso_backend::SimulatorBackend;
+
+    /// 3-qubit GHZ parity with a 0.8 rad phase error to correct
+    fn make_runner() -> StrategyRunner<SimulatorBackend> {
+        let config = TqqcConfig::for_qubits(3)
+            .with_noise(0.005)
+            .with_shots(1024)
+            .with_theta(0.8)
+            .with_seed(42);
+        let backend = SimulatorBackend::from_depol(3, 0.005)
+            .unwrap()
+            .with_seed(42);
+        StrategyRunner::new(config, backend)
+    }
+
+    #[test]
+    fn test_runner_respects_budget() {
+        let runner = make_runner();
+        let mut spsa = Spsa::new(1).with_seed(1);
+
+        let result = runner.run(&mut spsa, 10 * 1024).unwrap();
+
+        assert!(result.shots_used <= 10 * 1024);
+        assert_eq!(result.evaluations, 10);
+        assert_eq!(result.history.len(), 5);
+    }
+
+    #[test]
+    fn test_all_strategies_improve() {
+        let runner = make_runner();
+        let config = runner.config().clone();
+        let mut optimizers: Vec<Box<dyn Optimizer>> = vec![
+            Box::new(TqqcWalk::from_config(&config)),
+            Box::new(Spsa::new(1).with_seed(3)),
+            Box::new(NelderMead::new(1)),
+            Box::new(ParameterShift::new(1)),
+        ];
+
+        let results = runner.compare(&mut optimizers, 60 * 1024).unwrap();
+
+        assert_eq!(results.len(), 4);
+        for r in &results {
+            // Baseline is cos(0.8) ~ 0.70; all strategies should move towards -0.8
+            assert!(r.improvement() > 0.1, "{} did not improve: {:?}", r.name, r);
+            assert!(r.shots_used <= 60 * 1024);
+        }
+    }
+}
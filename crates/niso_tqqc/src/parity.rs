@@ -4,7 +4,7 @@
 //!
 //! Provides parity expectation calculation and TQQC circuit generation.
 
-use crate::config::TqqcConfig;
+use crate::config::{ParamMode, TqqcConfig};
 use niso_core::{Circuit, CircuitBuilder, Counts};
 
 /// Parity calculation utilities
@@ -102,6 +102,23 @@ impl Parity {
             .build()
     }
 
+    /// Build TQQC circuit for a delta vector
+    /// Gantree: build_circuit_params(cfg,theta,deltas) -> Circuit // 다중 델타 회로
+    ///
+    /// `ParamMode::Scalar` uses `deltas[0]` only and matches
+    /// [`build_circuit`](Self::build_circuit); `ParamMode::PerQubit`
+    /// applies one Rz(δ_q) per qubit.
+    pub fn build_circuit_params(config: &TqqcConfig, theta: f64, deltas: &[f64]) -> Circuit {
+        match config.param_mode {
+            ParamMode::Scalar => {
+                Self::build_circuit(config, theta, deltas.first().copied().unwrap_or(0.0))
+            }
+            ParamMode::PerQubit => CircuitBuilder::new(config.qubits)
+                .tqqc_parity_multi(theta, deltas, config.entangler, &config.basis)
+                .build(),
+        }
+    }
+
     /// Build circuit with explicit basis string
     pub fn build_circuit_with_basis(
         num_qubits: usize,
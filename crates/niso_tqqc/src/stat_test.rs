@@ -2,12 +2,20 @@
 //!
 //! Gantree: L5_TQQC → StatisticalTest
 //!
-//! Implements z-test for significance testing in delta search.
+//! Implements z-test for significance testing in delta search, plus a
+//! sequential probability ratio test (SPRT) and a Beta-posterior test that
+//! can be re-run on growing shot batches.
 
-use crate::config::SigMode;
-use niso_core::stats;
+use crate::config::{Correction, SigMode};
+use niso_core::stats::{self, normal_cdf};
 use serde::{Deserialize, Serialize};
 
+/// Default type-II error for the sequential test
+pub const DEFAULT_TYPE_II: f64 = 0.2;
+
+/// Default minimum parity difference worth detecting
+pub const DEFAULT_EFFECT: f64 = 0.05;
+
 /// Direction of improvement
 /// Gantree: Direction // 방향
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -47,6 +55,10 @@ pub struct TestResult {
 
     /// Is a tie (difference < epsilon)
     pub is_tie: bool,
+
+    /// Sequential modes only: neither boundary reached, more shots needed
+    #[serde(default)]
+    pub pending: bool,
 }
 
 impl TestResult {
@@ -58,6 +70,7 @@ impl TestResult {
             z_critical,
             direction: Some(direction),
             is_tie: false,
+            pending: false,
         }
     }
 
@@ -69,6 +82,15 @@ impl TestResult {
             z_critical,
             direction: None,
             is_tie: false,
+            pending: false,
+        }
+    }
+
+    /// Create an undecided sequential result
+    pub fn pending(z_score: f64, z_critical: f64) -> Self {
+        Self {
+            pending: true,
+            ..Self::insignificant(z_score, z_critical)
         }
     }
 
@@ -80,6 +102,7 @@ impl TestResult {
             z_critical: 0.0,
             direction: None,
             is_tie: true,
+            pending: false,
         }
     }
 }
@@ -95,6 +118,33 @@ pub struct StatisticalTest {
     /// Base significance level
     /// Gantree: level: f64 // 유의 수준
     pub level: f64,
+
+    /// Type-II error (Sequential mode)
+    /// Gantree: type_ii: f64 // 제2종 오류
+    #[serde(default = "default_type_ii")]
+    pub type_ii: f64,
+
+    /// Minimum parity difference worth detecting (Sequential mode)
+    /// Gantree: effect: f64 // 효과 크기
+    #[serde(default = "default_effect")]
+    pub effect: f64,
+
+    /// Multiple-comparison correction
+    /// Gantree: correction: Correction // 다중 비교 보정
+    #[serde(default)]
+    pub correction: Correction,
+
+    /// Size of the comparison family (0 = uncorrected)
+    #[serde(default)]
+    pub comparisons: usize,
+}
+
+fn default_type_ii() -> f64 {
+    DEFAULT_TYPE_II
+}
+
+fn default_effect() -> f64 {
+    DEFAULT_EFFECT
 }
 
 impl StatisticalTest {
@@ -105,7 +155,14 @@ impl StatisticalTest {
     /// Create new statistical test
     /// Gantree: new(mode,level) -> Self // 생성자
     pub fn new(mode: SigMode, level: f64) -> Self {
-        Self { mode, level }
+        Self {
+            mode,
+            level,
+            type_ii: DEFAULT_TYPE_II,
+            effect: DEFAULT_EFFECT,
+            correction: Correction::None,
+            comparisons: 0,
+        }
     }
 
     /// Create with default settings
@@ -123,6 +180,64 @@ impl StatisticalTest {
         Self::new(SigMode::Adaptive, level)
     }
 
+    /// Create sequential (SPRT) test with type-I error 1 - level
+    pub fn sequential(level: f64, type_ii: f64) -> Self {
+        Self::new(SigMode::Sequential, level).with_type_ii(type_ii)
+    }
+
+    /// Create Beta-posterior test
+    pub fn bayesian(level: f64) -> Self {
+        Self::new(SigMode::Bayesian, level)
+    }
+
+    /// Set type-II error
+    pub fn with_type_ii(mut self, type_ii: f64) -> Self {
+        self.type_ii = type_ii;
+        self
+    }
+
+    /// Set minimum detectable parity difference
+    pub fn with_effect(mut self, effect: f64) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    /// Apply a multiple-comparison correction over `comparisons` tests
+    pub fn with_correction(mut self, correction: Correction, comparisons: usize) -> Self {
+        self.correction = correction;
+        self.comparisons = comparisons;
+        self
+    }
+
+    /// Per-comparison level after `rejections` significant results
+    /// Gantree: comparison_level(&self,rejections) -> f64 // 보정 수준
+    pub fn comparison_level(&self, rejections: usize) -> f64 {
+        let alpha = 1.0 - self.level;
+        let m = self.comparisons.max(1) as f64;
+        match self.correction {
+            Correction::None => self.level,
+            Correction::Bonferroni => 1.0 - alpha / m,
+            Correction::Holm => {
+                let remaining = (self.comparisons.saturating_sub(rejections)).max(1);
+                1.0 - alpha / remaining as f64
+            }
+        }
+    }
+
+    /// Uncorrected test at the level of the next comparison
+    pub fn for_comparison(&self, rejections: usize) -> Self {
+        Self {
+            level: self.comparison_level(rejections),
+            correction: Correction::None,
+            ..self.clone()
+        }
+    }
+
+    /// Whether results may be `pending` and benefit from more shots
+    pub fn is_sequential(&self) -> bool {
+        matches!(self.mode, SigMode::Sequential | SigMode::Bayesian)
+    }
+
     // ========================================================================
     // Z-Score Calculation
     // ========================================================================
@@ -179,9 +294,12 @@ impl StatisticalTest {
 
     /// Get z critical value
     /// Gantree: z_critical(&self,shots,noise) -> f64 // 임계값
+    ///
+    /// Sequential and Bayesian modes report the nominal fixed value here;
+    /// their actual boundary depends on the observed parities (see `test`).
     pub fn z_critical(&self, shots: u64, noise: f64) -> f64 {
         match self.mode {
-            SigMode::Fixed => self.fixed_critical(),
+            SigMode::Fixed | SigMode::Sequential | SigMode::Bayesian => self.fixed_critical(),
             SigMode::Adaptive => self.adaptive_critical(shots, noise),
         }
     }
@@ -189,14 +307,7 @@ impl StatisticalTest {
     /// Get fixed critical value
     /// Gantree: fixed_critical(level) -> f64 // 고정
     fn fixed_critical(&self) -> f64 {
-        // Standard normal quantiles
-        if self.level >= 0.99 {
-            stats::Z_CRIT_99
-        } else if self.level >= 0.95 {
-            stats::Z_CRIT_95
-        } else {
-            stats::Z_CRIT_90
-        }
+        stats::z_critical(self.level)
     }
 
     /// Get adaptive critical value
@@ -206,6 +317,9 @@ impl StatisticalTest {
     /// - High noise (>0.02): +0.025 to level
     /// - Low shots (<4096): +0.025 to level
     /// - High shots (>=16384): -0.05 from level
+    ///
+    /// The adjusted level is clamped to [0.90, 0.99] (widened to include
+    /// the base level) and converted to z exactly.
     fn adaptive_critical(&self, shots: u64, noise: f64) -> f64 {
         let mut adjusted_level = self.level;
 
@@ -225,18 +339,12 @@ impl StatisticalTest {
         }
 
         // Clamp to valid range
-        adjusted_level = adjusted_level.clamp(0.90, 0.99);
-
-        // Return corresponding z value
-        if adjusted_level >= 0.99 {
-            stats::Z_CRIT_99
-        } else if adjusted_level >= 0.975 {
-            2.24 // 97.5%
-        } else if adjusted_level >= 0.95 {
-            stats::Z_CRIT_95
-        } else {
-            stats::Z_CRIT_90
-        }
+        adjusted_level = adjusted_level.clamp(
+            stats::MIN_CONFIDENCE_LEVEL.min(self.level),
+            stats::MAX_CONFIDENCE_LEVEL.max(self.level),
+        );
+
+        stats::z_critical(adjusted_level)
     }
 
     // ========================================================================
@@ -258,6 +366,12 @@ impl StatisticalTest {
             return TestResult::tie();
         }
 
+        match self.mode {
+            SigMode::Sequential => return self.sprt(parity_plus, parity_minus, shots),
+            SigMode::Bayesian => return self.posterior(parity_plus, parity_minus, shots),
+            SigMode::Fixed | SigMode::Adaptive => {}
+        }
+
         // Compute z-score
         let z_score = self.compute_z_from_parity(parity_plus, parity_minus, shots);
         let z_critical = self.z_critical(shots, noise);
@@ -275,6 +389,104 @@ impl StatisticalTest {
         }
     }
 
+    /// Wald's SPRT on the parity difference D = E₊ - E₋
+    /// Gantree: sprt(&self,p_plus,p_minus,shots) -> TestResult // 순차 검정
+    ///
+    /// H0: D = 0 against H1: |D| = effect, with D ~ N(·, σ²) and
+    /// σ² = ((1-E₊²) + (1-E₋²)) / N. The log-likelihood ratio
+    /// (effect·|D| - effect²/2) / σ² is compared with Wald's boundaries
+    /// ln((1-β)/(α/2)) and ln(β/(1-α)); α is split over both directions.
+    /// Parities computed from pooled counts of all batches so far give the
+    /// same statistic as summing per-batch ratios.
+    fn sprt(&self, parity_plus: f64, parity_minus: f64, shots: u64) -> TestResult {
+        let alpha = (1.0 - self.level).max(1e-12);
+        let beta = self.type_ii;
+        let upper = ((1.0 - beta) / (alpha / 2.0)).ln();
+        let lower = (beta / (1.0 - alpha)).ln();
+
+        let diff = parity_plus - parity_minus;
+        let direction = if diff > 0.0 {
+            Direction::Plus
+        } else {
+            Direction::Minus
+        };
+
+        if shots == 0 {
+            return TestResult::pending(0.0, f64::INFINITY);
+        }
+
+        let variance = ((1.0 - parity_plus.powi(2)) + (1.0 - parity_minus.powi(2))) / shots as f64;
+        let sigma = variance.sqrt();
+        if sigma < 1e-10 {
+            return TestResult::significant(f64::INFINITY, 0.0, direction);
+        }
+
+        // Boundaries expressed on the z = |D|/σ scale
+        let z_score = diff.abs() / sigma;
+        let z_upper = upper * sigma / self.effect + self.effect / (2.0 * sigma);
+        let llr = (self.effect * diff.abs() - self.effect.powi(2) / 2.0) / variance;
+
+        if llr >= upper {
+            TestResult::significant(z_score, z_upper, direction)
+        } else if llr <= lower {
+            TestResult::insignificant(z_score, z_upper)
+        } else {
+            TestResult::pending(z_score, z_upper)
+        }
+    }
+
+    /// Beta-posterior test on the even-outcome probabilities
+    /// Gantree: posterior(&self,p_plus,p_minus,shots) -> TestResult // 베이지안 검정
+    ///
+    /// Compares H0 (both sides share one P(even) ~ Beta(1,1)) with H1
+    /// (independent Beta(1,1) priors) through the Beta-Binomial Bayes
+    /// factor, at even prior odds. The probability that plus beats minus is
+    /// P(H1 | data) · P(p₊ > p₋ | H1, data), the latter from a normal
+    /// approximation of the Beta posteriors. Significant once it exceeds
+    /// `level`; `pending` until either that or P(H0 | data) does. Bayes
+    /// factors are martingales under H0, so re-testing after every batch
+    /// keeps the false-move rate near 1 - level.
+    fn posterior(&self, parity_plus: f64, parity_minus: f64, shots: u64) -> TestResult {
+        let n = shots as f64;
+        let even_plus = n * (1.0 + parity_plus) / 2.0;
+        let even_minus = n * (1.0 + parity_minus) / 2.0;
+
+        let ln_bf = ln_beta(1.0 + even_plus, 1.0 + n - even_plus)
+            + ln_beta(1.0 + even_minus, 1.0 + n - even_minus)
+            - ln_beta(
+                1.0 + even_plus + even_minus,
+                1.0 + 2.0 * n - even_plus - even_minus,
+            );
+        let prob_h1 = 1.0 / (1.0 + (-ln_bf).exp());
+
+        let moments = |even: f64| {
+            let (a, b) = (1.0 + even, 1.0 + n - even);
+            let mean = a / (a + b);
+            let var = a * b / ((a + b).powi(2) * (a + b + 1.0));
+            (mean, var)
+        };
+        let (mean_plus, var_plus) = moments(even_plus);
+        let (mean_minus, var_minus) = moments(even_minus);
+        let diff = mean_plus - mean_minus;
+        let sd = (var_plus + var_minus).sqrt();
+
+        let z_score = diff.abs() / sd;
+        let z_critical = stats::probit(self.level);
+        let prob_better = prob_h1 * normal_cdf(z_score);
+
+        if prob_better > self.level {
+            let direction = if diff > 0.0 {
+                Direction::Plus
+            } else {
+                Direction::Minus
+            };
+            TestResult::significant(z_score, z_critical, direction)
+        } else if 1.0 - prob_h1 > self.level {
+            TestResult::insignificant(z_score, z_critical)
+        } else {
+            TestResult::pending(z_score, z_critical)
+        }
+    }
     /// Test with even probabilities (proportion test)
     pub fn test_proportions(
         &self,
@@ -291,6 +503,41 @@ impl StatisticalTest {
     }
 }
 
+/// ln B(a, b)
+fn ln_beta(a: f64, b: f64) -> f64 {
+    ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b)
+}
+
+/// ln Γ(x) for x > 0 (Lanczos, g = 7)
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // Reflection: Γ(x)Γ(1-x) = π / sin(πx)
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFS[0], |acc, (i, c)| acc + c / (x + i as f64 + 1.0));
+
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
 impl Default for StatisticalTest {
     fn default() -> Self {
         Self::default_tqqc()
@@ -304,6 +551,109 @@ impl Default for StatisticalTest {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::TqqcConfig;
+    use crate::parity::Parity;
+    use niso_backend::{Backend, SimulatorBackend};
+
+    const TRIALS: u64 = 200;
+    const BATCH: u64 = 256;
+
+    /// Measure ±δ parities around `theta` with independent seeds, adding
+    /// shot batches while the test is pending (up to `max_batches`)
+    fn run_trial(st: &StatisticalTest, theta: f64, trial: u64, max_batches: u64) -> TestResult {
+        let config = TqqcConfig::for_qubits(3).with_noise(0.01);
+        let plus = Parity::build_circuit(&config, theta, 0.3);
+        let minus = Parity::build_circuit(&config, theta, -0.3);
+
+        let (mut sum_plus, mut sum_minus, mut shots) = (0.0, 0.0, 0);
+        let mut batch = 0;
+        loop {
+            let seed = (trial * max_batches + batch) * 2;
+            let backend = SimulatorBackend::from_depol(3, 0.01).unwrap();
+            let result_plus = backend.with_seed(seed).execute(&plus, BATCH).unwrap();
+            let backend = SimulatorBackend::from_depol(3, 0.01).unwrap();
+            let result_minus = backend.with_seed(seed + 1).execute(&minus, BATCH).unwrap();
+
+            sum_plus += Parity::expectation(&result_plus.counts) * BATCH as f64;
+            sum_minus += Parity::expectation(&result_minus.counts) * BATCH as f64;
+            shots += BATCH;
+            batch += 1;
+
+            let result = st.test(
+                sum_plus / shots as f64,
+                sum_minus / shots as f64,
+                shots,
+                0.01,
+            );
+            if !result.pending || batch >= max_batches {
+                return result;
+            }
+        }
+    }
+
+    /// Fraction of trials with a significant move in `direction`
+    fn move_rate(st: &StatisticalTest, theta: f64, max_batches: u64, direction: Direction) -> f64 {
+        let moves = (0..TRIALS)
+            .filter(|&t| {
+                let r = run_trial(st, theta, t, max_batches);
+                r.is_significant && r.direction == Some(direction)
+            })
+            .count();
+        moves as f64 / TRIALS as f64
+    }
+
+    #[test]
+    fn test_false_move_rate_under_simulator() {
+        // theta = 0: cos(+δ) = cos(-δ), so every significant move is false
+        let modes = [
+            StatisticalTest::fixed(0.95),
+            StatisticalTest::sequential(0.95, 0.2),
+            StatisticalTest::bayesian(0.95),
+        ];
+
+        for st in &modes {
+            let batches = if st.is_sequential() { 8 } else { 1 };
+            let rate = move_rate(st, 0.0, batches, Direction::Plus)
+                + move_rate(st, 0.0, batches, Direction::Minus);
+            assert!(rate <= 0.10, "{:?}: false-move rate {}", st.mode, rate);
+        }
+    }
+
+    #[test]
+    fn test_sequential_detects_real_difference() {
+        // theta = 0.6: cos(0.9) < cos(0.3), minus is better
+        let sprt = StatisticalTest::sequential(0.95, 0.2);
+        assert!(move_rate(&sprt, 0.6, 8, Direction::Minus) >= 0.9);
+
+        let bayes = StatisticalTest::bayesian(0.95);
+        assert!(move_rate(&bayes, 0.6, 8, Direction::Minus) >= 0.9);
+    }
+
+    #[test]
+    fn test_sprt_boundaries() {
+        let st = StatisticalTest::sequential(0.95, 0.2).with_effect(0.05);
+
+        // Large difference with many shots: accept H1
+        let result = st.test(0.6, 0.4, 8192, 0.02);
+        assert!(result.is_significant);
+        assert!(!result.pending);
+
+        // Tiny difference with many shots: accept H0
+        let result = st.test(0.501, 0.5, 65536, 0.02);
+        assert!(!result.is_significant);
+        assert!(!result.pending);
+
+        // Small sample: undecided
+        let result = st.test(0.52, 0.5, 256, 0.02);
+        assert!(result.pending);
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        // Γ(5) = 24, Γ(0.5) = √π
+        assert!((ln_gamma(5.0) - 24f64.ln()).abs() < 1e-10);
+        assert!((ln_gamma(0.5) - std::f64::consts::PI.sqrt().ln()).abs() < 1e-10);
+    }
 
     #[test]
     fn test_statistical_test_new() {
@@ -343,6 +693,36 @@ mod tests {
         assert!(z_high_shots <= z_normal);
     }
 
+    #[test]
+    fn test_exact_levels() {
+        // 0.97 used to snap to 1.96
+        let z97 = StatisticalTest::fixed(0.97).z_critical(8192, 0.02);
+        assert!((z97 - 2.1701).abs() < 1e-3);
+
+        // Adaptive adjustments are continuous: 0.95 + 0.025 -> 97.5%
+        let st = StatisticalTest::adaptive(0.95);
+        let expected = stats::z_critical(0.975);
+        assert!((st.z_critical(2048, 0.01) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_corrections() {
+        let st = StatisticalTest::fixed(0.95).with_correction(Correction::Bonferroni, 50);
+        assert!((st.comparison_level(0) - 0.999).abs() < 1e-12);
+        assert!((st.comparison_level(10) - 0.999).abs() < 1e-12);
+
+        let holm = StatisticalTest::fixed(0.95).with_correction(Correction::Holm, 50);
+        assert!((holm.comparison_level(0) - 0.999).abs() < 1e-12);
+        assert!(holm.comparison_level(10) < holm.comparison_level(0));
+        assert!((holm.comparison_level(60) - 0.95).abs() < 1e-12);
+
+        // A corrected comparison demands a larger z
+        let corrected = st.for_comparison(0);
+        assert!(corrected.z_critical(8192, 0.02) > st.z_critical(8192, 0.02));
+        assert!(!corrected.test(0.52, 0.48, 8192, 0.02).is_significant);
+        assert!(st.test(0.52, 0.48, 8192, 0.02).is_significant);
+    }
+
     #[test]
     fn test_compute_z() {
         let st = StatisticalTest::default_tqqc();
@@ -0,0 +1,171 @@
+//! Checkpointable optimization state
+//!
+//! Gantree: L5_TQQC → TqqcState
+//!
+//! Everything `TqqcEngine::optimize` carries between outer iterations:
+//! the delta vector, tracking counters, history, convergence window and
+//! RNG position. Saving the state after each iteration lets a long
+//! hardware run be resumed with `TqqcEngine::resume`.
+
+use crate::convergence::Convergence;
+use crate::engine::{IterationRecord, TqqcResult};
+use niso_core::{NisoResult, ParamVec};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Serializable position of the engine's random generator
+/// Gantree: RngState // 난수 상태
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RngState {
+    /// ChaCha12 seed
+    pub seed: [u8; 32],
+
+    /// Word position in the ChaCha12 stream
+    pub word_pos: u128,
+}
+
+impl RngState {
+    /// Capture the generator position
+    pub fn capture(rng: &ChaCha12Rng) -> Self {
+        Self {
+            seed: rng.get_seed(),
+            word_pos: rng.get_word_pos(),
+        }
+    }
+
+    /// Rebuild a generator at the captured position
+    pub fn restore(&self) -> ChaCha12Rng {
+        let mut rng = ChaCha12Rng::from_seed(self.seed);
+        rng.set_word_pos(self.word_pos);
+        rng
+    }
+}
+
+/// TQQC optimization state between outer iterations
+/// Gantree: TqqcState // 체크포인트 상태
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TqqcState {
+    /// Next outer iteration to run
+    pub iteration: usize,
+
+    /// Current delta vector
+    pub deltas: ParamVec,
+
+    /// Baseline parity (delta=0)
+    pub parity_baseline: f64,
+
+    /// Parity at the current deltas
+    pub parity_current: f64,
+
+    /// Improvement of the last iteration (drives the dynamic inner count)
+    pub last_improve: f64,
+
+    /// Total inner iterations so far
+    pub total_inner: usize,
+
+    /// Number of ties so far
+    pub ties_count: usize,
+
+    /// Number of significant moves so far
+    pub significant_moves: usize,
+
+    /// Whether early stop was triggered
+    pub early_stopped: bool,
+
+    /// Whether the run is complete
+    pub finished: bool,
+
+    /// Whether an observer aborted the run
+    #[serde(default)]
+    pub aborted: bool,
+
+    /// Shots executed so far
+    pub shots_used: u64,
+
+    /// Iteration history
+    pub history: Vec<IterationRecord>,
+
+    /// Convergence window
+    pub convergence: Convergence,
+
+    /// Random generator position
+    pub rng: RngState,
+}
+
+impl TqqcState {
+    /// Result of the run so far
+    /// Gantree: to_result(&self) -> TqqcResult // 결과 변환
+    pub fn to_result(&self) -> TqqcResult {
+        TqqcResult {
+            delta_opt: self.deltas[0],
+            deltas_opt: self.deltas.clone(),
+            parity_baseline: self.parity_baseline,
+            parity_final: self.parity_current,
+            improvement: self.parity_current - self.parity_baseline,
+            iterations: self.history.len(),
+            early_stopped: self.early_stopped,
+            ties_count: self.ties_count,
+            significant_moves: self.significant_moves,
+            total_inner_iterations: self.total_inner,
+            history: self.history.clone(),
+            total_shots: self.shots_used,
+            aborted: self.aborted,
+        }
+    }
+
+    /// Serialize to JSON
+    pub fn to_json(&self) -> NisoResult<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Deserialize from JSON
+    pub fn from_json(json: &str) -> NisoResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Write a checkpoint file
+    /// Gantree: save(&self,path) -> Result // 체크포인트 저장
+    ///
+    /// Writes to a temporary file first so a crash mid-write never leaves
+    /// a truncated checkpoint behind.
+    pub fn save(&self, path: impl AsRef<Path>) -> NisoResult<()> {
+        let path = path.as_ref();
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, self.to_json()?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Read a checkpoint file
+    /// Gantree: load(path) -> Result<Self> // 체크포인트 로드
+    pub fn load(path: impl AsRef<Path>) -> NisoResult<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_rng_state_roundtrip() {
+        let mut rng = ChaCha12Rng::seed_from_u64(7);
+        let _: f64 = rng.gen();
+        let _: bool = rng.gen();
+
+        let state = RngState::capture(&rng);
+        let json = serde_json::to_string(&state).unwrap();
+        let mut restored = serde_json::from_str::<RngState>(&json).unwrap().restore();
+
+        for _ in 0..10 {
+            assert_eq!(rng.gen::<u64>(), restored.gen::<u64>());
+        }
+    }
+}
@@ -0,0 +1,572 @@
+//! Optimizer strategy implementations
+//!
+//! Gantree: L5_TQQC → Strategies
+//!
+//! - `TqqcWalk`: the TQQC ±step walk, run by `TqqcEngine`
+//! - `Spsa`: simultaneous perturbation stochastic approximation
+//! - `NelderMead`: derivative-free simplex search
+//! - `ParameterShift`: gradient ascent with parameter-shift gradients
+//!
+//! All strategies maximize the objective.
+
+use crate::config::TqqcConfig;
+use crate::engine::TqqcEngine;
+use crate::objective::{Objective, ParityObjective};
+use crate::optimizer::{DirectRun, Optimizer};
+use niso_backend::Backend;
+use niso_core::{NisoResult, ParamVec};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::f64::consts::FRAC_PI_2;
+use std::sync::Arc;
+
+fn make_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+// ============================================================================
+// TQQC Walk
+// ============================================================================
+
+/// TQQC ±step walk run by `TqqcEngine`
+/// Gantree: TqqcWalk // TQQC 탐색
+///
+/// Delegates to `TqqcEngine::start`/`step` with the same `TqqcConfig`
+/// (dynamic inner loop, statistical test, adaptive shots, delta mode), so
+/// runner comparisons measure the real TQQC search. The walk drives the
+/// backend itself through `run_direct`; `propose` yields nothing.
+pub struct TqqcWalk {
+    config: TqqcConfig,
+    params: ParamVec,
+}
+
+impl TqqcWalk {
+    /// Create a walk from a TQQC configuration
+    pub fn from_config(config: &TqqcConfig) -> Self {
+        Self {
+            config: config.clone(),
+            params: ParityObjective::new(config.clone()).initial_params(),
+        }
+    }
+
+    /// Upper bound on the shots one outer iteration can spend
+    fn max_step_shots(&self) -> u64 {
+        let per_side = self
+            .config
+            .adaptive_shots
+            .map_or(self.config.shots, |adaptive| adaptive.max);
+        let inner = if self.config.dynamic_inner {
+            self.config.inner_max.max(1)
+        } else {
+            1
+        };
+        2 * per_side * inner as u64
+    }
+
+    /// Run outer iterations while the next one is sure to fit in the budget
+    fn walk(
+        &mut self,
+        backend: &dyn Backend,
+        objective: Arc<dyn Objective>,
+        shot_budget: u64,
+    ) -> NisoResult<DirectRun> {
+        let mut engine = TqqcEngine::new(self.config.clone(), backend).with_objective(objective);
+        let mut state = engine.start()?;
+        let baseline_shots = state.shots_used;
+        let max_step_shots = self.max_step_shots();
+
+        let mut history = Vec::new();
+        while !state.finished && state.shots_used - baseline_shots + max_step_shots <= shot_budget {
+            engine.step(&mut state)?;
+            history.push(state.parity_current);
+        }
+
+        self.params = state.deltas.clone();
+        Ok(DirectRun {
+            params_opt: state.deltas,
+            evaluations: state.history.iter().map(|r| 2 * r.inner_count).sum(),
+            shots_used: state.shots_used - baseline_shots,
+            history,
+        })
+    }
+}
+
+impl Optimizer for TqqcWalk {
+    fn name(&self) -> &str {
+        "tqqc"
+    }
+
+    fn propose(&mut self) -> Vec<ParamVec> {
+        Vec::new()
+    }
+
+    fn update(&mut self, _values: &[f64]) {}
+
+    fn current(&self) -> ParamVec {
+        self.params.clone()
+    }
+
+    fn run_direct(
+        &mut self,
+        backend: &dyn Backend,
+        objective: Arc<dyn Objective>,
+        shot_budget: u64,
+    ) -> Option<NisoResult<DirectRun>> {
+        Some(self.walk(backend, objective, shot_budget))
+    }
+}
+
+// ============================================================================
+// SPSA
+// ============================================================================
+
+/// Simultaneous perturbation stochastic approximation
+/// Gantree: Spsa // SPSA
+///
+/// Gains follow Spall's schedule: a_k = a / (k + 1 + A)^α,
+/// c_k = c / (k + 1)^γ with α = 0.602, γ = 0.101.
+pub struct Spsa {
+    params: ParamVec,
+    a: f64,
+    c: f64,
+    stability: f64,
+    k: usize,
+    perturbation: ParamVec,
+    rng: StdRng,
+}
+
+impl Spsa {
+    /// Create SPSA starting at zero
+    pub fn new(num_params: usize) -> Self {
+        Self {
+            params: vec![0.0; num_params],
+            a: 0.3,
+            c: 0.2,
+            stability: 2.0,
+            k: 0,
+            perturbation: Vec::new(),
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Set starting parameters
+    pub fn with_initial(mut self, params: ParamVec) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Set gain coefficients `a` (step) and `c` (perturbation)
+    pub fn with_gains(mut self, a: f64, c: f64) -> Self {
+        self.a = a;
+        self.c = c;
+        self
+    }
+
+    /// Set seed
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = make_rng(Some(seed));
+        self
+    }
+
+    fn a_k(&self) -> f64 {
+        self.a / (self.k as f64 + 1.0 + self.stability).powf(0.602)
+    }
+
+    fn c_k(&self) -> f64 {
+        self.c / (self.k as f64 + 1.0).powf(0.101)
+    }
+}
+
+impl Optimizer for Spsa {
+    fn name(&self) -> &str {
+        "spsa"
+    }
+
+    fn propose(&mut self) -> Vec<ParamVec> {
+        let c_k = self.c_k();
+        self.perturbation = (0..self.params.len())
+            .map(|_| if self.rng.gen::<bool>() { 1.0 } else { -1.0 })
+            .collect();
+
+        let shifted = |sign: f64| -> ParamVec {
+            self.params
+                .iter()
+                .zip(&self.perturbation)
+                .map(|(x, d)| x + sign * c_k * d)
+                .collect()
+        };
+
+        vec![shifted(1.0), shifted(-1.0)]
+    }
+
+    fn update(&mut self, values: &[f64]) {
+        let (a_k, c_k) = (self.a_k(), self.c_k());
+        let diff = (values[0] - values[1]) / (2.0 * c_k);
+
+        // Δ_i = ±1, so dividing by Δ_i equals multiplying
+        for (x, d) in self.params.iter_mut().zip(&self.perturbation) {
+            *x += a_k * diff * d;
+        }
+        self.k += 1;
+    }
+
+    fn current(&self) -> ParamVec {
+        self.params.clone()
+    }
+}
+
+// ============================================================================
+// Nelder–Mead
+// ============================================================================
+
+/// Pending Nelder–Mead step
+#[derive(Debug, Clone)]
+enum NmPhase {
+    Init,
+    Reflect,
+    Expand { reflected: (ParamVec, f64) },
+    Contract { reflected: (ParamVec, f64) },
+    Shrink,
+}
+
+/// Nelder–Mead simplex search
+/// Gantree: NelderMead // 심플렉스
+///
+/// Standard coefficients: reflection 1, expansion 2, contraction 0.5,
+/// shrink 0.5.
+pub struct NelderMead {
+    initial: ParamVec,
+    initial_step: f64,
+    simplex: Vec<(ParamVec, f64)>,
+    phase: NmPhase,
+    pending: Vec<ParamVec>,
+    tolerance: f64,
+}
+
+impl NelderMead {
+    /// Create Nelder–Mead starting at zero
+    pub fn new(num_params: usize) -> Self {
+        Self {
+            initial: vec![0.0; num_params],
+            initial_step: 0.5,
+            simplex: Vec::new(),
+            phase: NmPhase::Init,
+            pending: Vec::new(),
+            tolerance: 1e-4,
+        }
+    }
+
+    /// Set starting parameters
+    pub fn with_initial(mut self, params: ParamVec) -> Self {
+        self.initial = params;
+        self
+    }
+
+    /// Set edge length of the initial simplex
+    pub fn with_initial_step(mut self, step: f64) -> Self {
+        self.initial_step = step;
+        self
+    }
+
+    /// Sort vertices best-first
+    fn sort(&mut self) {
+        self.simplex
+            .sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    }
+
+    /// Centroid of all vertices except the worst
+    fn centroid(&self) -> ParamVec {
+        let n = self.simplex.len() - 1;
+        let mut c = vec![0.0; self.initial.len()];
+        for (x, _) in &self.simplex[..n] {
+            for (ci, xi) in c.iter_mut().zip(x) {
+                *ci += xi / n as f64;
+            }
+        }
+        c
+    }
+
+    /// c + coef * (x - c)
+    fn along(c: &[f64], x: &[f64], coef: f64) -> ParamVec {
+        c.iter()
+            .zip(x)
+            .map(|(ci, xi)| ci + coef * (xi - ci))
+            .collect()
+    }
+
+    fn worst(&self) -> &(ParamVec, f64) {
+        &self.simplex[self.simplex.len() - 1]
+    }
+
+    fn replace_worst(&mut self, vertex: (ParamVec, f64)) {
+        let last = self.simplex.len() - 1;
+        self.simplex[last] = vertex;
+        self.phase = NmPhase::Reflect;
+    }
+}
+
+impl Optimizer for NelderMead {
+    fn name(&self) -> &str {
+        "nelder_mead"
+    }
+
+    fn propose(&mut self) -> Vec<ParamVec> {
+        self.pending = match &self.phase {
+            NmPhase::Init => {
+                let mut vertices = vec![self.initial.clone()];
+                for i in 0..self.initial.len() {
+                    let mut v = self.initial.clone();
+                    v[i] += self.initial_step;
+                    vertices.push(v);
+                }
+                vertices
+            }
+            NmPhase::Reflect => {
+                let c = self.centroid();
+                vec![Self::along(&c, &self.worst().0, -1.0)]
+            }
+            NmPhase::Expand { reflected } => {
+                let c = self.centroid();
+                vec![Self::along(&c, &reflected.0, 2.0)]
+            }
+            NmPhase::Contract { reflected } => {
+                let c = self.centroid();
+                let worst = self.worst();
+                if reflected.1 > worst.1 {
+                    vec![Self::along(&c, &reflected.0, 0.5)] // Outside
+                } else {
+                    vec![Self::along(&c, &worst.0, 0.5)] // Inside
+                }
+            }
+            NmPhase::Shrink => {
+                let best = &self.simplex[0].0;
+                self.simplex[1..]
+                    .iter()
+                    .map(|(x, _)| Self::along(best, x, 0.5))
+                    .collect()
+            }
+        };
+        self.pending.clone()
+    }
+
+    fn update(&mut self, values: &[f64]) {
+        let evaluated: Vec<(ParamVec, f64)> =
+            self.pending.drain(..).zip(values.iter().copied()).collect();
+
+        match std::mem::replace(&mut self.phase, NmPhase::Reflect) {
+            NmPhase::Init => {
+                self.simplex = evaluated;
+            }
+            NmPhase::Reflect => {
+                let reflected = evaluated[0].clone();
+                let n = self.simplex.len();
+                if reflected.1 > self.simplex[0].1 {
+                    self.phase = NmPhase::Expand { reflected };
+                } else if reflected.1 > self.simplex[n - 2].1 {
+                    self.replace_worst(reflected);
+                } else {
+                    self.phase = NmPhase::Contract { reflected };
+                }
+            }
+            NmPhase::Expand { reflected } => {
+                let expanded = evaluated[0].clone();
+                if expanded.1 > reflected.1 {
+                    self.replace_worst(expanded);
+                } else {
+                    self.replace_worst(reflected);
+                }
+            }
+            NmPhase::Contract { reflected } => {
+                let contracted = evaluated[0].clone();
+                if contracted.1 > reflected.1.max(self.worst().1) {
+                    self.replace_worst(contracted);
+                } else {
+                    self.phase = NmPhase::Shrink;
+                }
+            }
+            NmPhase::Shrink => {
+                for (slot, vertex) in self.simplex[1..].iter_mut().zip(evaluated) {
+                    *slot = vertex;
+                }
+            }
+        }
+
+        self.sort();
+    }
+
+    fn current(&self) -> ParamVec {
+        self.simplex
+            .first()
+            .map(|(x, _)| x.clone())
+            .unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn is_done(&self) -> bool {
+        if self.simplex.len() < 2 {
+            return false;
+        }
+        let best = &self.simplex[0].0;
+        self.simplex[1..].iter().all(|(x, _)| {
+            x.iter()
+                .zip(best)
+                .all(|(a, b)| (a - b).abs() < self.tolerance)
+        })
+    }
+}
+
+// ============================================================================
+// Parameter Shift
+// ============================================================================
+
+/// Gradient ascent with parameter-shift gradients
+/// Gantree: ParameterShift // 파라미터 시프트
+///
+/// For rotation gates, ∂f/∂θ_i = [f(θ + π/2·e_i) − f(θ − π/2·e_i)] / 2
+/// exactly, so each round costs 2·n evaluations.
+pub struct ParameterShift {
+    params: ParamVec,
+    learning_rate: f64,
+}
+
+impl ParameterShift {
+    /// Create parameter-shift gradient ascent starting at zero
+    pub fn new(num_params: usize) -> Self {
+        Self {
+            params: vec![0.0; num_params],
+            learning_rate: 0.5,
+        }
+    }
+
+    /// Set starting parameters
+    pub fn with_initial(mut self, params: ParamVec) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Set learning rate
+    pub fn with_learning_rate(mut self, learning_rate: f64) -> Self {
+        self.learning_rate = learning_rate;
+        self
+    }
+}
+
+impl Optimizer for ParameterShift {
+    fn name(&self) -> &str {
+        "parameter_shift"
+    }
+
+    fn propose(&mut self) -> Vec<ParamVec> {
+        let mut proposals = Vec::with_capacity(2 * self.params.len());
+        for i in 0..self.params.len() {
+            for sign in [1.0, -1.0] {
+                let mut p = self.params.clone();
+                p[i] += sign * FRAC_PI_2;
+                proposals.push(p);
+            }
+        }
+        proposals
+    }
+
+    fn update(&mut self, values: &[f64]) {
+        for (i, pair) in values.chunks(2).enumerate() {
+            let gradient = (pair[0] - pair[1]) / 2.0;
+            self.params[i] += self.learning_rate * gradient;
+        }
+    }
+
+    fn current(&self) -> ParamVec {
+        self.params.clone()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::StrategyRunner;
+    use niso_backend::SimulatorBackend;
+
+    /// Drive an optimizer against an exact objective
+    fn drive(opt: &mut dyn Optimizer, f: impl Fn(&[f64]) -> f64, rounds: usize) -> ParamVec {
+        for _ in 0..rounds {
+            if opt.is_done() {
+                break;
+            }
+            let proposals = opt.propose();
+            let values: Vec<f64> = proposals.iter().map(|p| f(p)).collect();
+            opt.update(&values);
+        }
+        opt.current()
+    }
+
+    fn bowl(x: &[f64]) -> f64 {
+        -((x[0] - 0.4).powi(2) + (x[1] + 0.3).powi(2))
+    }
+
+    #[test]
+    fn test_nelder_mead_exact() {
+        let mut nm = NelderMead::new(2);
+        let x = drive(&mut nm, bowl, 200);
+
+        assert!((x[0] - 0.4).abs() < 1e-2);
+        assert!((x[1] + 0.3).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_parameter_shift_exact() {
+        // f = cos(x0 - 0.5) + cos(x1 + 0.2): shift rule is exact
+        let f = |x: &[f64]| (x[0] - 0.5).cos() + (x[1] + 0.2).cos();
+        let mut ps = ParameterShift::new(2);
+
+        let first = ps.propose();
+        assert_eq!(first.len(), 4);
+
+        let x = drive(&mut ps, f, 100);
+        assert!((x[0] - 0.5).abs() < 1e-3);
+        assert!((x[1] + 0.2).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_spsa_exact() {
+        let mut spsa = Spsa::new(2).with_seed(5);
+        let x = drive(&mut spsa, bowl, 500);
+
+        assert!((x[0] - 0.4).abs() < 0.05);
+        assert!((x[1] + 0.3).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_tqqc_walk_matches_engine() {
+        let config = TqqcConfig::for_qubits(3)
+            .with_noise(0.005)
+            .with_shots(1024)
+            .with_theta(0.8)
+            .with_points(5)
+            .with_seed(42);
+        let make_backend = || SimulatorBackend::from_depol(3, 0.005).unwrap().with_seed(7);
+
+        let runner = StrategyRunner::new(config.clone(), make_backend());
+        let mut walk = TqqcWalk::from_config(&config);
+        assert!(walk.propose().is_empty());
+        let result = runner.run(&mut walk, u64::MAX).unwrap();
+
+        // The runner measures its own baseline first; replay it on the engine's backend
+        let backend = make_backend();
+        let objective = ParityObjective::new(config.clone());
+        let baseline = objective
+            .build_circuit(&objective.baseline_params())
+            .unwrap();
+        backend.execute(&baseline, config.shots).unwrap();
+        let expected = TqqcEngine::new(config, backend).optimize().unwrap();
+
+        assert_eq!(result.params_opt, expected.deltas_opt);
+        assert_eq!(walk.current(), expected.deltas_opt);
+        assert_eq!(result.history.len(), expected.iterations);
+    }
+}